@@ -22,7 +22,17 @@ pub enum InspectorAttribute {
     Tag(syn::Member),
 }
 
+/// Attributes which can be used on fields of any type, stored in `FieldOptions` instead of the type-specific options
+const FIELD_ATTRIBUTES: &[&str] = &["help", "reset", "read_only"];
+
 impl InspectorAttribute {
+    pub fn is_field_attribute(&self) -> bool {
+        match self.lhs() {
+            syn::Member::Named(ident) => FIELD_ATTRIBUTES.iter().any(|name| ident == name),
+            syn::Member::Unnamed(_) => false,
+        }
+    }
+
     pub fn lhs(&self) -> &syn::Member {
         match self {
             InspectorAttribute::Assignment(member, _) => member,
@@ -62,7 +72,7 @@ pub fn extract_inspector_attributes(
 ) -> syn::Result<Vec<InspectorAttribute>> {
     Ok(attrs
         .iter()
-        .filter(|attr| attr.path().get_ident().map_or(false, |p| p == "inspector"))
+        .filter(|attr| attr.path().get_ident().is_some_and(|p| p == "inspector"))
         .map(|attr| attr.parse_args_with(parse_inspectable_attributes))
         .collect::<syn::Result<Vec<_>>>()?
         .into_iter()
@@ -35,22 +35,13 @@ fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStr
             if attrs.is_empty() {
                 return None;
             }
-            let attrs = attrs.into_iter().map(|attribute| {
-                let name = attribute.lhs();
-                let value = attribute.rhs();
-                quote! {
-                    field_options.#name = std::convert::Into::into(#value);
-                }
-            });
-
-            Some(Ok(quote! {
-                let mut field_options = <#ty as bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                #(#attrs)*
-                options.insert(bevy_inspector_egui::inspector_options::Target::Field(#i), <#ty as bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options));
-            }))
+            let target = quote! { bevy_inspector_egui::inspector_options::Target::Field(#i) };
+            Some(Ok(expand_field_attributes(ty, target, attrs)))
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
+    let container = expand_container_attributes(input)?;
+
     let type_name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
@@ -61,6 +52,7 @@ fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStr
             fn from_type() -> Self {
                 let mut options = bevy_inspector_egui::InspectorOptions::default();
 
+                #container
                 #(#fields)*
 
                 options
@@ -91,31 +83,21 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
                     if attrs.is_empty() {
                         return None;
                     }
-                    let attrs = attrs.into_iter().map(|attribute| {
-                        let name = attribute.lhs();
-                        let value = attribute.rhs();
-                        quote! {
-                            field_options.#name = std::convert::Into::into(#value);
+                    let target = quote! {
+                        bevy_inspector_egui::inspector_options::Target::VariantField {
+                            variant_index: #variant_index,
+                            field_index: #field_index,
                         }
-                    });
-
-                    Some(Ok(quote! {
-                        let mut field_options = <#ty as bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                        #(#attrs)*
-                        options.insert(
-                            bevy_inspector_egui::inspector_options::Target::VariantField {
-                                variant_index: #variant_index,
-                                field_index: #field_index,
-                            },
-                            <#ty as bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options)
-                        );
-                    }))
+                    };
+                    Some(Ok(expand_field_attributes(ty, target, attrs)))
                 })
                 .collect::<syn::Result<Vec<_>>>()?;
             Ok(attrs)
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
+    let container = expand_container_attributes(input)?;
+
     let type_name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
@@ -126,6 +108,7 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
             fn from_type() -> Self {
                 let mut options = bevy_inspector_egui::InspectorOptions::default();
 
+                #container
                 #(#(#fields)*)*
 
                 options
@@ -133,6 +116,59 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
         }
     })
 }
+/// Generic attributes like `help` set the `FieldOptions` of the target,
+/// the rest are assigned to the `DeriveOptions` of the field type.
+fn expand_field_attributes(
+    ty: &syn::Type,
+    target: TokenStream,
+    attrs: Vec<attributes::InspectorAttribute>,
+) -> TokenStream {
+    let (field_attrs, type_attrs): (Vec<_>, Vec<_>) = attrs
+        .into_iter()
+        .partition(attributes::InspectorAttribute::is_field_attribute);
+
+    let field_attrs = field_attrs.into_iter().map(|attribute| {
+        let name = attribute.lhs();
+        let value = attribute.rhs();
+        quote! {
+            options.field_mut(#target).#name = std::convert::Into::into(#value);
+        }
+    });
+
+    let type_options = (!type_attrs.is_empty()).then(|| {
+        let attrs = type_attrs.into_iter().map(|attribute| {
+            let name = attribute.lhs();
+            let value = attribute.rhs();
+            quote! {
+                field_options.#name = std::convert::Into::into(#value);
+            }
+        });
+        quote! {
+            let mut field_options = <#ty as bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
+            #(#attrs)*
+            options.insert(#target, <#ty as bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options));
+        }
+    });
+
+    quote! {
+        #(#field_attrs)*
+        #type_options
+    }
+}
+
+/// `#[inspector(..)]` attributes on the type itself set fields on its `ContainerOptions`
+fn expand_container_attributes(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = attributes::extract_inspector_attributes(&input.attrs)?;
+    let attrs = attrs.into_iter().map(|attribute| {
+        let name = attribute.lhs();
+        let value = attribute.rhs();
+        quote! {
+            options.container_mut().#name = std::convert::Into::into(#value);
+        }
+    });
+    Ok(quote! { #(#attrs)* })
+}
+
 fn expand_union(_: &DeriveInput, data: &DataUnion) -> syn::Result<TokenStream> {
     Err(syn::Error::new_spanned(
         data.union_token,
@@ -1,7 +1,9 @@
 use bevy_ecs::entity::Entity;
 use bevy_inspector_egui::{
     inspector_options::{
-        std_options::{EntityDisplay, EntityOptions, NumberOptions, QuatDisplay, QuatOptions},
+        std_options::{
+            EntityDisplay, EntityOptions, NumberOptions, QuatDisplay, QuatOptions, StringOptions,
+        },
         Target,
     },
     InspectorOptions,
@@ -54,3 +56,87 @@ fn expr_attribute() {
         .unwrap();
     assert!(matches!(entity_options.display, EntityDisplay::Id));
 }
+
+#[test]
+fn container_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(grid)]
+    struct Test {
+        #[inspector(min = 0.0)]
+        a: f32,
+        b: f32,
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct NoGrid {
+        a: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert!(options.container().grid);
+    assert!(options.get(Target::Field(0)).is_some());
+
+    let options = <InspectorOptions as FromType<NoGrid>>::from_type();
+    assert!(!options.container().grid);
+}
+
+#[test]
+fn string_max_len() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(max_len = 8)]
+        name: String,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let string_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<StringOptions>()
+        .unwrap();
+    assert_eq!(string_options.max_len, Some(8));
+}
+
+#[test]
+fn help_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(help = "in meters")]
+        a: f32,
+        #[inspector(min = 0.0, help = "in seconds")]
+        b: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert_eq!(
+        options.field(Target::Field(0)).unwrap().help,
+        Some("in meters")
+    );
+    assert!(options.get(Target::Field(0)).is_none());
+
+    assert_eq!(
+        options.field(Target::Field(1)).unwrap().help,
+        Some("in seconds")
+    );
+    let number_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(number_options.min, Some(0.0));
+}
+
+#[test]
+fn reset_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(reset)]
+        a: f32,
+        #[inspector(help = "in seconds")]
+        b: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    assert!(options.field(Target::Field(0)).unwrap().reset);
+    assert!(!options.field(Target::Field(1)).unwrap().reset);
+}
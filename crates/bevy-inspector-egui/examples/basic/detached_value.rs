@@ -0,0 +1,78 @@
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_inspector_egui::{bevy_inspector, DefaultInspectorConfigPlugin};
+use bevy_window::PrimaryWindow;
+
+/// Settings of a painting tool, which are owned by the tool instead of being stored in the world
+#[derive(Reflect)]
+struct Brush {
+    name: String,
+    radius: f32,
+    texture: Handle<Image>,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(EguiPlugin)
+        .add_plugins(DefaultInspectorConfigPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, inspector_ui)
+        .run();
+}
+
+fn inspector_ui(world: &mut World, mut brush: Local<Option<Box<dyn Reflect>>>) {
+    let brush = brush.get_or_insert_with(|| {
+        let texture = world.resource_mut::<Assets<Image>>().add(checkerboard());
+        Box::new(Brush {
+            name: "Checkerboard".to_string(),
+            radius: 8.0,
+            texture,
+        })
+    });
+
+    let mut egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .single(world)
+        .clone();
+
+    egui::Window::new("Brush").show(egui_context.get_mut(), |ui| {
+        // the world is used for the type registry and to show the image behind the handle
+        bevy_inspector::ui_for_reflect_with_world(
+            brush.as_mut(),
+            world,
+            ui,
+            egui::Id::new("brush"),
+        );
+    });
+}
+
+fn checkerboard() -> Image {
+    let size = 32;
+    let data = (0..size * size)
+        .flat_map(|i| {
+            let (x, y) = (i % size, i / size);
+            match (x / 4 + y / 4) % 2 {
+                0 => [255, 255, 255, 255],
+                _ => [40, 40, 40, 255],
+            }
+        })
+        .collect();
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy_egui::EguiContext;
+use bevy_inspector_egui::bevy_egui::EguiPlugin;
+use bevy_inspector_egui::bevy_inspector;
+use bevy_inspector_egui::DefaultInspectorConfigPlugin;
+use bevy_window::PrimaryWindow;
+
+#[derive(Component)]
+struct Spinning;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(EguiPlugin)
+        .add_plugins(DefaultInspectorConfigPlugin)
+        // a slow timestep makes the single steps easy to follow
+        .insert_resource(FixedTime::new_from_secs(0.5))
+        .add_systems(Startup, (setup, pause))
+        .add_systems(FixedUpdate, spin)
+        .add_systems(Update, inspector_ui)
+        .run();
+}
+
+/// start out paused, so that the cube only turns when stepping
+fn pause(mut time: ResMut<Time>) {
+    time.pause();
+}
+
+fn spin(mut cubes: Query<&mut Transform, With<Spinning>>) {
+    for mut transform in &mut cubes {
+        transform.rotate_y(std::f32::consts::FRAC_PI_8);
+    }
+}
+
+fn inspector_ui(world: &mut World) {
+    let mut egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .single(world)
+        .clone();
+
+    egui::Window::new("Fixed Time").show(egui_context.get_mut(), |ui| {
+        bevy_inspector::ui_for_fixed_time(world, ui);
+    });
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..default()
+        },
+        Spinning,
+    ));
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
@@ -15,6 +15,9 @@ struct Config {
     option: Option<f32>,
     #[inspector(min = 10, max = 20)] // same for Vec<T>
     vec: Vec<u32>,
+    // the components of vectors can have their own range, here only `y` is clamped
+    #[inspector(min_y = 0.0)]
+    position: Vec3,
 }
 
 // Enums can be have `InspectorOptions` as well.
@@ -23,6 +26,8 @@ struct Config {
 #[reflect(InspectorOptions)]
 enum Shape {
     Box {
+        // vectors are shown on a single line unless they are `expanded`
+        #[inspector(min = Vec3::ZERO, expanded)]
         size: Vec3,
     },
     Icosphere {
@@ -0,0 +1,73 @@
+use std::any::TypeId;
+
+use bevy::prelude::*;
+use bevy_egui::EguiContext;
+use bevy_inspector_egui::bevy_egui::EguiPlugin;
+use bevy_inspector_egui::DefaultInspectorConfigPlugin;
+use bevy_window::PrimaryWindow;
+
+#[derive(Reflect, Resource, Default)]
+#[reflect(Resource)]
+struct Score {
+    points: u32,
+    multiplier: f32,
+}
+
+#[derive(Reflect, Resource)]
+#[reflect(Resource)]
+struct Difficulty {
+    enemy_speed: f32,
+    enemy_count: usize,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Difficulty {
+            enemy_speed: 1.0,
+            enemy_count: 10,
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(EguiPlugin)
+        .add_plugins(DefaultInspectorConfigPlugin)
+        .init_resource::<Score>()
+        .init_resource::<Difficulty>()
+        .register_type::<Score>()
+        .register_type::<Difficulty>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, inspector_ui)
+        .run();
+}
+
+fn inspector_ui(world: &mut World) {
+    let mut egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .single(world)
+        .clone();
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    egui::Window::new("Game Settings").show(egui_context.get_mut(), |ui| {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            bevy_inspector_egui::bevy_inspector::by_type_id::ui_for_resources(
+                world,
+                &[
+                    TypeId::of::<Score>(),
+                    TypeId::of::<Difficulty>(),
+                    TypeId::of::<ClearColor>(),
+                ],
+                ui,
+                &type_registry,
+            );
+        });
+    });
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
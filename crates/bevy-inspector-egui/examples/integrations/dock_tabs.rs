@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_inspector_egui::bevy_inspector::WorldInspectorOptions;
+use bevy_inspector_egui::quick::{
+    asset_inspector_panel, resource_inspector_panel, world_inspector_panel,
+};
+use bevy_inspector_egui::DefaultInspectorConfigPlugin;
+use bevy_window::PrimaryWindow;
+use egui_dock::{DockArea, NodeIndex, Style, Tree};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(EguiPlugin)
+        .add_plugins(DefaultInspectorConfigPlugin)
+        .register_type::<Configuration>()
+        .init_resource::<Configuration>()
+        .insert_resource(Tabs::new())
+        .add_systems(Startup, setup)
+        .add_systems(Update, dock_ui)
+        .run();
+}
+
+#[derive(Reflect, Resource, Default)]
+#[reflect(Resource)]
+struct Configuration {
+    name: String,
+    option: f32,
+}
+
+#[derive(Debug)]
+enum Tab {
+    World,
+    Configuration,
+    Materials,
+}
+
+#[derive(Resource)]
+struct Tabs(Tree<Tab>);
+
+impl Tabs {
+    fn new() -> Self {
+        let mut tree = Tree::new(vec![Tab::World]);
+        tree.split_below(
+            NodeIndex::root(),
+            0.6,
+            vec![Tab::Configuration, Tab::Materials],
+        );
+        Tabs(tree)
+    }
+}
+
+fn dock_ui(world: &mut World) {
+    let Ok(egui_context) = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .get_single(world)
+    else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let ctx = egui_context.get_mut();
+
+    world.resource_scope::<Tabs, _>(|world, mut tabs| {
+        egui::SidePanel::left("dock")
+            .default_width(350.0)
+            .show(ctx, |ui| {
+                DockArea::new(&mut tabs.0)
+                    .style(Style::from_egui(ui.style()))
+                    .show_inside(ui, &mut TabViewer { world });
+            });
+    });
+}
+
+struct TabViewer<'a> {
+    world: &'a mut World,
+}
+
+impl egui_dock::TabViewer for TabViewer<'_> {
+    type Tab = Tab;
+
+    // the panels render just their content, so they fit into the tabs provided by the dock area
+    fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
+        match tab {
+            Tab::World => world_inspector_panel(self.world, ui, &WorldInspectorOptions::default()),
+            Tab::Configuration => resource_inspector_panel::<Configuration>(self.world, ui),
+            Tab::Materials => asset_inspector_panel::<StandardMaterial>(self.world, ui),
+        }
+    }
+
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
+        format!("{tab:?}").into()
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+        material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+        ..default()
+    });
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::hover_highlight::HoverHighlightPlugin;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WorldInspectorPlugin::new())
+        // outlines the entity under the pointer in the inspector
+        .add_plugins(HoverHighlightPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let cube = meshes.add(Mesh::from(shape::Cube { size: 1.0 }));
+    let sphere = meshes.add(Mesh::try_from(shape::Icosphere::default()).unwrap());
+    for i in 0..5 {
+        let mesh = match i % 2 {
+            0 => cube.clone(),
+            _ => sphere.clone(),
+        };
+        commands.spawn((
+            Name::new(format!("Shape {i}")),
+            PbrBundle {
+                mesh,
+                material: materials.add(Color::hsl(i as f32 * 70.0, 0.7, 0.5).into()),
+                transform: Transform::from_xyz(i as f32 * 1.5 - 3.0, 0.5, 0.0),
+                ..default()
+            },
+        ));
+    }
+    commands.spawn((
+        Name::new("Ground"),
+        PbrBundle {
+            mesh: meshes.add(shape::Plane::from_size(10.0).into()),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            ..default()
+        },
+    ));
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-2.0, 6.0, 9.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_inspector_egui::bevy_inspector::{self, hierarchy::SelectedEntities};
+use bevy_inspector_egui::transform_gizmo::TransformGizmoPlugin;
+use bevy_inspector_egui::DefaultInspectorConfigPlugin;
+use bevy_window::PrimaryWindow;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(EguiPlugin)
+        .add_plugins(DefaultInspectorConfigPlugin)
+        // draws handles for the entity selected in the `SelectedEntities` resource
+        .add_plugins(TransformGizmoPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, inspector_ui)
+        .run();
+}
+
+fn inspector_ui(world: &mut World) {
+    let mut egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .single(world)
+        .clone();
+
+    // the selection is shared with the gizmo through the `SelectedEntities` resource
+    world.resource_scope(|world, mut selected_entities: Mut<SelectedEntities>| {
+        egui::SidePanel::left("hierarchy")
+            .default_width(200.0)
+            .show(egui_context.get_mut(), |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.heading("Hierarchy");
+                    bevy_inspector::hierarchy::hierarchy_ui(world, ui, &mut selected_entities);
+
+                    ui.label("Select an entity and drag its handles in the viewport");
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+
+        egui::SidePanel::right("inspector")
+            .default_width(250.0)
+            .show(egui_context.get_mut(), |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.heading("Inspector");
+
+                    // the numbers update while the entity is dragged
+                    if let &[entity] = selected_entities.as_slice() {
+                        bevy_inspector::ui_for_entity(world, entity, ui);
+                    }
+
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+    });
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let cube = meshes.add(Mesh::from(shape::Cube { size: 1.0 }));
+    for (i, color) in [Color::ORANGE_RED, Color::SEA_GREEN, Color::MIDNIGHT_BLUE]
+        .into_iter()
+        .enumerate()
+    {
+        commands.spawn((
+            Name::new(format!("Cube {i}")),
+            PbrBundle {
+                mesh: cube.clone(),
+                material: materials.add(color.into()),
+                transform: Transform::from_xyz(i as f32 * 2.0 - 2.0, 0.5, 0.0),
+                ..default()
+            },
+        ));
+    }
+    commands.spawn((
+        Name::new("Ground"),
+        PbrBundle {
+            mesh: meshes.add(shape::Plane::from_size(10.0).into()),
+            material: materials.add(Color::rgb(0.3, 0.5, 0.3).into()),
+            ..default()
+        },
+    ));
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-2.0, 6.0, 9.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
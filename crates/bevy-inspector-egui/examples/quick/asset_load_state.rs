@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+
+/// The handles show the load state and path of their asset, and the asset itself once it is loaded
+#[derive(Resource, Reflect)]
+struct Textures {
+    // this file doesn't exist, so it shows up as failed
+    missing: Handle<Image>,
+    // assets which weren't loaded by the asset server are just shown without a load state
+    generated: Handle<Image>,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .register_type::<Textures>()
+        .add_plugins(ResourceInspectorPlugin::<Textures>::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+    commands.insert_resource(Textures {
+        missing: asset_server.load("textures/missing.png"),
+        generated: images.add(Image::default()),
+    });
+}
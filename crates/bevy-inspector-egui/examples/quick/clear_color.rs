@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // `ClearColor` is registered by bevy, and its `Color` gets the same color picker as everywhere else
+        .insert_resource(ClearColor(Color::rgb(0.1, 0.2, 0.3)))
+        .add_plugins(ResourceInspectorPlugin::<ClearColor>::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::EventInspectorPlugin;
+
+#[derive(Event, Reflect)]
+struct CollisionEvent {
+    a: Entity,
+    b: Entity,
+    impulse: f32,
+}
+
+#[derive(Resource)]
+struct CollisionTimer(Timer);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_event::<CollisionEvent>()
+        .register_type::<CollisionEvent>()
+        .add_plugins(EventInspectorPlugin::<CollisionEvent>::default().max_events(20))
+        .insert_resource(CollisionTimer(Timer::from_seconds(
+            0.5,
+            TimerMode::Repeating,
+        )))
+        .add_systems(Startup, setup)
+        .add_systems(Update, collide)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn(Name::new("Ball"));
+    commands.spawn(Name::new("Wall"));
+}
+
+/// pretend that every entity collides with another one twice a second
+fn collide(
+    time: Res<Time>,
+    mut timer: ResMut<CollisionTimer>,
+    entities: Query<Entity, With<Name>>,
+    mut collisions: EventWriter<CollisionEvent>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let entities: Vec<_> = entities.iter().collect();
+    for (i, &a) in entities.iter().enumerate() {
+        let b = entities[(i + 1) % entities.len()];
+        collisions.send(CollisionEvent {
+            a,
+            b,
+            impulse: time.elapsed_seconds().sin().abs() * 10.0,
+        });
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::{QuickWindowHeader, WorldInspectorPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // the frame rate is only shown when the diagnostics are measured
+        .add_plugins(FrameTimeDiagnosticsPlugin)
+        .insert_resource(QuickWindowHeader { frame_time: true })
+        .add_plugins(WorldInspectorPlugin::new())
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_frame_time)
+        .run();
+}
+
+/// Press `F` to hide or show the frame rate in the inspector window
+fn toggle_frame_time(keyboard: Res<Input<KeyCode>>, mut header: ResMut<QuickWindowHeader>) {
+    if keyboard.just_pressed(KeyCode::F) {
+        header.frame_time = !header.frame_time;
+    }
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn(PbrBundle {
+        mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+        material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+        ..default()
+    });
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
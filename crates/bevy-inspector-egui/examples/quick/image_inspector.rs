@@ -0,0 +1,60 @@
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+
+#[derive(Resource, Reflect)]
+struct Textures {
+    gradient: Handle<Image>,
+    volume: Handle<Image>,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .register_type::<Textures>()
+        .add_plugins(ResourceInspectorPlugin::<Textures>::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let size = 64;
+    let data = (0..size * size)
+        .flat_map(|i| {
+            let (x, y) = (i % size, i / size);
+            [(x * 4) as u8, (y * 4) as u8, 128, 255]
+        })
+        .collect();
+    let gradient = images.add(Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    ));
+
+    // 3D textures can't be displayed, so only their metadata is shown
+    let volume = images.add(Image::new_fill(
+        Extent3d {
+            width: 8,
+            height: 8,
+            depth_or_array_layers: 8,
+        },
+        TextureDimension::D3,
+        &[255, 255, 255, 255],
+        TextureFormat::Rgba8UnormSrgb,
+    ));
+
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn(SpriteBundle {
+        texture: gradient.clone(),
+        transform: Transform::from_scale(Vec3::splat(4.0)),
+        ..default()
+    });
+    commands.insert_resource(Textures { gradient, volume });
+}
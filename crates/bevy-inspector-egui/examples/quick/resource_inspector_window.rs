@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::prelude::*;
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+
+#[derive(Reflect, Resource, Default, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+struct Configuration {
+    name: String,
+    #[inspector(min = 0.0, max = 1.0)]
+    volume: f32,
+    fullscreen: bool,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(
+            ResourceInspectorPlugin::<Configuration>::default()
+                .window_title("Settings")
+                .default_size(egui::vec2(300.0, 200.0))
+                .resizable(false),
+        )
+        .init_resource::<Configuration>()
+        .register_type::<Configuration>()
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::{ResourceInspectorPlugin, WorldInspectorPlugin};
+
+/// The scene handle shows where the scene was loaded from and how many instances of it exist,
+/// and has a button to spawn another one
+#[derive(Resource, Reflect)]
+struct Scenes {
+    gltf: Handle<Scene>,
+}
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .register_type::<Scenes>()
+        .add_plugins(ResourceInspectorPlugin::<Scenes>::default())
+        // the spawned instances show up in the world inspector
+        .add_plugins(WorldInspectorPlugin::new())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    // pass the path of a glTF file in the `assets` folder, e.g. `cargo run --example scene_inspector models/helmet.gltf`
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "models/scene.gltf".to_string());
+    commands.insert_resource(Scenes {
+        gltf: asset_server.load(format!("{path}#Scene0")),
+    });
+
+    commands.spawn(DirectionalLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
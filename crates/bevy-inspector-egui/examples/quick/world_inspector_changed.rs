@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+// Run with `--features highlight_changes` to also outline the changed components.
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // only the moving cube and its `Transform`s are listed while it moves
+        .add_plugins(WorldInspectorPlugin::new().only_changed(true))
+        .add_systems(Startup, setup)
+        .add_systems(Update, move_cube)
+        .run();
+}
+
+#[derive(Component)]
+struct Moving;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Name::new("Moving cube"),
+        Moving,
+        PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            ..default()
+        },
+    ));
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
+
+/// Moves the cube every other half second, so that it keeps appearing and disappearing in the inspector
+fn move_cube(time: Res<Time>, mut cubes: Query<&mut Transform, With<Moving>>) {
+    if (time.elapsed_seconds() * 2.0) as u32 % 2 == 1 {
+        return;
+    }
+    for mut transform in &mut cubes {
+        transform.translation.x = time.elapsed_seconds().sin() * 2.0;
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_inspector_egui::bevy_egui::EguiContexts;
+use bevy_inspector_egui::bevy_inspector::hierarchy::SelectedEntities;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // shows only the clicked cube, until `World` is clicked at the top of the window
+        .add_plugins(WorldInspectorPlugin::new().selected_only(true))
+        .add_systems(Startup, setup)
+        .add_systems(Update, click_to_select)
+        .run();
+}
+
+/// Radius of the sphere around each cube which can be clicked
+const PICK_RADIUS: f32 = 0.4;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for i in 0..5 {
+        commands.spawn((
+            Name::new(format!("Cube {i}")),
+            PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::Cube { size: 0.5 })),
+                material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+                transform: Transform::from_xyz(i as f32 - 2.0, 0.0, 0.0),
+                ..default()
+            },
+        ));
+    }
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 2.5, 6.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
+
+/// Selects the cube closest to the camera under the cursor, a picking crate would do this in a real app
+fn click_to_select(
+    mouse: Res<Input<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    cubes: Query<(Entity, &GlobalTransform), With<Handle<Mesh>>>,
+    mut egui_contexts: EguiContexts,
+    mut selected: ResMut<SelectedEntities>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || egui_contexts.ctx_mut().wants_pointer_input() {
+        return;
+    }
+    let Some(cursor) = windows.single().cursor_position() else {
+        return;
+    };
+    let (camera, camera_transform) = cameras.single();
+    let Some(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+
+    let clicked = cubes
+        .iter()
+        .filter_map(|(entity, transform)| {
+            // distance along the ray to the point closest to the cube's center
+            let to_center = transform.translation() - ray.origin;
+            let along = to_center.dot(ray.direction);
+            let distance = (to_center - ray.direction * along).length();
+            (along > 0.0 && distance < PICK_RADIUS).then_some((entity, along))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+    if let Some((entity, _)) = clicked {
+        selected.select_replace(entity);
+    }
+}
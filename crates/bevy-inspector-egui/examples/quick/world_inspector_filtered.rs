@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WorldInspectorPlugin::filtered::<(), With<Selected>>())
+        .add_systems(Startup, setup)
+        .add_systems(Update, select_next)
+        .run();
+}
+
+/// Only entities with this marker are shown in the inspector
+#[derive(Component)]
+struct Selected;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for i in 0..5 {
+        let mut cube = commands.spawn((
+            Name::new(format!("Cube {i}")),
+            PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::Cube { size: 0.5 })),
+                material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+                transform: Transform::from_xyz(i as f32 - 2.0, 0.0, 0.0),
+                ..default()
+            },
+        ));
+        if i == 0 {
+            cube.insert(Selected);
+        }
+    }
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 2.5, 6.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..default()
+    });
+}
+
+/// Moves the selection to the next cube when pressing space
+fn select_next(
+    mut commands: Commands,
+    keyboard: Res<Input<KeyCode>>,
+    cubes: Query<(Entity, Option<&Selected>), With<Handle<Mesh>>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let mut cubes: Vec<_> = cubes.iter().collect();
+    cubes.sort_by_key(|(entity, _)| *entity);
+
+    let Some(selected) = cubes.iter().position(|(_, selected)| selected.is_some()) else {
+        return;
+    };
+    let next = (selected + 1) % cubes.len();
+    commands.entity(cubes[selected].0).remove::<Selected>();
+    commands.entity(cubes[next].0).insert(Selected);
+}
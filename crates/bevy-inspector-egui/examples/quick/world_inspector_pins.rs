@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_inspector::pins::InspectorPins;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WorldInspectorPlugin::new())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut pins: ResMut<InspectorPins>,
+) {
+    // lots of entities, so that the camera would be hard to find in the list
+    for i in 0..100 {
+        commands.spawn(PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Cube { size: 0.2 })),
+            material: materials.add(Color::rgb(0.8, 0.7, 0.6).into()),
+            transform: Transform::from_xyz(
+                (i % 10) as f32 * 0.5 - 2.25,
+                0.0,
+                (i / 10) as f32 * 0.5 - 2.25,
+            ),
+            ..default()
+        });
+    }
+    commands.spawn(PointLightBundle {
+        point_light: PointLight {
+            intensity: 1500.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+
+    // the camera is shown in the "Pinned" section of the world inspector.
+    // Other entities and resources can be pinned by right-clicking them.
+    let camera = commands
+        .spawn((
+            Name::new("Camera"),
+            Camera3dBundle {
+                transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+                ..default()
+            },
+        ))
+        .id();
+    pins.pin_entity(camera);
+}
@@ -0,0 +1,94 @@
+//! Deferring structural edits like adding and removing components or despawning entities until after the inspector UI was drawn.
+//!
+//! The UI functions of the [`bevy_inspector`](super) hand out the world as a [`RestrictedWorldView`](crate::restricted_world_view::RestrictedWorldView)
+//! while drawing, so structural edits are pushed to a command queue instead of being applied right away.
+//! By default that queue is applied at the end of each UI function. If the [`InspectorCommandQueue`] resource exists,
+//! the commands are collected there instead and applied by the [`apply_inspector_commands`] system,
+//! after all inspector UI of the frame was drawn:
+//! ```rust
+//! # use bevy_app::{App, PostUpdate};
+//! use bevy_inspector_egui::bevy_inspector::command_queue::{apply_inspector_commands, InspectorCommandQueue};
+//!
+//! App::new()
+//!     .init_resource::<InspectorCommandQueue>()
+//!     .add_systems(PostUpdate, apply_inspector_commands);
+//! ```
+//! The quick plugins do this automatically.
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::{Command, CommandQueue};
+
+/// Structural edits made through the inspector, waiting for [`apply_inspector_commands`]
+#[derive(Resource, Default)]
+pub struct InspectorCommandQueue {
+    queue: CommandQueue,
+}
+
+impl InspectorCommandQueue {
+    pub fn push<C: Command>(&mut self, command: C) {
+        self.queue.push(command);
+    }
+}
+
+/// Applies the commands of the [`InspectorCommandQueue`]
+pub fn apply_inspector_commands(world: &mut World) {
+    let Some(mut commands) = world.get_resource_mut::<InspectorCommandQueue>() else {
+        return;
+    };
+    let mut queue = std::mem::take(&mut commands.queue);
+    queue.apply(world);
+}
+
+/// Moves the commands of the `queue` to the [`InspectorCommandQueue`] if it exists, and applies them otherwise
+pub(crate) fn apply_or_defer(world: &mut World, queue: &mut CommandQueue) {
+    match world.get_resource_mut::<InspectorCommandQueue>() {
+        Some(mut commands) => {
+            let mut queue = std::mem::take(queue);
+            commands.push(move |world: &mut World| queue.apply(world));
+        }
+        None => queue.apply(world),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::system::CommandQueue;
+
+    use super::{apply_inspector_commands, InspectorCommandQueue};
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn deferred_until_applied() {
+        let mut app = App::new();
+        app.init_resource::<InspectorCommandQueue>()
+            .add_systems(PostUpdate, apply_inspector_commands);
+        let entity = app.world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        queue.push(move |world: &mut World| {
+            world.entity_mut(entity).insert(Marker);
+        });
+        super::apply_or_defer(&mut app.world, &mut queue);
+        assert!(app.world.get::<Marker>(entity).is_none());
+
+        app.update();
+        assert!(app.world.get::<Marker>(entity).is_some());
+    }
+
+    #[test]
+    fn applied_without_resource() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        queue.push(move |world: &mut World| {
+            world.despawn(entity);
+        });
+        super::apply_or_defer(&mut world, &mut queue);
+        assert!(world.get_entity(entity).is_none());
+    }
+}
@@ -0,0 +1,149 @@
+//! Events for every edit made through the inspector, for example to keep a log of what a tester changed.
+//!
+//! [`InspectorEdit`]s are only sent for components and resources, and only if the event was added to the app:
+//! ```rust
+//! # use bevy_app::{App, Update};
+//! # use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::edit_log::InspectorEdit;
+//!
+//! fn log_edits(mut edits: EventReader<InspectorEdit>) {
+//!     for edit in edits.iter() {
+//!         println!("{:?}.{} = {:?}", edit.entity, edit.field_path, edit.new);
+//!     }
+//! }
+//!
+//! App::new()
+//!     .add_event::<InspectorEdit>()
+//!     .add_systems(Update, log_edits);
+//! ```
+//! Dragging a number sends an event for every frame in which the value changed.
+
+use std::any::TypeId;
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{GetPath, Reflect};
+
+use super::history::{EditTarget, InspectorHistory};
+use crate::restricted_world_view::RestrictedWorldView;
+
+/// A field of a component or resource was changed through the inspector
+#[derive(Event, Debug)]
+pub struct InspectorEdit {
+    /// The entity of the component, or `None` for a resource
+    pub entity: Option<Entity>,
+    /// Type of the component or resource
+    pub type_id: TypeId,
+    /// Path of the changed field inside of the component or resource, using the syntax of [`GetPath`].
+    /// It is empty if the value was changed as a whole.
+    pub field_path: String,
+    /// Value of the field before the edit
+    pub old: Box<dyn Reflect>,
+    /// Value of the field after the edit
+    pub new: Box<dyn Reflect>,
+}
+
+/// Whether edits are recorded, as [`InspectorEdit`]s or in the [`InspectorHistory`], which needs a copy of the value from before the edit
+pub(crate) fn is_recording(world: &RestrictedWorldView<'_>) -> bool {
+    world.get_resource::<Events<InspectorEdit>>().is_ok()
+        || world.get_resource::<InspectorHistory>().is_ok()
+}
+
+/// Runs `f` with a callback for [`InspectorUi::with_field_changed_callback`](crate::reflect_inspector::InspectorUi::with_field_changed_callback),
+/// returning its result and the path and new value of every changed field, to be passed to [`send`]
+pub(crate) fn collect_changes<R>(
+    f: impl FnOnce(&mut dyn FnMut(&dyn Reflect, &str)) -> R,
+) -> (R, Vec<(String, Box<dyn Reflect>)>) {
+    let mut changes = Vec::new();
+    let result = f(&mut |value, path| changes.push((path.to_owned(), value.clone_value())));
+    (result, changes)
+}
+
+/// Sends an [`InspectorEdit`] for every changed field, taking their old values from `before`
+pub(crate) fn send(
+    world: &mut RestrictedWorldView<'_>,
+    target: EditTarget,
+    before: &dyn Reflect,
+    changes: Vec<(String, Box<dyn Reflect>)>,
+) {
+    let Ok(mut events) = world.get_resource_mut::<Events<InspectorEdit>>() else {
+        return;
+    };
+    let (entity, type_id) = match target {
+        EditTarget::Resource(type_id) => (None, type_id),
+        EditTarget::Component(entity, type_id) => (Some(entity), type_id),
+    };
+    for (field_path, new) in changes {
+        let old = match field_path.is_empty() {
+            true => before,
+            false => match before.reflect_path(&field_path) {
+                Ok(old) => old,
+                // the field didn't exist before, e.g. a list element which was added
+                Err(_) => continue,
+            },
+        };
+        events.send(InspectorEdit {
+            entity,
+            type_id,
+            field_path,
+            old: old.clone_value(),
+            new,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_reflect::Reflect;
+
+    use super::InspectorEdit;
+    use crate::utils::tests::{std_type_registry, TestUi};
+
+    #[derive(Reflect, Default)]
+    struct Stats {
+        speed: f32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Player {
+        stats: Stats,
+    }
+
+    #[test]
+    fn edit_sends_event() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Player>();
+        let mut world = World::new();
+        world.insert_resource(AppTypeRegistry::default());
+        *world.resource::<AppTypeRegistry>().write() = type_registry;
+        world.init_resource::<Events<InspectorEdit>>();
+        let entity = world.spawn(Player::default()).id();
+
+        let mut test_ui = TestUi::new();
+        let mut ui =
+            |ui: &mut egui::Ui| crate::bevy_inspector::ui_for_entity(&mut world, entity, ui);
+        test_ui.run(&mut ui);
+        let header = test_ui.text_position("Player { stats: (0.0) }").unwrap();
+        test_ui.click(header, &mut ui);
+        // wait for the header to open, grids are invisible in their first frame
+        for _ in 0..30 {
+            test_ui.run(&mut ui);
+        }
+        let pos = test_ui.text_position("0").unwrap();
+        test_ui.replace_text(pos, "0", "2.5", &mut ui);
+
+        let events = world.resource::<Events<InspectorEdit>>();
+        let edits: Vec<_> = events.iter_current_update_events().collect();
+        assert_eq!(edits.len(), 1);
+        let edit = edits[0];
+        assert_eq!(edit.entity, Some(entity));
+        assert_eq!(edit.type_id, TypeId::of::<Player>());
+        assert_eq!(edit.field_path, "stats.speed");
+        assert_eq!(edit.old.downcast_ref::<f32>(), Some(&0.0));
+        assert_eq!(edit.new.downcast_ref::<f32>(), Some(&2.5));
+    }
+}
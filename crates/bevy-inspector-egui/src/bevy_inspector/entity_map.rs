@@ -0,0 +1,176 @@
+//! Maps keyed by [`Entity`], like `HashMap<Entity, f32>`, which are shown by the [`short_circuit`](super::short_circuit)
+//! functions with links to the entities of their keys.
+//!
+//! bevy 0.11 has no `EntityHashMap` yet, so this covers all maps whose key type is [`Entity`].
+
+use std::any::TypeId;
+
+use bevy_ecs::entity::Entity;
+use bevy_reflect::{std_traits::ReflectDefault, Map, MapInfo, TypeInfo};
+
+use super::hierarchy::entity_link;
+use crate::egui_utils;
+use crate::reflect_inspector::{errors::no_default_value, InspectorUi};
+
+/// The type info of the `map` if it is keyed by [`Entity`]
+pub(crate) fn entity_map_info(map: &dyn Map) -> Option<&'static MapInfo> {
+    match map.get_represented_type_info()? {
+        TypeInfo::Map(info) if info.key_type_id() == TypeId::of::<Entity>() => Some(info),
+        _ => None,
+    }
+}
+
+/// Maps keyed by [`Entity`], whose keys are shown as [`entity_link`]s.
+///
+/// Entries can be removed, and added for the entities which aren't a key yet.
+/// New values are the `Default` of the value type, or a copy of the first value if it has none.
+pub(crate) fn entity_map_ui(
+    map: &mut dyn Map,
+    info: &MapInfo,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) -> bool {
+    let mut changed = false;
+    let mut to_remove = None;
+    let visible = env.visible_collection_items(ui, id, map.len());
+    egui::Grid::new(id).show(ui, |ui| {
+        for i in 0..visible {
+            let (key, value) = map.get_at_mut(i).unwrap();
+            let entity = *key.downcast_ref::<Entity>().unwrap();
+            entity_link(entity, ui, env);
+            changed |= env.ui_for_reflect_with_options(value, ui, id.with(i), &());
+            if egui_utils::label_button(ui, "✖", egui::Color32::RED) {
+                to_remove = Some(entity);
+            }
+            ui.end_row();
+        }
+    });
+    env.show_more_items(ui, id, visible, map.len());
+
+    if let Some(entity) = to_remove {
+        map.remove(&entity);
+        changed = true;
+    }
+    let error_id = id.with("error");
+    if let Some(entity) = entity_map_add_ui(map, ui, id, env) {
+        let value = env
+            .type_registry
+            .get_type_data::<ReflectDefault>(info.value_type_id())
+            .map(|reflect_default| reflect_default.default())
+            .or_else(|| Some(map.get_at(0)?.1.clone_value()));
+        match value {
+            Some(value) => {
+                map.insert_boxed(Box::new(entity), value);
+                changed = true;
+            }
+            None => ui.data_mut(|data| data.insert_temp(error_id, true)),
+        }
+    }
+    if ui.data(|data| data.get_temp::<bool>(error_id).unwrap_or(false)) {
+        no_default_value(ui, info.value_type_name());
+    }
+    if ui.input(|input| input.pointer.any_down()) {
+        ui.data_mut(|data| data.remove::<bool>(error_id));
+    }
+
+    changed
+}
+
+pub(crate) fn entity_map_ui_readonly(
+    map: &dyn Map,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) {
+    let visible = env.visible_collection_items(ui, id, map.len());
+    egui::Grid::new(id).show(ui, |ui| {
+        for (i, (key, value)) in map.iter().take(visible).enumerate() {
+            entity_link(*key.downcast_ref::<Entity>().unwrap(), ui, env);
+            env.ui_for_reflect_readonly_with_options(value, ui, id.with(i), &());
+            ui.end_row();
+        }
+    });
+    env.show_more_items(ui, id, visible, map.len());
+}
+
+/// Dropdown of the existing entities which aren't a key of the `map`, returning the one which was picked.
+///
+/// The entities are only listed while the dropdown is open.
+fn entity_map_add_ui(
+    map: &dyn Map,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) -> Option<Entity> {
+    let world = env.context.world.as_mut()?;
+
+    let mut picked = None;
+    egui::ComboBox::from_id_source(id.with("add entity"))
+        .selected_text("Add entity")
+        .show_ui(ui, |ui| {
+            let mut entities: Vec<Entity> = world
+                .world()
+                .archetypes()
+                .iter()
+                .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.entity()))
+                .filter(|entity| map.get(entity).is_none())
+                .collect();
+            entities.sort();
+
+            for entity in entities {
+                let name =
+                    crate::utils::guess_entity_name::guess_entity_name_restricted(world, entity);
+                if ui.selectable_label(false, name).clicked() {
+                    picked = Some(entity);
+                }
+            }
+        });
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::world::World;
+    use bevy_utils::HashMap;
+
+    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::restricted_world_view::RestrictedWorldView;
+    use crate::utils::tests::{std_type_registry, TestUi};
+
+    #[test]
+    fn entity_map_edit() {
+        let type_registry = std_type_registry();
+
+        let mut world = World::new();
+        let enemy = world.spawn(Name::new("Enemy")).id();
+        let ally = world.spawn(Name::new("Ally")).id();
+        let mut threat = HashMap::from([(enemy, 1.0f32)]);
+
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let mut cx = Context {
+                world: Some(RestrictedWorldView::new(&mut world)),
+                queue: None,
+            };
+            InspectorUi::for_bevy(&type_registry, &mut cx).ui_for_reflect(&mut threat, ui);
+        };
+        let mut test_ui = TestUi::new();
+        // grids are only painted from the second frame on
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("Enemy").is_some());
+
+        let drag_value = test_ui.text_position("1.0").unwrap();
+        test_ui.replace_text(drag_value, "1.0", "2.5", &mut add_contents);
+
+        // entries can be added for the entities which aren't a key yet
+        let add = test_ui.text_position("Add entity").unwrap();
+        test_ui.click(add, &mut add_contents);
+        test_ui.run(&mut add_contents);
+        let pick = test_ui.text_position("Ally").unwrap();
+        test_ui.click(pick, &mut add_contents);
+
+        assert_eq!(threat, HashMap::from([(enemy, 2.5), (ally, 0.0)]));
+    }
+}
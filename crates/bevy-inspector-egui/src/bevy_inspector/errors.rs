@@ -77,6 +77,15 @@ pub fn no_component_id(ui: &mut egui::Ui, type_name: &str) {
     ui.label(job);
 }
 
+/// Muted line in place of the header of a component which exists, but can't be inspected
+pub fn component_not_reflectable(ui: &mut egui::Ui, name: &str, reason: &str) {
+    ui.horizontal(|ui| {
+        // line up with the titles of the component headers
+        ui.add_space(ui.spacing().indent);
+        ui.weak(format!("{name} — not reflectable ({reason})"));
+    });
+}
+
 pub fn no_type_data(ui: &mut egui::Ui, type_name: &str, type_data: &str) {
     let job = layout_job(&[
         (FontId::monospace(12.0), type_name),
@@ -155,7 +164,7 @@ pub fn no_type_id(ui: &mut egui::Ui, component_name: &str) {
     ui.label(job);
 }
 
-pub fn name_of_type(type_id: TypeId, type_registry: &TypeRegistry) -> Cow<str> {
+pub fn name_of_type(type_id: TypeId, type_registry: &TypeRegistry) -> Cow<'_, str> {
     type_registry
         .get(type_id)
         .map(|registration| Cow::Borrowed(registration.short_name()))
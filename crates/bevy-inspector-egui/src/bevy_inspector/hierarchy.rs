@@ -1,17 +1,21 @@
 use std::collections::HashSet;
 
-use bevy_ecs::{prelude::*, query::ReadOnlyWorldQuery};
-use bevy_hierarchy::{Children, Parent};
+use bevy_ecs::{prelude::*, query::ReadOnlyWorldQuery, system::CommandQueue};
+use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
 use bevy_reflect::TypeRegistry;
 use egui::{CollapsingHeader, RichText};
 
+use super::hover::InspectorHover;
+use crate::reflect_inspector::{Context, InspectorUi};
 use crate::utils::guess_entity_name;
 
 /// Display UI of the entity hierarchy.
 ///
+/// Entities can be reparented by dragging them onto another entity, or onto empty space to remove their parent.
+///
 /// Returns `true` if a new entity was selected.
 pub fn hierarchy_ui(world: &mut World, ui: &mut egui::Ui, selected: &mut SelectedEntities) -> bool {
-    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = super::type_registry::inspector_type_registry(world);
     let type_registry = type_registry.read();
 
     Hierarchy {
@@ -53,19 +57,73 @@ impl<T> Hierarchy<'_, T> {
         let mut entities: Vec<_> = root_query.iter(self.world).collect();
         entities.sort();
 
+        // nested headers have different ids, so this is derived once for the whole hierarchy
+        let dragged_id = ui.id().with("hierarchy dragged entity");
+        let dragged = ui.data(|data| data.get_temp::<Entity>(dragged_id));
+        let mut drop_target = None;
+
         let mut selected = false;
         for &entity in &entities {
-            selected |= self.entity_ui(ui, entity, &always_open, &entities);
+            selected |= self.entity_ui(
+                ui,
+                entity,
+                &always_open,
+                &entities,
+                dragged_id,
+                &mut drop_target,
+            );
         }
+
+        if let Some(dragged) = dragged {
+            if ui.input(|input| input.pointer.any_released()) {
+                ui.data_mut(|data| data.remove::<Entity>(dragged_id));
+                let drop_pos = ui.input(|input| input.pointer.interact_pos());
+                let over_hierarchy = drop_pos
+                    .is_some_and(|pos| ui.max_rect().intersect(ui.clip_rect()).contains(pos));
+                // the entities may have been despawned by the time a deferred queue is applied
+                let mut queue = CommandQueue::default();
+                match drop_target {
+                    Some(DropTarget::Entity(parent)) => queue.push(move |world: &mut World| {
+                        if world.get_entity(parent).is_none() {
+                            return;
+                        }
+                        if let Some(mut dragged) = world.get_entity_mut(dragged) {
+                            dragged.set_parent(parent);
+                        }
+                    }),
+                    Some(DropTarget::Rejected) => {}
+                    None if over_hierarchy => queue.push(move |world: &mut World| {
+                        if let Some(mut dragged) = world.get_entity_mut(dragged) {
+                            dragged.remove_parent();
+                        }
+                    }),
+                    None => {}
+                }
+                super::command_queue::apply_or_defer(self.world, &mut queue);
+            } else if !ui.input(|input| input.pointer.any_down()) {
+                ui.data_mut(|data| data.remove::<Entity>(dragged_id));
+            }
+        }
+
         selected
     }
 
+    /// Whether `entity` is `ancestor` or one of its descendants
+    fn is_descendant_of(&self, entity: Entity, ancestor: Entity) -> bool {
+        std::iter::successors(Some(entity), |&entity| {
+            self.world.get::<Parent>(entity).map(|parent| parent.get())
+        })
+        .any(|entity| entity == ancestor)
+    }
+
     fn entity_ui(
         &mut self,
         ui: &mut egui::Ui,
         entity: Entity,
         always_open: &HashSet<Entity>,
         at_same_level: &[Entity],
+        dragged_id: egui::Id,
+        drop_target: &mut Option<DropTarget>,
     ) -> bool {
         let mut new_selection = false;
         let selected = self.selected.contains(entity);
@@ -79,7 +137,7 @@ impl<T> Hierarchy<'_, T> {
         let has_children = self
             .world
             .get::<Children>(entity)
-            .map_or(false, |children| children.len() > 0);
+            .is_some_and(|children| !children.is_empty());
 
         let open = if !has_children {
             Some(false)
@@ -112,13 +170,40 @@ impl<T> Hierarchy<'_, T> {
                 if let Some(children) = children {
                     let children = children.to_vec();
                     for &child in children.iter() {
-                        self.entity_ui(ui, child, always_open, &children);
+                        self.entity_ui(ui, child, always_open, &children, dragged_id, drop_target);
                     }
                 } else {
                     ui.label("No children");
                 }
             });
         let header_response = response.header_response;
+        let header_response = header_response.union(header_response.interact(egui::Sense::drag()));
+
+        if header_response.hovered() {
+            if let Some(mut hover) = self.world.get_resource_mut::<InspectorHover>() {
+                hover.set(entity);
+            }
+        }
+
+        if header_response.drag_started() {
+            ui.data_mut(|data| data.insert_temp(dragged_id, entity));
+        }
+        if let Some(dragged) = ui.data(|data| data.get_temp::<Entity>(dragged_id)) {
+            let hovered = ui
+                .input(|input| input.pointer.interact_pos())
+                .is_some_and(|pos| header_response.rect.contains(pos));
+            if hovered {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+                if self.is_descendant_of(entity, dragged) {
+                    *drop_target = Some(DropTarget::Rejected);
+                } else {
+                    *drop_target = Some(DropTarget::Entity(entity));
+                    let rect = header_response.rect;
+                    let stroke = ui.visuals().selection.stroke;
+                    ui.painter().hline(rect.x_range(), rect.bottom(), stroke);
+                }
+            }
+        }
 
         if header_response.clicked() {
             let selection_mode = ui.input(|input| {
@@ -150,6 +235,15 @@ impl<T> Hierarchy<'_, T> {
     }
 }
 
+/// Where an entity dragged in the hierarchy is dropped
+#[derive(Clone, Copy)]
+enum DropTarget {
+    /// The dragged entity becomes a child of this entity
+    Entity(Entity),
+    /// The entity under the pointer is the dragged entity or one of its descendants
+    Rejected,
+}
+
 fn paint_default_icon(ui: &mut egui::Ui, openness: f32, response: &egui::Response) {
     let visuals = ui.style().interact(response);
     let stroke = visuals.fg_stroke;
@@ -174,7 +268,7 @@ fn paint_default_icon(ui: &mut egui::Ui, openness: f32, response: &egui::Respons
 }
 
 /// Collection of currently selected entities
-#[derive(Default, Debug)]
+#[derive(Resource, Default, Debug)]
 pub struct SelectedEntities {
     entities: Vec<Entity>,
     last_action: Option<(SelectionMode, Entity)>,
@@ -302,3 +396,176 @@ impl SelectedEntities {
         self.entities.as_slice()
     }
 }
+
+/// Link with the name of the `entity`, which selects it in the [`SelectedEntities`] resource when clicked
+pub(crate) fn entity_link(entity: Entity, ui: &mut egui::Ui, env: &mut InspectorUi<'_, '_>) {
+    let Context {
+        world: Some(world),
+        queue,
+    } = &mut env.context
+    else {
+        ui.label(format!("{entity:?}"));
+        return;
+    };
+    if !world.contains_entity(entity) {
+        ui.weak(format!("{entity:?} (despawned)"));
+        return;
+    }
+
+    let name = guess_entity_name::guess_entity_name_restricted(world, entity);
+    if !ui.link(name).on_hover_text(format!("{entity:?}")).clicked() {
+        return;
+    }
+    match (world.get_resource_mut::<SelectedEntities>(), queue) {
+        (Ok(mut selected), _) => selected.select_replace(entity),
+        // the selection might be taken out of the world while the hierarchy is shown
+        (Err(_), Some(queue)) => queue.push(move |world: &mut World| {
+            if let Some(mut selected) = world.get_resource_mut::<SelectedEntities>() {
+                selected.select_replace(entity);
+            }
+        }),
+        (Err(_), None) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::Parent;
+
+    use super::SelectedEntities;
+    use crate::bevy_inspector::command_queue::{apply_inspector_commands, InspectorCommandQueue};
+    use crate::utils::tests::TestUi;
+
+    #[test]
+    fn drag_to_reparent() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let alpha = world.spawn(Name::new("Alpha")).id();
+        let beta = world.spawn(Name::new("Beta")).id();
+
+        // keeps the parent of the selected entity open
+        let mut selected = SelectedEntities::default();
+        selected.select_replace(beta);
+
+        let mut test_ui = TestUi::new();
+        let mut show =
+            |world: &mut World, test_ui: &mut TestUi, drag: Option<(&str, egui::Vec2)>| {
+                let mut ui = |ui: &mut egui::Ui| {
+                    super::hierarchy_ui(world, ui, &mut selected);
+                };
+                test_ui.run(&mut ui);
+                if let Some((name, offset)) = drag {
+                    let from = test_ui.text_position(name).unwrap();
+                    test_ui.drag(from, from + offset, &mut ui);
+                }
+            };
+        show(&mut world, &mut test_ui, None);
+        let offset =
+            test_ui.text_position("Alpha").unwrap() - test_ui.text_position("Beta").unwrap();
+
+        show(&mut world, &mut test_ui, Some(("Beta", offset)));
+        assert_eq!(world.get::<Parent>(beta).map(Parent::get), Some(alpha));
+
+        // an entity can't become the child of its own descendant
+        show(&mut world, &mut test_ui, None);
+        let offset =
+            test_ui.text_position("Beta").unwrap() - test_ui.text_position("Alpha").unwrap();
+        show(&mut world, &mut test_ui, Some(("Alpha", offset)));
+        assert!(world.get::<Parent>(alpha).is_none());
+
+        // dropping onto empty space removes the parent
+        show(
+            &mut world,
+            &mut test_ui,
+            Some(("Beta", egui::vec2(0.0, 200.0))),
+        );
+        assert!(world.get::<Parent>(beta).is_none());
+    }
+
+    #[test]
+    fn drag_in_second_hierarchy() {
+        let spawn_world = |parent: &str, child: &str| {
+            let mut world = World::new();
+            world.init_resource::<AppTypeRegistry>();
+            let parent = world.spawn(Name::new(parent.to_string())).id();
+            let child = world.spawn(Name::new(child.to_string())).id();
+            (world, parent, child)
+        };
+        let (mut first, _, beta) = spawn_world("Alpha", "Beta");
+        let (mut second, gamma, delta) = spawn_world("Gamma", "Delta");
+
+        let mut first_selected = SelectedEntities::default();
+        let mut second_selected = SelectedEntities::default();
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            ui.push_id("first", |ui| {
+                super::hierarchy_ui(&mut first, ui, &mut first_selected)
+            });
+            ui.push_id("second", |ui| {
+                super::hierarchy_ui(&mut second, ui, &mut second_selected)
+            });
+        };
+        test_ui.run(&mut ui);
+        // the entities of both worlds have the same ids, which used to share the drag state
+        let from = test_ui.text_position("Delta").unwrap();
+        let to = test_ui.text_position("Gamma").unwrap();
+        test_ui.drag(from, to, &mut ui);
+
+        assert_eq!(second.get::<Parent>(delta).map(Parent::get), Some(gamma));
+        assert!(first.get::<Parent>(beta).is_none());
+    }
+
+    #[test]
+    fn deferred_reparent_to_despawned_entity() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorCommandQueue>();
+        let alpha = world.spawn(Name::new("Alpha")).id();
+        let beta = world.spawn(Name::new("Beta")).id();
+
+        let mut selected = SelectedEntities::default();
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            super::hierarchy_ui(&mut world, ui, &mut selected);
+        };
+        test_ui.run(&mut ui);
+        let from = test_ui.text_position("Beta").unwrap();
+        let to = test_ui.text_position("Alpha").unwrap();
+        test_ui.drag(from, to, &mut ui);
+
+        // the parent is gone before the queue is applied
+        world.despawn(alpha);
+        apply_inspector_commands(&mut world);
+        assert!(world.get::<Parent>(beta).is_none());
+    }
+
+    #[test]
+    fn ctrl_click_to_select_multiple() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let alpha = world.spawn(Name::new("Alpha")).id();
+        let beta = world.spawn(Name::new("Beta")).id();
+        let gamma = world.spawn(Name::new("Gamma")).id();
+
+        let mut selected = SelectedEntities::default();
+        let mut test_ui = TestUi::new();
+        let mut click = |selected: &mut SelectedEntities, name, modifiers| {
+            let mut ui = |ui: &mut egui::Ui| {
+                super::hierarchy_ui(&mut world, ui, selected);
+            };
+            test_ui.run(&mut ui);
+            let pos = test_ui.text_position(name).unwrap();
+            test_ui.click_with_modifiers(pos, modifiers, &mut ui);
+        };
+        click(&mut selected, "Alpha", egui::Modifiers::CTRL);
+        click(&mut selected, "Gamma", egui::Modifiers::CTRL);
+        assert_eq!(selected.as_slice(), &[alpha, gamma]);
+
+        // shift-clicking extends the selection from the last clicked entity
+        click(&mut selected, "Beta", egui::Modifiers::SHIFT);
+        assert_eq!(selected.len(), 2);
+        assert!(selected.contains(beta) && selected.contains(gamma));
+    }
+}
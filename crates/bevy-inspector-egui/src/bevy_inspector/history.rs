@@ -0,0 +1,295 @@
+//! Undo and redo for edits made through the inspector.
+//!
+//! Edits to components and resources are only recorded if the [`InspectorHistory`] resource exists:
+//! ```rust
+//! # use bevy_app::App;
+//! use bevy_inspector_egui::bevy_inspector::history::InspectorHistory;
+//!
+//! App::new().init_resource::<InspectorHistory>();
+//! ```
+//! The [`WorldInspectorPlugin`](crate::quick::WorldInspectorPlugin), [`ResourceInspectorPlugin`](crate::quick::ResourceInspectorPlugin)
+//! and [`FilterQueryInspectorPlugin`](crate::quick::FilterQueryInspectorPlugin) do this automatically
+//! and handle the `Ctrl+Z`/`Ctrl+Y` shortcuts using [`handle_shortcuts`].
+
+use std::any::TypeId;
+use std::collections::VecDeque;
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{Reflect, TypeRegistry};
+
+use crate::restricted_world_view::RestrictedWorldView;
+
+/// Default value of [`InspectorHistory::max_len`]
+pub const DEFAULT_MAX_HISTORY_LEN: usize = 100;
+
+/// Edits to the same value within this many seconds of each other are merged into a single undo step,
+/// so that dragging a value around can be undone at once.
+pub const COALESCE_SECONDS: f64 = 0.5;
+
+/// The value an edit was made to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditTarget {
+    Resource(TypeId),
+    Component(Entity, TypeId),
+}
+
+struct Edit {
+    target: EditTarget,
+    /// Value of the target before the edit was made (or after, in the redo stack)
+    snapshot: Box<dyn Reflect>,
+    time: f64,
+}
+
+/// Bounded list of edits which can be undone and redone
+#[derive(Resource)]
+pub struct InspectorHistory {
+    undo: VecDeque<Edit>,
+    redo: Vec<Edit>,
+    /// Maximum number of undo steps to keep, older ones are discarded
+    pub max_len: usize,
+}
+
+impl Default for InspectorHistory {
+    fn default() -> Self {
+        InspectorHistory::with_max_len(DEFAULT_MAX_HISTORY_LEN)
+    }
+}
+
+impl InspectorHistory {
+    pub fn with_max_len(max_len: usize) -> Self {
+        InspectorHistory {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            max_len,
+        }
+    }
+
+    /// Records that `target` was changed at `time` (in seconds), with `before` being its previous value.
+    ///
+    /// This discards everything that could be redone.
+    pub fn record(&mut self, target: EditTarget, before: Box<dyn Reflect>, time: f64) {
+        self.redo.clear();
+
+        if let Some(last) = self.undo.back_mut() {
+            if last.target == target && time - last.time < COALESCE_SECONDS {
+                last.time = time;
+                return;
+            }
+        }
+
+        self.undo.push_back(Edit {
+            target,
+            snapshot: before,
+            time,
+        });
+        while self.undo.len() > self.max_len {
+            self.undo.pop_front();
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Number of steps which can be undone
+    pub fn len(&self) -> usize {
+        self.undo.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.undo.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+/// Reverts the last recorded edit. Returns `false` if there was nothing to undo.
+pub fn undo(world: &mut World) -> bool {
+    step(world, true)
+}
+
+/// Re-applies the last undone edit. Returns `false` if there was nothing to redo.
+pub fn redo(world: &mut World) -> bool {
+    step(world, false)
+}
+
+/// Calls [`undo`] on `Ctrl+Z` and [`redo`] on `Ctrl+Y` or `Ctrl+Shift+Z`.
+///
+/// Nothing happens while a text field has keyboard focus, as it has its own undo.
+pub fn handle_shortcuts(world: &mut World, ctx: &egui::Context) {
+    if ctx.wants_keyboard_input() {
+        return;
+    }
+
+    let (undo_pressed, redo_pressed) = ctx.input_mut(|input| {
+        let redo = input.consume_key(egui::Modifiers::COMMAND, egui::Key::Y)
+            || input.consume_key(
+                egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+                egui::Key::Z,
+            );
+        let undo = input.consume_key(egui::Modifiers::COMMAND, egui::Key::Z);
+        (undo, redo)
+    });
+
+    if undo_pressed {
+        undo(world);
+    }
+    if redo_pressed {
+        redo(world);
+    }
+}
+
+fn step(world: &mut World, undo: bool) -> bool {
+    if !world.contains_resource::<InspectorHistory>() {
+        return false;
+    }
+    let type_registry = super::type_registry::inspector_type_registry(world);
+    let type_registry = type_registry.read();
+
+    world.resource_scope(|world, mut history: Mut<InspectorHistory>| {
+        let edit = match undo {
+            true => history.undo.pop_back(),
+            false => history.redo.pop(),
+        };
+        let Some(edit) = edit else {
+            return false;
+        };
+
+        let Some(current) = apply_snapshot(world, edit.target, &*edit.snapshot, &type_registry)
+        else {
+            return false;
+        };
+        let edit = Edit {
+            snapshot: current,
+            ..edit
+        };
+        match undo {
+            true => history.redo.push(edit),
+            false => history.undo.push_back(edit),
+        }
+        true
+    })
+}
+
+/// Applies the snapshot to the target and returns the value it had before
+fn apply_snapshot(
+    world: &mut World,
+    target: EditTarget,
+    snapshot: &dyn Reflect,
+    type_registry: &TypeRegistry,
+) -> Option<Box<dyn Reflect>> {
+    let mut world = RestrictedWorldView::new(world);
+    match target {
+        EditTarget::Resource(type_id) => {
+            let (value, set_changed) = world
+                .get_resource_reflect_mut_by_id(type_id, type_registry)
+                .ok()?;
+            let current = value.clone_value();
+            value.apply(snapshot);
+            set_changed();
+            Some(current)
+        }
+        EditTarget::Component(entity, type_id) => {
+            let (value, _, set_changed) = world
+                .get_entity_component_reflect(entity, type_id, type_registry)
+                .ok()?;
+            let current = value.clone_value();
+            value.apply(snapshot);
+            set_changed();
+            Some(current)
+        }
+    }
+}
+
+pub(crate) fn record(
+    world: &mut RestrictedWorldView<'_>,
+    target: EditTarget,
+    before: Box<dyn Reflect>,
+    time: f64,
+) {
+    if let Ok(mut history) = world.get_resource_mut::<InspectorHistory>() {
+        history.record(target, before, time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_reflect::Reflect;
+
+    use super::{EditTarget, InspectorHistory};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    fn world() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorHistory>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn(Health(10.0)).id();
+        (world, entity)
+    }
+
+    fn edit(world: &mut World, entity: Entity, value: f32, time: f64) {
+        let mut health = world.get_mut::<Health>(entity).unwrap();
+        let before = health.clone_value();
+        health.0 = value;
+        let target = EditTarget::Component(entity, TypeId::of::<Health>());
+        world
+            .resource_mut::<InspectorHistory>()
+            .record(target, before, time);
+    }
+
+    #[test]
+    fn undo_restores_original() {
+        let (mut world, entity) = world();
+
+        edit(&mut world, entity, 5.0, 0.0);
+        assert!(super::undo(&mut world));
+        assert_eq!(world.get::<Health>(entity), Some(&Health(10.0)));
+        assert!(!super::undo(&mut world));
+
+        assert!(super::redo(&mut world));
+        assert_eq!(world.get::<Health>(entity), Some(&Health(5.0)));
+    }
+
+    #[test]
+    fn rapid_edits_coalesce() {
+        let (mut world, entity) = world();
+
+        edit(&mut world, entity, 9.0, 0.0);
+        edit(&mut world, entity, 8.0, 0.1);
+        edit(&mut world, entity, 7.0, 0.2);
+        edit(&mut world, entity, 1.0, 5.0);
+        assert_eq!(world.resource::<InspectorHistory>().len(), 2);
+
+        super::undo(&mut world);
+        assert_eq!(world.get::<Health>(entity), Some(&Health(7.0)));
+        super::undo(&mut world);
+        assert_eq!(world.get::<Health>(entity), Some(&Health(10.0)));
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let (mut world, entity) = world();
+        world.resource_mut::<InspectorHistory>().max_len = 3;
+
+        for i in 0..10 {
+            edit(&mut world, entity, i as f32, i as f64);
+        }
+        assert_eq!(world.resource::<InspectorHistory>().len(), 3);
+    }
+}
@@ -0,0 +1,66 @@
+//! Keeping track of the entity under the pointer in the inspector, so that it can be highlighted elsewhere, like in the viewport.
+//!
+//! The [`hierarchy`](super::hierarchy) and the component lists of entities report the entity they show while hovered
+//! to the [`InspectorHover`] resource, if it exists.
+
+use bevy_ecs::prelude::*;
+
+/// The entity whose hierarchy row or components were hovered in the inspector since the last [`InspectorHover::take`]
+#[derive(Resource, Default, Debug)]
+pub struct InspectorHover {
+    entity: Option<Entity>,
+}
+
+impl InspectorHover {
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// Returns the hovered entity and resets it, so that it is only reported again if it was hovered since
+    pub fn take(&mut self) -> Option<Entity> {
+        self.entity.take()
+    }
+
+    pub(crate) fn set(&mut self, entity: Entity) {
+        self.entity = Some(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+
+    use super::InspectorHover;
+    use crate::bevy_inspector::hierarchy::{hierarchy_ui, SelectedEntities};
+    use crate::utils::tests::TestUi;
+
+    #[test]
+    fn hierarchy_reports_hover() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorHover>();
+        world.spawn(Name::new("Player"));
+        let enemy = world.spawn(Name::new("Enemy")).id();
+
+        let mut selected = SelectedEntities::default();
+        let hovered = Cell::new(None);
+        let mut add_contents = |ui: &mut egui::Ui| {
+            hierarchy_ui(&mut world, ui, &mut selected);
+            hovered.set(world.resource_mut::<InspectorHover>().take());
+        };
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert_eq!(hovered.get(), None);
+
+        // the pointer stays above the row after clicking it
+        let row = test_ui.text_position("Enemy").unwrap();
+        test_ui.click(row, &mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert_eq!(hovered.get(), Some(enemy));
+    }
+}
@@ -37,23 +37,55 @@
 //! ```
 
 use std::any::TypeId;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use bevy_asset::{Asset, Assets, ReflectAsset};
+use bevy_core::Name;
 use bevy_ecs::query::ReadOnlyWorldQuery;
-use bevy_ecs::reflect::AppTypeRegistry;
 use bevy_ecs::system::CommandQueue;
-use bevy_ecs::{component::ComponentId, prelude::*};
-use bevy_hierarchy::{Children, Parent};
-use bevy_reflect::{Reflect, TypeRegistry};
+use bevy_ecs::{
+    component::{ComponentId, ComponentTicks, Tick},
+    prelude::*,
+};
+use bevy_hierarchy::{BuildWorldChildren, Children, Parent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, ReflectRef, TypeRegistry};
+use bevy_time::{fixed_timestep::FixedTime, Time};
+use bevy_utils::Duration;
 use pretty_type_name::pretty_type_name;
 
+use type_registry::inspector_type_registry;
+
+mod entity_map;
 pub(crate) mod errors;
 
 /// UI for displaying the entity hierarchy
 pub mod hierarchy;
 
-use crate::reflect_inspector::{Context, InspectorUi};
-use crate::restricted_world_view::RestrictedWorldView;
+pub mod command_queue;
+pub mod edit_log;
+pub mod history;
+pub mod hover;
+pub mod pins;
+pub mod source_link;
+pub mod theme;
+pub mod type_label;
+pub mod type_registry;
+pub mod value_search;
+
+use crate::egui_utils;
+use crate::reflect_inspector::{
+    breadcrumb_ui, default_diff, field_filter, value_summary, Context, InspectorUi,
+};
+use crate::restricted_world_view::{
+    Error as RestrictedWorldViewError, RestrictedWorldView, TypeRegistryCache,
+};
+use crate::utils::guess_entity_name::guess_entity_name_restricted;
+use history::EditTarget;
+use hover::InspectorHover;
+use pins::{InspectorPins, PinTarget};
+use source_link::TypeSourceResolver;
+use theme::InspectorTheme;
+use type_label::TypeLabelFormatter;
 
 /// Display a single [`&mut dyn Reflect`](bevy_reflect::Reflect).
 ///
@@ -63,7 +95,21 @@ use crate::restricted_world_view::RestrictedWorldView;
 /// If all you're displaying is a simple value without any references into the bevy world, consider just using
 /// [`reflect_inspector::ui_for_value`](crate::reflect_inspector::ui_for_value).
 pub fn ui_for_value(value: &mut dyn Reflect, ui: &mut egui::Ui, world: &mut World) -> bool {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    ui_for_reflect_with_world(value, world, ui, egui::Id::null())
+}
+
+/// Like [`ui_for_value`], but with an `id` which keeps the UI state of several values apart.
+///
+/// The value doesn't need to be stored in the `world`, which is only used for the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) (or [`InspectorTypeRegistry`](type_registry::InspectorTypeRegistry))
+/// and for looking up things like the assets of handles.
+/// Commands queued by the UI, e.g. for spawning entities, are applied to the `world` afterwards.
+pub fn ui_for_reflect_with_world(
+    value: &mut dyn Reflect,
+    world: &mut World,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> bool {
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
 
     let mut queue = CommandQueue::default();
@@ -72,17 +118,146 @@ pub fn ui_for_value(value: &mut dyn Reflect, ui: &mut egui::Ui, world: &mut Worl
         queue: Some(&mut queue),
     };
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
-    let changed = env.ui_for_reflect(value, ui);
-    queue.apply(world);
+    let changed = env.ui_for_reflect_with_options(value, ui, id, &());
+    command_queue::apply_or_defer(world, &mut queue);
     changed
 }
 
+/// Options for the entity UI of [`ui_for_world_with_options`] and related functions
+///
+/// ```rust
+/// # use bevy_inspector_egui::bevy_inspector::WorldInspectorOptions;
+/// # use bevy_ecs::prelude::*;
+/// # #[derive(Component)] struct Marker;
+/// let mut options = WorldInspectorOptions::default();
+/// options.hide_component::<Marker>();
+/// options.hide_module("bevy_render::view");
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct WorldInspectorOptions {
+    /// Components which are not displayed
+    pub hidden_components: HashSet<TypeId>,
+    /// Components whose type is defined in one of these modules or their submodules are not displayed
+    pub hidden_modules: Vec<String>,
+    /// Show an "Add Component" menu and a button to remove each component
+    pub edit_components: bool,
+    /// Only display components which changed since the inspector was last shown, and the entities containing them
+    pub only_changed: bool,
+    /// Show the ticks at which each component was added and last changed at the top of its header
+    pub show_change_ticks: bool,
+    /// Show the components under collapsible headers for the crate they are defined in, like `bevy_render`
+    pub group_by_crate: bool,
+    /// Order in which the components of entities are listed
+    pub component_order: ComponentOrder,
+    /// Show the fields which differ from the default value of their component in bold, and a `•` next to the
+    /// headers of such components, see [`InspectorUi::with_default_highlight`]
+    pub highlight_non_default: bool,
+}
+
+/// Order of the components of an entity, see [`WorldInspectorOptions::component_order`]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComponentOrder {
+    /// Sorted by the displayed type name, so they don't jump around when the entity moves to another archetype
+    #[default]
+    Alphabetical,
+    /// The order of the entity's archetype, which is the order in which the component types were first used in the world
+    Archetype,
+}
+
+impl WorldInspectorOptions {
+    pub fn hide_component<T: 'static>(&mut self) -> &mut Self {
+        self.hidden_components.insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Hide all components defined in the `module` or its submodules, e.g. `bevy_render` or `bevy_render::view`.
+    ///
+    /// The module has to match whole path segments, so `bevy_render` doesn't hide the components of `bevy_render_foo`.
+    pub fn hide_module(&mut self, module: impl Into<String>) -> &mut Self {
+        self.hidden_modules.push(module.into());
+        self
+    }
+
+    /// Allow adding components with a default value and removing components, see [`WorldInspectorOptions::edit_components`]
+    pub fn edit_components(&mut self, edit_components: bool) -> &mut Self {
+        self.edit_components = edit_components;
+        self
+    }
+
+    /// Only show what changed since the last frame, see [`WorldInspectorOptions::only_changed`]
+    pub fn only_changed(&mut self, only_changed: bool) -> &mut Self {
+        self.only_changed = only_changed;
+        self
+    }
+
+    /// Show when components were added and changed, see [`WorldInspectorOptions::show_change_ticks`]
+    pub fn show_change_ticks(&mut self, show_change_ticks: bool) -> &mut Self {
+        self.show_change_ticks = show_change_ticks;
+        self
+    }
+
+    /// Group the components of entities by their crate, see [`WorldInspectorOptions::group_by_crate`]
+    pub fn group_by_crate(&mut self, group_by_crate: bool) -> &mut Self {
+        self.group_by_crate = group_by_crate;
+        self
+    }
+
+    /// How the components of entities are sorted, see [`WorldInspectorOptions::component_order`]
+    pub fn component_order(&mut self, component_order: ComponentOrder) -> &mut Self {
+        self.component_order = component_order;
+        self
+    }
+
+    /// Highlight what differs from the default values, see [`WorldInspectorOptions::highlight_non_default`]
+    pub fn highlight_non_default(&mut self, highlight_non_default: bool) -> &mut Self {
+        self.highlight_non_default = highlight_non_default;
+        self
+    }
+
+    pub fn is_hidden(&self, type_id: Option<TypeId>, type_name: &str) -> bool {
+        type_id.is_some_and(|type_id| self.hidden_components.contains(&type_id))
+            || self
+                .hidden_modules
+                .iter()
+                .any(|module| in_module(type_name, module))
+    }
+}
+
+/// Whether the `type_name` is the path of the `module` itself or of an item inside of it
+fn in_module(type_name: &str, module: &str) -> bool {
+    type_name
+        .strip_prefix(module)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
 /// Display `Entities`, `Resources` and `Assets` using their respective functions inside headers
+///
+/// Values pinned in the [`InspectorPins`] are shown above them in a `Pinned` header.
 pub fn ui_for_world(world: &mut World, ui: &mut egui::Ui) {
+    ui_for_world_with_options(world, ui, &WorldInspectorOptions::default());
+}
+
+/// Like [`ui_for_world`], but entities are displayed according to the given [`WorldInspectorOptions`]
+pub fn ui_for_world_with_options(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    options: &WorldInspectorOptions,
+) {
+    pins::remove_despawned(world);
+    if world
+        .get_resource::<InspectorPins>()
+        .is_some_and(|pins| !pins.is_empty())
+    {
+        egui::CollapsingHeader::new("Pinned")
+            .default_open(true)
+            .show(ui, |ui| {
+                ui_for_pinned(world, ui, options);
+            });
+    }
     egui::CollapsingHeader::new("Entities")
         .default_open(true)
         .show(ui, |ui| {
-            ui_for_world_entities(world, ui);
+            ui_for_world_entities_with_options(world, ui, options);
         });
     egui::CollapsingHeader::new("Resources").show(ui, |ui| {
         ui_for_resources(world, ui);
@@ -94,57 +269,210 @@ pub fn ui_for_world(world: &mut World, ui: &mut egui::Ui) {
 
 /// Display all reflectable resources in the world
 pub fn ui_for_resources(world: &mut World, ui: &mut egui::Ui) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
 
+    let formatter = world.get_resource::<TypeLabelFormatter>().copied();
     let mut resources: Vec<_> = type_registry
         .iter()
         .filter(|registration| registration.data::<ReflectResource>().is_some())
-        .map(|registration| (registration.short_name().to_owned(), registration.type_id()))
+        .map(|registration| {
+            (
+                registration_label(registration, formatter),
+                registration.type_id(),
+            )
+        })
         .collect();
     resources.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
     for (name, type_id) in resources {
-        ui.collapsing(&name, |ui| {
+        let response = ui.collapsing(&name, |ui| {
             by_type_id::ui_for_resource(world, type_id, ui, &name, &type_registry);
         });
+        pins::context_menu(
+            world,
+            response.header_response,
+            PinTarget::Resource(type_id),
+        );
+    }
+}
+
+/// Display the entities and resources pinned in the [`InspectorPins`] resource
+pub fn ui_for_pinned(world: &mut World, ui: &mut egui::Ui, options: &WorldInspectorOptions) {
+    let type_registry = inspector_type_registry(world);
+    let type_registry = type_registry.read();
+    let registry_cache = TypeRegistryCache::new(&type_registry);
+
+    let pinned = match world.get_resource::<InspectorPins>() {
+        Some(pins) => pins.iter().collect::<Vec<_>>(),
+        None => return,
+    };
+
+    let id = ui.make_persistent_id("pinned");
+    for target in pinned {
+        match target {
+            PinTarget::Entity(entity) => {
+                if world.get_entity(entity).is_none() {
+                    continue;
+                }
+                let id = id.with(entity);
+                let entity_name = guess_entity_name(world, entity);
+                egui::collapsing_header::CollapsingState::load_with_default_open(
+                    ui.ctx(),
+                    id.with("header"),
+                    false,
+                )
+                .show_header(ui, |ui| {
+                    let response = ui.label(&entity_name);
+                    entity_context_menu(world, response, entity);
+                })
+                .body(|ui| {
+                    ui_for_entity_with_children_inner(
+                        world,
+                        entity,
+                        ui,
+                        id,
+                        &registry_cache,
+                        options,
+                        &HashSet::new(),
+                    );
+                });
+            }
+            PinTarget::Resource(type_id) => {
+                let formatter = world.get_resource::<TypeLabelFormatter>().copied();
+                let name = match type_registry.get(type_id) {
+                    Some(registration) => registration_label(registration, formatter),
+                    None => continue,
+                };
+                let response = ui.collapsing(&name, |ui| {
+                    by_type_id::ui_for_resource(world, type_id, ui, &name, &type_registry);
+                });
+                pins::context_menu(world, response.header_response, target);
+            }
+        }
     }
 }
 
 /// Display the resource `R`
 pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
 
     // create a context with access to the world except for the `R` resource
-    let Some((mut resource, world_view)) =
-        RestrictedWorldView::new(world).split_off_resource_typed::<R>()
+    let Ok(Some((mut resource, mut world_view))) =
+        RestrictedWorldView::new(world).try_split_off_resource_typed::<R>()
     else {
         errors::resource_does_not_exist(ui, &pretty_type_name::<R>());
         return;
     };
+    let before = snapshot_before_edit(Some(&mut world_view), resource.as_reflect(), ui);
     let mut queue = CommandQueue::default();
     let mut cx = Context {
         world: Some(world_view),
         queue: Some(&mut queue),
     };
-    let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+    let filter = field_filter_ui(ui, egui::Id::new(TypeId::of::<R>()), resource.as_reflect());
+    let (changed, changes) = edit_log::collect_changes(|on_change| {
+        InspectorUi::for_bevy(&type_registry, &mut cx)
+            .with_field_filter(&filter)
+            .with_field_changed_callback(on_change)
+            .ui_for_reflect(resource.bypass_change_detection(), ui)
+    });
+    if edit_changed(changed, resource.as_reflect(), before.as_deref()) {
+        resource.set_changed();
+        if let (Some(world), Some(before)) = (cx.world.as_mut(), before) {
+            let target = EditTarget::Resource(TypeId::of::<R>());
+            edit_log::send(world, target, &*before, changes);
+            history::record(world, target, before, ui.input(|input| input.time));
+        }
+    }
+
+    command_queue::apply_or_defer(world, &mut queue);
+}
+
+/// Display the non-send resource `R`, inserted with [`World::insert_non_send_resource`].
+///
+/// Non-send resources may only be accessed from the main thread, so this panics when called from another thread.
+/// Exclusive systems always run on the main thread.
+/// Since [`Reflect`] requires `Send + Sync`, `R` can't be a `!Send` type itself, but e.g. a value which is
+/// stored as a non-send resource because it's shared with main-thread-only code.
+///
+/// Unlike for [`ui_for_resource`], edits aren't recorded in the [`InspectorHistory`](history::InspectorHistory).
+pub fn ui_for_non_send_resource<R: Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = inspector_type_registry(world);
+    let type_registry = type_registry.read();
 
-    if env.ui_for_reflect(resource.bypass_change_detection(), ui) {
+    // create a context with access to the world except for the `R` non-send resource
+    let Ok(Some((mut resource, mut world_view))) =
+        RestrictedWorldView::new(world).try_split_off_non_send_resource_typed::<R>()
+    else {
+        errors::resource_does_not_exist(ui, &pretty_type_name::<R>());
+        return;
+    };
+    let before = snapshot_before_edit(Some(&mut world_view), resource.as_reflect(), ui);
+    let mut queue = CommandQueue::default();
+    let mut cx = Context {
+        world: Some(world_view),
+        queue: Some(&mut queue),
+    };
+    let filter = field_filter_ui(ui, egui::Id::new(TypeId::of::<R>()), resource.as_reflect());
+    let (changed, changes) = edit_log::collect_changes(|on_change| {
+        InspectorUi::for_bevy(&type_registry, &mut cx)
+            .with_field_filter(&filter)
+            .with_field_changed_callback(on_change)
+            .ui_for_reflect(resource.bypass_change_detection(), ui)
+    });
+    if edit_changed(changed, resource.as_reflect(), before.as_deref()) {
         resource.set_changed();
+        if let (Some(world), Some(before)) = (cx.world.as_mut(), before) {
+            let target = EditTarget::Resource(TypeId::of::<R>());
+            edit_log::send(world, target, &*before, changes);
+        }
+    }
+
+    command_queue::apply_or_defer(world, &mut queue);
+}
+
+/// Resources and components with at least this many fields, counting nested ones, get a text field for filtering them
+const FIELD_FILTER_MIN_FIELDS: usize = 8;
+
+/// Maximum number of characters of the value summary next to the title of a collapsed component header
+const HEADER_SUMMARY_LEN: usize = 60;
+
+/// Shows the [`field_filter_ui`](crate::reflect_inspector::field_filter_ui) if the value has enough fields to need one,
+/// and returns the filter to pass to [`InspectorUi::with_field_filter`]
+fn field_filter_ui(ui: &mut egui::Ui, id: egui::Id, value: &dyn Reflect) -> String {
+    if !field_filter::has_fields(value, FIELD_FILTER_MIN_FIELDS) {
+        return String::new();
     }
+    field_filter::field_filter_ui(ui, id, value)
+}
 
-    queue.apply(world);
+/// Header label of a registered type, from the [`TypeLabelFormatter`] if there is one
+fn registration_label(
+    registration: &bevy_reflect::TypeRegistration,
+    formatter: Option<TypeLabelFormatter>,
+) -> String {
+    match formatter {
+        Some(formatter) => formatter.format(registration.type_id(), registration.type_name()),
+        None => registration.short_name().to_owned(),
+    }
 }
 
 /// Display all reflectable assets
 pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
 
+    let formatter = world.get_resource::<TypeLabelFormatter>().copied();
     let mut assets: Vec<_> = type_registry
         .iter()
         .filter(|registration| registration.data::<ReflectAsset>().is_some())
-        .map(|registration| (registration.short_name().to_owned(), registration.type_id()))
+        .map(|registration| {
+            (
+                registration_label(registration, formatter),
+                registration.type_id(),
+            )
+        })
         .collect();
     assets.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
     for (name, type_id) in assets {
@@ -156,12 +484,12 @@ pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
 
 /// Display all assets of the specified asset type `A`
 pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
 
     // create a context with access to the world except for the `R` resource
-    let Some((mut assets, world_view)) =
-        RestrictedWorldView::new(world).split_off_resource_typed::<Assets<A>>()
+    let Ok(Some((mut assets, world_view))) =
+        RestrictedWorldView::new(world).try_split_off_resource_typed::<Assets<A>>()
     else {
         errors::resource_does_not_exist(ui, &pretty_type_name::<Assets<A>>());
         return;
@@ -173,7 +501,7 @@ pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     };
 
     let mut assets: Vec<_> = assets.iter_mut().collect();
-    assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+    assets.sort_by_key(|(handle_id, _)| *handle_id);
     for (handle_id, asset) in assets {
         let id = egui::Id::new(handle_id);
 
@@ -185,22 +513,23 @@ pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
             });
     }
 
-    queue.apply(world);
+    command_queue::apply_or_defer(world, &mut queue);
 }
 
 /// Display state `T` and change state on edit
 pub fn ui_for_state<T: States + Reflect>(world: &mut World, ui: &mut egui::Ui) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
 
     // create a context with access to the world except for the `State<T>` resource
-    let Some((state, world_view)) =
-        RestrictedWorldView::new(world).split_off_resource_typed::<State<T>>()
+    let Ok(Some((state, world_view))) =
+        RestrictedWorldView::new(world).try_split_off_resource_typed::<State<T>>()
     else {
         errors::state_does_not_exist(ui, &pretty_type_name::<T>());
         return;
     };
-    let Some((mut next_state, world_view)) = world_view.split_off_resource_typed::<NextState<T>>()
+    let Ok(Some((mut next_state, world_view))) =
+        world_view.try_split_off_resource_typed::<NextState<T>>()
     else {
         errors::state_does_not_exist(ui, &pretty_type_name::<T>());
         return;
@@ -218,12 +547,157 @@ pub fn ui_for_state<T: States + Reflect>(world: &mut World, ui: &mut egui::Ui) {
     if changed {
         next_state.0 = Some(current);
     }
-    queue.apply(world);
+    command_queue::apply_or_defer(world, &mut queue);
+}
+
+/// Display the timestep of the [`FixedTime`] resource and the time accumulated towards the next step,
+/// with buttons to pause the [`Time`] it is accumulated from and to step the `FixedUpdate` schedule.
+///
+/// Stepping adds one timestep to the accumulated time, so that the `FixedUpdate` systems run once more in the next frame,
+/// even while the time is paused. `FixedTime` isn't reflected, so this can't be shown by [`ui_for_resource`].
+pub fn ui_for_fixed_time(world: &mut World, ui: &mut egui::Ui) {
+    let paused = world.get_resource::<Time>().map(Time::is_paused);
+    let Some(mut fixed_time) = world.get_resource_mut::<FixedTime>() else {
+        errors::resource_does_not_exist(ui, "FixedTime");
+        return;
+    };
+
+    egui::Grid::new(ui.id().with("fixed time"))
+        .num_columns(2)
+        .show(ui, |ui| {
+            let millis = |duration: Duration| format!("{:.2} ms", duration.as_secs_f64() * 1000.0);
+            ui.label("Timestep");
+            ui.weak(millis(fixed_time.period));
+            ui.end_row();
+            ui.label("Accumulated");
+            ui.weak(millis(fixed_time.accumulated()));
+            ui.end_row();
+        });
+
+    let mut toggle_pause = false;
+    ui.horizontal(|ui| {
+        if let Some(paused) = paused {
+            toggle_pause = ui
+                .button(if paused { "▶ Resume" } else { "⏸ Pause" })
+                .clicked();
+        }
+        if ui
+            .button("Step")
+            .on_hover_text("Run the FixedUpdate schedule once more")
+            .clicked()
+        {
+            let period = fixed_time.period;
+            fixed_time.tick(period);
+        }
+    });
+
+    if toggle_pause {
+        let mut time = world.resource_mut::<Time>();
+        match time.is_paused() {
+            true => time.unpause(),
+            false => time.pause(),
+        }
+    }
 }
 
 /// Display all entities and their components
 pub fn ui_for_world_entities(world: &mut World, ui: &mut egui::Ui) {
-    ui_for_world_entities_filtered::<Without<Parent>>(world, ui, true);
+    ui_for_world_entities_with_options(world, ui, &WorldInspectorOptions::default());
+}
+
+/// Display all entities and their components, except for those hidden by the [`WorldInspectorOptions`]
+///
+/// An "Only changed" checkbox above the entities enables [`WorldInspectorOptions::only_changed`],
+/// and entities matching the [`value_search`] query typed into the search field are highlighted.
+pub fn ui_for_world_entities_with_options(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    options: &WorldInspectorOptions,
+) {
+    let id = ui.id().with("world ui");
+    let only_changed_id = id.with("only changed");
+    let mut only_changed = ui
+        .data_mut(|data| data.get_temp::<bool>(only_changed_id))
+        .unwrap_or(options.only_changed);
+    ui.checkbox(&mut only_changed, "Only changed")
+        .on_hover_text("Only show components which changed since the last frame");
+    ui.data_mut(|data| data.insert_temp(only_changed_id, only_changed));
+
+    let highlighted = value_search_ui(world, ui, id.with("value search"));
+
+    if only_changed != options.only_changed {
+        let mut options = options.clone();
+        options.only_changed(only_changed);
+        entities_ui::<(), Without<Parent>>(world, ui, true, &options, &highlighted);
+    } else {
+        entities_ui::<(), Without<Parent>>(world, ui, true, options, &highlighted);
+    }
+}
+
+/// Text field for a [`value_search::ValueQuery`], returning the matching entities
+fn value_search_ui(world: &World, ui: &mut egui::Ui, search_id: egui::Id) -> HashSet<Entity> {
+    let mut search = ui
+        .data_mut(|data| data.get_temp::<String>(search_id))
+        .unwrap_or_default();
+    ui.add(
+        egui::TextEdit::singleline(&mut search)
+            .hint_text("Search values, e.g. Health.0 < 10")
+            .desired_width(f32::INFINITY),
+    );
+    ui.data_mut(|data| data.insert_temp(search_id, search.clone()));
+
+    if search.trim().is_empty() {
+        return HashSet::new();
+    }
+
+    // parsing the query and looking up its component is only redone when the search changes,
+    // and the matching entities only when the world has changed as well
+    let cache_id = search_id.with("cache");
+    let change_tick = world.read_change_tick();
+    let cache = ui
+        .data_mut(|data| data.get_temp::<ValueSearchCache>(cache_id))
+        .filter(|cache| cache.search == search);
+    let mut cache = cache.unwrap_or_else(|| {
+        let query = search.parse::<value_search::ValueQuery>().map(|query| {
+            let type_registry = inspector_type_registry(world);
+            let type_registry = type_registry.read();
+            query.resolve(&type_registry)
+        });
+        ValueSearchCache {
+            search,
+            query,
+            matching: None,
+        }
+    });
+
+    let matching = match &cache.query {
+        Ok(query) => {
+            let matching = match cache.matching.take() {
+                Some((tick, matching)) if tick == change_tick => matching,
+                _ => query.matching_entities(world).into_iter().collect(),
+            };
+            ui.weak(match matching.len() {
+                1 => "1 matching entity".to_string(),
+                n => format!("{n} matching entities"),
+            });
+            cache.matching = Some((change_tick, matching.clone()));
+            matching
+        }
+        Err(error) => {
+            ui.colored_label(ui.visuals().error_fg_color, error.to_string());
+            HashSet::new()
+        }
+    };
+    ui.data_mut(|data| data.insert_temp(cache_id, cache));
+    matching
+}
+
+/// The last [`value_search_ui`] query, and its matching entities at a change tick
+#[derive(Clone)]
+struct ValueSearchCache {
+    search: String,
+    query: Result<value_search::ResolvedValueQuery, value_search::ParseError>,
+    matching: Option<(Tick, HashSet<Entity>)>,
 }
 
 /// Display all entities matching the given filter
@@ -232,49 +706,248 @@ pub fn ui_for_world_entities_filtered<F: ReadOnlyWorldQuery>(
     ui: &mut egui::Ui,
     with_children: bool,
 ) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    entities_ui::<(), F>(
+        world,
+        ui,
+        with_children,
+        &WorldInspectorOptions::default(),
+        &HashSet::new(),
+    );
+}
+
+/// Display a flat list of the entities matching the query `Q` with the filter `F`, according to the [`WorldInspectorOptions`]
+pub fn ui_for_world_entities_query<Q: ReadOnlyWorldQuery, F: ReadOnlyWorldQuery>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    options: &WorldInspectorOptions,
+) {
+    entities_ui::<Q, F>(world, ui, false, options, &HashSet::new());
+}
+
+fn entities_ui<Q: ReadOnlyWorldQuery, F: ReadOnlyWorldQuery>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    with_children: bool,
+    options: &WorldInspectorOptions,
+    highlighted: &HashSet<Entity>,
+) {
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
+    let type_registry = TypeRegistryCache::new(&type_registry);
 
-    let mut root_entities = world.query_filtered::<Entity, F>();
-    let mut entities = root_entities.iter(world).collect::<Vec<_>>();
+    let mut root_entities = world.query_filtered::<(Entity, Q), F>();
+    let mut entities = root_entities
+        .iter(world)
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
     entities.sort();
 
-    let id = egui::Id::new("world ui");
+    let id = ui.id().with("world ui");
+
+    // Only entities intersecting the visible part of the ui are displayed, the others just take up the space
+    // they had when they were last visible. Entities which were never visible are assumed to be collapsed.
+    let heights_id = id.with("row heights");
+    let mut row_heights = ui.data_mut(|data| {
+        std::mem::take(data.get_temp_mut_or_default::<HashMap<Entity, f32>>(heights_id))
+    });
+    let item_spacing = ui.spacing().item_spacing.y;
+    let collapsed_height = ui.spacing().interact_size.y + item_spacing;
+
     for entity in entities {
+        if options.only_changed && !has_changes(&mut world.into(), entity, with_children, options) {
+            continue;
+        }
         let id = id.with(entity);
 
-        let entity_name = guess_entity_name(world, entity);
+        let top = ui.cursor().top();
+        let height = row_heights
+            .get(&entity)
+            .copied()
+            .unwrap_or(collapsed_height);
+        let row =
+            egui::Rect::from_min_size(ui.cursor().min, egui::vec2(ui.available_width(), height));
+        if !ui.is_rect_visible(row) {
+            ui.allocate_space(egui::vec2(row.width(), height - item_spacing));
+            continue;
+        }
 
-        egui::CollapsingHeader::new(&entity_name)
-            .id_source(id)
-            .show(ui, |ui| {
+        let state_id = ui.make_persistent_id(id);
+        let mut title_clicked = false;
+        egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), state_id, false)
+            .show_header(ui, |ui| {
+                let renaming = ui.data(|data| data.get_temp::<String>(id.with("rename")).is_some());
+                let mut queue = CommandQueue::default();
+                let response = entity_title(&mut world.into(), &mut queue, ui, id, entity);
+                command_queue::apply_or_defer(world, &mut queue);
+                if highlighted.contains(&entity) {
+                    let stroke = ui.visuals().selection.stroke;
+                    ui.painter()
+                        .rect_stroke(response.rect.expand(2.0), 2.0, stroke);
+                }
+                title_clicked = response.clicked() && !renaming;
+                entity_context_menu(world, response, entity);
+            })
+            .body(|ui| {
                 if with_children {
-                    ui_for_entity_with_children_inner(world, entity, ui, id, &type_registry);
+                    ui_for_entity_with_children_inner(
+                        world,
+                        entity,
+                        ui,
+                        id,
+                        &type_registry,
+                        options,
+                        highlighted,
+                    );
                 } else {
                     let mut queue = CommandQueue::default();
-                    ui_for_entity_components(
+                    entity_components_ui(
                         &mut world.into(),
                         Some(&mut queue),
                         entity,
                         ui,
                         id,
                         &type_registry,
+                        options,
+                        None,
                     );
-                    queue.apply(world);
+                    command_queue::apply_or_defer(world, &mut queue);
                 }
             });
+
+        // clicking the name toggles the entity like the arrow does, double-clicking it toggles back and starts renaming
+        if title_clicked {
+            let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
+                ui.ctx(),
+                state_id,
+                false,
+            );
+            state.toggle(ui);
+            state.store(ui.ctx());
+        }
+
+        row_heights.insert(entity, ui.cursor().top() - top);
+    }
+
+    ui.data_mut(|data| data.insert_temp(heights_id, row_heights));
+}
+
+/// Title of the entity: its [`Name`] followed by the entity id, or just the guessed name with a button to add a [`Name`].
+///
+/// Double-clicking the name turns it into a text field, and the new name is written back through the `world` view.
+/// Returns the response of the name label.
+fn entity_title(
+    world: &mut RestrictedWorldView<'_>,
+    queue: &mut CommandQueue,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    entity: Entity,
+) -> egui::Response {
+    let name = world
+        .get_entity_component_mut::<Name>(entity)
+        .ok()
+        .map(|name| name.as_str().to_owned());
+
+    let (response, new_name) = match &name {
+        Some(name) => {
+            let title = editable_entity_name(ui, id, name, name);
+            ui.weak(format!("{entity:?}"));
+            title
+        }
+        None => {
+            let guessed_name = guess_entity_name_restricted(world, entity);
+            let title = editable_entity_name(ui, id, &guessed_name, "");
+            let editing = ui.memory(|mem| mem.data.get_temp::<String>(id.with("rename")).is_some());
+            if !editing
+                && ui
+                    .small_button("+ name")
+                    .on_hover_text("Add a Name component")
+                    .clicked()
+            {
+                ui.memory_mut(|mem| mem.data.insert_temp(id.with("rename"), String::new()));
+            }
+            title
+        }
+    };
+
+    if let Some(new_name) = new_name {
+        set_entity_name(world, queue, entity, new_name);
+    }
+
+    response
+}
+
+/// Label which turns into a text field starting out with `name` when double-clicked.
+///
+/// Returns the new name when the edit is committed by pressing `Enter` or clicking elsewhere, `Escape` cancels the edit.
+fn editable_entity_name(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    label: &str,
+    name: &str,
+) -> (egui::Response, Option<String>) {
+    let rename_id = id.with("rename");
+
+    let Some(mut buffer) = ui.memory_mut(|mem| mem.data.get_temp::<String>(rename_id)) else {
+        let response = ui.add(egui::Label::new(label).sense(egui::Sense::click()));
+        if response.double_clicked() {
+            ui.memory_mut(|mem| mem.data.insert_temp(rename_id, name.to_owned()));
+        }
+        return (response, None);
+    };
+
+    let response = ui.text_edit_singleline(&mut buffer);
+    if response.lost_focus() {
+        ui.memory_mut(|mem| mem.data.remove::<String>(rename_id));
+        let cancelled = ui.input(|input| input.key_pressed(egui::Key::Escape));
+        return (response, (!cancelled).then_some(buffer));
+    }
+    if !response.has_focus() {
+        response.request_focus();
+    }
+    ui.memory_mut(|mem| mem.data.insert_temp(rename_id, buffer));
+    (response, None)
+}
+
+/// Updates the [`Name`] of the entity if there is access to it, or inserts one using the `queue` if the entity has none
+fn set_entity_name(
+    world: &mut RestrictedWorldView<'_>,
+    queue: &mut CommandQueue,
+    entity: Entity,
+    new_name: String,
+) {
+    match world.get_entity_component_mut::<Name>(entity) {
+        Ok(mut name) => name.set(new_name),
+        Err(RestrictedWorldViewError::ComponentDoesNotExist(_)) => {
+            queue.push(move |world: &mut World| {
+                if let Some(mut entity) = world.get_entity_mut(entity) {
+                    entity.insert(Name::new(new_name));
+                }
+            })
+        }
+        Err(_) => {}
     }
 }
 
 /// Display the given entity with all its components and children
 pub fn ui_for_entity_with_children(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
+    let type_registry = TypeRegistryCache::new(&type_registry);
 
-    let entity_name = guess_entity_name(world, entity);
-    ui.label(entity_name);
+    let id = egui::Id::new(entity);
+    let mut queue = CommandQueue::default();
+    ui.horizontal(|ui| entity_title(&mut world.into(), &mut queue, ui, id, entity));
+    command_queue::apply_or_defer(world, &mut queue);
 
-    ui_for_entity_with_children_inner(world, entity, ui, egui::Id::new(entity), &type_registry)
+    ui_for_entity_with_children_inner(
+        world,
+        entity,
+        ui,
+        egui::Id::new(entity),
+        &type_registry,
+        &WorldInspectorOptions::default(),
+        &HashSet::new(),
+    )
 }
 
 fn ui_for_entity_with_children_inner(
@@ -282,16 +955,20 @@ fn ui_for_entity_with_children_inner(
     entity: Entity,
     ui: &mut egui::Ui,
     id: egui::Id,
-    type_registry: &TypeRegistry,
+    type_registry: &TypeRegistryCache<'_>,
+    options: &WorldInspectorOptions,
+    highlighted: &HashSet<Entity>,
 ) {
     let mut queue = CommandQueue::default();
-    ui_for_entity_components(
+    entity_components_ui(
         &mut world.into(),
         Some(&mut queue),
         entity,
         ui,
         id,
         type_registry,
+        options,
+        None,
     );
 
     let children = world
@@ -301,108 +978,585 @@ fn ui_for_entity_with_children_inner(
         if !children.is_empty() {
             ui.label("Children");
             for &child in children.iter() {
+                if options.only_changed && !has_changes(&mut world.into(), child, true, options) {
+                    continue;
+                }
                 let id = id.with(child);
 
                 let child_entity_name = guess_entity_name(world, child);
-                egui::CollapsingHeader::new(&child_entity_name)
+                let mut header = egui::RichText::new(&child_entity_name);
+                if highlighted.contains(&child) {
+                    header = header.background_color(ui.visuals().selection.bg_fill);
+                }
+                egui::CollapsingHeader::new(header)
                     .id_source(id)
                     .show(ui, |ui| {
                         ui.label(&child_entity_name);
 
-                        ui_for_entity_with_children_inner(world, child, ui, id, type_registry);
+                        ui_for_entity_with_children_inner(
+                            world,
+                            child,
+                            ui,
+                            id,
+                            type_registry,
+                            options,
+                            highlighted,
+                        );
                     });
             }
         }
     }
 
-    queue.apply(world);
+    command_queue::apply_or_defer(world, &mut queue);
 }
 
 /// Display the components of the given entity
 pub fn ui_for_entity(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    ui_for_entity_with_options(world, entity, ui, &WorldInspectorOptions::default());
+}
+
+/// Display just the components with the given [`TypeId`]s of the entity, in that order, skipping those the entity doesn't have.
+///
+/// In contrast to [`ui_for_entity`], neither the entity's title nor its other components are shown,
+/// so this can be used to compactly show a few components of many entities.
+///
+/// ```rust,no_run
+/// # use std::any::TypeId;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_transform::prelude::Transform;
+/// # #[derive(Component)] struct Health;
+/// fn dashboard(world: &mut World, entities: &[Entity], ui: &mut egui::Ui) {
+///     for &entity in entities {
+///         let components = [TypeId::of::<Health>(), TypeId::of::<Transform>()];
+///         bevy_inspector_egui::bevy_inspector::ui_for_entity_components(world, entity, &components, ui);
+///     }
+/// }
+/// ```
+pub fn ui_for_entity_components(
+    world: &mut World,
+    entity: Entity,
+    component_type_ids: &[TypeId],
+    ui: &mut egui::Ui,
+) {
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
+    let type_registry = TypeRegistryCache::new(&type_registry);
 
-    let entity_name = guess_entity_name(world, entity);
-    ui.label(entity_name);
+    let id = egui::Id::new(entity).with("components");
+    let mut queue = CommandQueue::default();
+    entity_components_ui(
+        &mut RestrictedWorldView::new(world),
+        Some(&mut queue),
+        entity,
+        ui,
+        id,
+        &type_registry,
+        &WorldInspectorOptions::default(),
+        Some(component_type_ids),
+    );
+    command_queue::apply_or_defer(world, &mut queue);
+}
+
+/// Display the components of the given entity, except for those hidden by the [`WorldInspectorOptions`]
+pub fn ui_for_entity_with_options(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    options: &WorldInspectorOptions,
+) {
+    let type_registry = inspector_type_registry(world);
+    let type_registry = type_registry.read();
+    let type_registry = TypeRegistryCache::new(&type_registry);
 
+    let id = egui::Id::new(entity);
     let mut queue = CommandQueue::default();
-    ui_for_entity_components(
-        &mut world.into(),
+    let mut world_view = RestrictedWorldView::new(world);
+    ui.horizontal(|ui| {
+        entity_title(&mut world_view, &mut queue, ui, id, entity);
+        despawn_entity_ui(&mut world_view, &mut queue, ui, id, entity);
+    });
+    entity_components_ui(
+        &mut world_view,
         Some(&mut queue),
         entity,
         ui,
-        egui::Id::new(entity),
+        id,
         &type_registry,
+        options,
+        None,
     );
-    queue.apply(world);
+    command_queue::apply_or_defer(world, &mut queue);
 }
 
-/// Display the components of the given entity
-pub(crate) fn ui_for_entity_components(
+/// Button opening a menu which asks for confirmation before despawning the entity.
+///
+/// If the entity has children, the menu lets you choose whether they are despawned as well
+/// or are kept as root entities.
+fn despawn_entity_ui(
+    world: &mut RestrictedWorldView<'_>,
+    queue: &mut CommandQueue,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    entity: Entity,
+) {
+    let Some(entity_cell) = world.world().get_entity(entity) else {
+        return;
+    };
+    let has_children = entity_cell.contains_type_id(TypeId::of::<Children>());
+    let recursive_id = id.with("despawn recursive");
+
+    ui.menu_button("✖ Despawn", |ui| {
+        let name = guess_entity_name_restricted(world, entity);
+        ui.label(format!("Despawn {name}?"));
+
+        let mut recursive = ui.memory_mut(|mem| *mem.data.get_temp_mut_or(recursive_id, true));
+        if has_children {
+            ui.checkbox(&mut recursive, "Including children");
+            ui.memory_mut(|mem| mem.data.insert_temp(recursive_id, recursive));
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Despawn").clicked() {
+                match recursive {
+                    true => queue.push(bevy_hierarchy::DespawnRecursive { entity }),
+                    false => {
+                        queue.push(move |world: &mut World| despawn_keep_children(world, entity))
+                    }
+                }
+                ui.close_menu();
+            }
+            if ui.button("Cancel").clicked() {
+                ui.close_menu();
+            }
+        });
+    });
+}
+
+/// Despawns the entity, turning its children into root entities
+fn despawn_keep_children(world: &mut World, entity: Entity) {
+    let children: Vec<Entity> = world
+        .get::<Children>(entity)
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    for child in children {
+        if let Some(mut child) = world.get_entity_mut(child) {
+            child.remove_parent();
+        }
+    }
+    if let Some(mut entity) = world.get_entity_mut(entity) {
+        entity.remove_parent();
+        entity.despawn();
+    }
+}
+
+/// Context menu of an entity row, with the [pin](pins) entry and a "Copy as scene" entry
+/// which puts the entity as RON scene text on the clipboard.
+fn entity_context_menu(world: &mut World, response: egui::Response, entity: Entity) {
+    #[cfg(not(feature = "bevy_scene"))]
+    if !world.contains_resource::<InspectorPins>() {
+        return;
+    }
+
+    response.context_menu(|ui| {
+        pins::pin_button(world, ui, PinTarget::Entity(entity));
+        #[cfg(feature = "bevy_scene")]
+        if ui.button("Copy as scene").clicked() {
+            if let Some(scene) = entity_scene_ron(world, entity) {
+                ui.output_mut(|output| output.copied_text = scene);
+            }
+            ui.close_menu();
+        }
+    });
+}
+
+/// Serializes the reflectable components of the entity as a [`DynamicScene`](bevy_scene::DynamicScene) containing just that entity.
+///
+/// Components which aren't registered in the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) are left out.
+/// Returns `None` and logs a warning if one of the components can't be serialized.
+#[cfg(feature = "bevy_scene")]
+fn entity_scene_ron(world: &World, entity: Entity) -> Option<String> {
+    let mut builder = bevy_scene::DynamicSceneBuilder::from_world(world);
+    builder.extract_entity(entity);
+    let scene = builder.build();
+
+    let type_registry = world.resource::<bevy_ecs::reflect::AppTypeRegistry>();
+    scene
+        .serialize_ron(type_registry)
+        .map_err(|error| bevy_log::warn!("Failed to serialize {entity:?} as a scene: {error}"))
+        .ok()
+}
+
+/// Display the components of the given entity, or only those of them in `only`
+pub(crate) fn entity_components_ui(
     world: &mut RestrictedWorldView<'_>,
     mut queue: Option<&mut CommandQueue>,
     entity: Entity,
     ui: &mut egui::Ui,
     id: egui::Id,
-    type_registry: &TypeRegistry,
+    type_registry: &TypeRegistryCache<'_>,
+    options: &WorldInspectorOptions,
+    only: Option<&[TypeId]>,
 ) {
-    let Some(components) = components_of_entity(world, entity) else {
+    let Some(components) = components_of_entity_among(world, entity, only, options) else {
         errors::entity_does_not_exist(ui, entity);
         return;
     };
 
-    for (name, component_id, component_type_id, size) in components {
-        let id = id.with(component_id);
+    let start = ui.cursor().min;
+    let edit_components = options.edit_components && queue.is_some();
+    let mut removed_components = Vec::new();
+    let theme = world.get_resource::<InspectorTheme>().ok().cloned();
+    let source_resolver = world.get_resource::<TypeSourceResolver>().ok().copied();
+
+    // without grouping, all components are shown in a single group without a header
+    let groups: Vec<(Option<String>, _)> = match options.group_by_crate {
+        true => group_components_by_crate(world, components)
+            .into_iter()
+            .map(|(group, components)| (Some(group), components))
+            .collect(),
+        false => vec![(None, components)],
+    };
 
-        let header = egui::CollapsingHeader::new(&name).id_source(id);
+    let mut components_ui =
+        |ui: &mut egui::Ui, components: Vec<(String, ComponentId, Option<TypeId>, usize)>| {
+            for (name, component_id, component_type_id, size) in components {
+                let id = id.with(component_id);
+
+                let mut title = egui::RichText::new(&name);
+                if let Some(theme) = &theme {
+                    let type_name = world
+                        .world()
+                        .components()
+                        .get_info(component_id)
+                        .unwrap()
+                        .name();
+                    title = title.color(theme.header_color(component_type_id, type_name));
+                }
 
-        let Some(component_type_id) = component_type_id else {
-            header.show(ui, |ui| errors::no_type_id(ui, &name));
-            continue;
-        };
+                let reflect_component =
+                    component_type_id
+                        .filter(|_| edit_components)
+                        .and_then(|type_id| {
+                            type_registry
+                                .type_registry()
+                                .get_type_data::<ReflectComponent>(type_id)
+                        });
+                let mut header = ComponentHeader {
+                    title,
+                    reflect_component,
+                    source: component_type_id
+                        .zip(source_resolver)
+                        .and_then(|(type_id, resolver)| resolver.resolve(type_id)),
+                    summary: None,
+                    modified: false,
+                };
 
-        if size == 0 {
-            header.show(ui, |_| {});
-            continue;
-        }
+                let Some(component_type_id) = component_type_id else {
+                    removed_components.extend(component_header(ui, id, header, |ui| {
+                        errors::no_type_id(ui, &name)
+                    }));
+                    continue;
+                };
 
-        // create a context with access to the world except for the currently viewed component
-        let (mut component_view, world) = world.split_off_component((entity, component_type_id));
-        let mut cx = Context {
-            world: Some(world),
-            queue: queue.as_deref_mut(),
-        };
+                let change_ticks = options
+                    .show_change_ticks
+                    .then(|| world.get_entity_component_ticks(entity, component_type_id))
+                    .and_then(Result::ok)
+                    .map(|ticks| change_ticks_label(world, ticks));
 
-        let (value, is_changed, set_changed) = match component_view.get_entity_component_reflect(
-            entity,
-            component_type_id,
-            type_registry,
-        ) {
-            Ok(value) => value,
-            Err(e) => {
-                header.show(ui, |ui| errors::show_error(e, ui, &name));
-                continue;
+                if size == 0 {
+                    removed_components.extend(component_header(ui, id, header, |ui| {
+                        if let Some(change_ticks) = &change_ticks {
+                            ui.weak(change_ticks);
+                        }
+                    }));
+                    continue;
+                }
+
+                // create a context with access to the world except for the currently viewed component
+                let (mut component_view, world) =
+                    world.split_off_component((entity, component_type_id));
+                let mut cx = Context {
+                    world: Some(world),
+                    queue: queue.as_deref_mut(),
+                };
+
+                let (value, is_changed, set_changed) = match component_view
+                    .get_entity_component_reflect_cached(entity, component_type_id, type_registry)
+                {
+                    Ok(value) => value,
+                    Err(RestrictedWorldViewError::NoTypeRegistration(_)) => {
+                        errors::component_not_reflectable(ui, &name, "not in the type registry");
+                        continue;
+                    }
+                    Err(RestrictedWorldViewError::NoTypeData(_, type_data)) => {
+                        errors::component_not_reflectable(ui, &name, &format!("no {type_data}"));
+                        continue;
+                    }
+                    Err(e) => {
+                        removed_components.extend(component_header(ui, id, header, |ui| {
+                            if let Some(change_ticks) = &change_ticks {
+                                ui.weak(change_ticks);
+                            }
+                            errors::show_error(e, ui, &name)
+                        }));
+                        continue;
+                    }
+                };
+
+                if is_changed {
+                    #[cfg(feature = "highlight_changes")]
+                    set_highlight_style(ui);
+                }
+
+                if !header_open(ui, id) {
+                    let summary = value_summary(value, HEADER_SUMMARY_LEN);
+                    header.summary = (!summary.is_empty()).then_some(summary);
+                }
+                header.modified = options.highlight_non_default
+                    && default_diff::differs_from_default(ui, type_registry.type_registry(), value);
+                let removed = component_header(ui, id, header, |ui| {
+                    ui.reset_style();
+                    if let Some(change_ticks) = &change_ticks {
+                        ui.weak(change_ticks);
+                    }
+
+                    let before = snapshot_before_edit(cx.world.as_mut(), value, ui);
+
+                    breadcrumb_ui(ui, id.with(component_id), &name);
+                    let filter = field_filter_ui(ui, id.with(component_id), value);
+                    let (inspector_changed, changes) = edit_log::collect_changes(|on_change| {
+                        InspectorUi::for_bevy(type_registry.type_registry(), &mut cx)
+                            .with_field_filter(&filter)
+                            .with_breadcrumb(id.with(component_id))
+                            .with_default_highlight(options.highlight_non_default)
+                            .with_field_changed_callback(on_change)
+                            .ui_for_reflect_with_options(value, ui, id.with(component_id), &())
+                    });
+
+                    if edit_changed(inspector_changed, value, before.as_deref()) {
+                        set_changed();
+                        if let (Some(world), Some(before)) = (cx.world.as_mut(), before) {
+                            let target = EditTarget::Component(entity, component_type_id);
+                            edit_log::send(world, target, &*before, changes);
+                            history::record(world, target, before, ui.input(|input| input.time));
+                        }
+                    }
+                });
+                removed_components.extend(removed);
+                ui.reset_style();
             }
         };
 
-        if is_changed {
-            #[cfg(feature = "highlight_changes")]
-            set_highlight_style(ui);
+    for (group, components) in groups {
+        match group {
+            Some(group) => {
+                egui::CollapsingHeader::new(&group)
+                    .id_source(id.with(("component group", &group)))
+                    .default_open(true)
+                    .show(ui, |ui| components_ui(ui, components));
+            }
+            None => components_ui(ui, components),
         }
+    }
 
-        header.show(ui, |ui| {
-            ui.reset_style();
-
-            let inspector_changed = InspectorUi::for_bevy(type_registry, &mut cx)
-                .ui_for_reflect_with_options(value, ui, id.with(component_id), &());
+    if ui.rect_contains_pointer(egui::Rect::from_min_max(start, ui.min_rect().max)) {
+        if let Ok(mut hover) = world.get_resource_mut::<InspectorHover>() {
+            hover.set(entity);
+        }
+    }
 
-            if inspector_changed {
-                set_changed();
+    let Some(queue) = queue.filter(|_| edit_components) else {
+        return;
+    };
+    for reflect_component in removed_components {
+        queue.push(move |world: &mut World| {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                reflect_component.remove(&mut entity);
             }
         });
-        ui.reset_style();
     }
+    add_component_ui(world, queue, entity, ui, id, type_registry.type_registry());
+}
+
+/// Buckets the components by the first segment of their type path, keeping them in the same order within each group
+fn group_components_by_crate(
+    world: &RestrictedWorldView<'_>,
+    components: Vec<(String, ComponentId, Option<TypeId>, usize)>,
+) -> BTreeMap<String, Vec<(String, ComponentId, Option<TypeId>, usize)>> {
+    let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for component in components {
+        let info = world.world().components().get_info(component.1).unwrap();
+        let type_name = info.name();
+        let end = type_name.find([':', '<']).unwrap_or(type_name.len());
+        groups
+            .entry(type_name[..end].to_string())
+            .or_default()
+            .push(component);
+    }
+    groups
+}
+
+/// When the component was added and last changed, and whether that was since the inspector was last shown
+fn change_ticks_label(world: &RestrictedWorldView<'_>, ticks: ComponentTicks) -> String {
+    let (last_run, this_run) = (
+        world.world().last_change_tick(),
+        world.world().change_tick(),
+    );
+    let added = ticks.added_tick().get();
+    let changed = ticks.last_changed_tick().get();
+    match ticks.is_added(last_run, this_run) {
+        true => format!("added at tick {added} (added this frame), changed at tick {changed}"),
+        false => format!("added at tick {added}, changed at tick {changed}"),
+    }
+}
+
+/// What is shown in the header of a component
+struct ComponentHeader<'a> {
+    title: egui::RichText,
+    /// Set if the component can be removed
+    reflect_component: Option<&'a ReflectComponent>,
+    /// Location from the [`TypeSourceResolver`]
+    source: Option<String>,
+    /// One-line summary of the value, shown after the title while the header is closed
+    summary: Option<String>,
+    /// Whether the value differs from its default, which is marked by a `•` after the title
+    modified: bool,
+}
+
+/// Whether the body of the [`component_header`] with the `id` was visible when it was last shown
+fn header_open(ui: &egui::Ui, id: egui::Id) -> bool {
+    ui.data(|data| data.get_temp::<bool>(id.with("header open")))
+        .unwrap_or(false)
+}
+
+/// Collapsing header of a component, with a `✕` button next to it if the component can be removed
+/// and a link to its source if it has one.
+///
+/// Returns the [`ReflectComponent`] if the button was clicked.
+fn component_header(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    header: ComponentHeader<'_>,
+    add_body: impl FnOnce(&mut egui::Ui),
+) -> Option<ReflectComponent> {
+    let ComponentHeader {
+        title,
+        reflect_component,
+        source,
+        summary,
+        modified,
+    } = header;
+    let title = egui_utils::header_title(ui, title, modified, summary);
+    let store_open = |ui: &mut egui::Ui, open: bool| {
+        ui.data_mut(|data| data.insert_temp(id.with("header open"), open));
+    };
+    if reflect_component.is_none() && source.is_none() {
+        let response = egui::CollapsingHeader::new(title)
+            .id_source(id)
+            .show(ui, add_body);
+        store_open(ui, response.body_returned.is_some());
+        return None;
+    }
+
+    let mut removed = false;
+    let (_, _, body) = egui::collapsing_header::CollapsingState::load_with_default_open(
+        ui.ctx(),
+        ui.make_persistent_id(id),
+        false,
+    )
+    .show_header(ui, |ui| {
+        ui.label(title);
+        if let Some(source) = source {
+            source_link::source_link_ui(ui, source);
+        }
+        if reflect_component.is_some() {
+            removed = ui
+                .small_button("✕")
+                .on_hover_text("Remove component")
+                .clicked();
+        }
+    })
+    .body(add_body);
+    store_open(ui, body.is_some());
+
+    reflect_component.filter(|_| removed).cloned()
+}
+
+/// Menu listing all registered components with a default value which the entity doesn't have yet.
+///
+/// The selected component is inserted using the `queue`.
+fn add_component_ui(
+    world: &mut RestrictedWorldView<'_>,
+    queue: &mut CommandQueue,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) {
+    let Some(entity_cell) = world.world().get_entity(entity) else {
+        return;
+    };
+    let search_id = id.with("add component search");
+
+    ui.menu_button("Add Component", |ui| {
+        let mut search = ui
+            .memory_mut(|mem| mem.data.get_temp::<String>(search_id))
+            .unwrap_or_default();
+        ui.add(egui::TextEdit::singleline(&mut search).hint_text("Search"));
+        let search_lowercase = search.to_lowercase();
+
+        let mut components: Vec<_> = type_registry
+            .iter()
+            .filter(|registration| !entity_cell.contains_type_id(registration.type_id()))
+            .filter_map(|registration| {
+                let reflect_component = registration.data::<ReflectComponent>()?;
+                let reflect_default = registration.data::<ReflectDefault>()?;
+                let name = pretty_type_name::pretty_type_name_str(registration.type_name());
+                name.to_lowercase().contains(&search_lowercase).then(|| {
+                    let type_id = registration.type_id();
+                    (
+                        name,
+                        type_id,
+                        reflect_component.clone(),
+                        reflect_default.clone(),
+                    )
+                })
+            })
+            .collect();
+        components.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+
+        let mut added = false;
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                if components.is_empty() {
+                    ui.weak("No matching components");
+                }
+                for (name, type_id, reflect_component, reflect_default) in components {
+                    if !ui.button(name).clicked() {
+                        continue;
+                    }
+                    added = true;
+                    queue.push(move |world: &mut World| {
+                        let Some(mut entity) = world.get_entity_mut(entity) else {
+                            return;
+                        };
+                        // the component may have been added since the menu was shown
+                        if !entity.contains_type_id(type_id) {
+                            reflect_component.insert(&mut entity, &*reflect_default.default());
+                        }
+                    });
+                }
+            });
+
+        if added {
+            ui.memory_mut(|mem| mem.data.remove::<String>(search_id));
+            ui.close_menu();
+        } else {
+            ui.memory_mut(|mem| mem.data.insert_temp(search_id, search));
+        }
+    });
 }
 
 #[cfg(feature = "highlight_changes")]
@@ -429,23 +1583,115 @@ fn set_highlight_style(ui: &mut egui::Ui) {
     };
 }
 
-fn components_of_entity(
+/// Whether an edited value is different from its copy from `before` the edit, so that it should be marked as changed.
+///
+/// Widgets may report changes without modifying the value, e.g. while a drag is held in place, which would trigger
+/// change detection every frame. Values which can't be compared are assumed to be different.
+fn value_differs(value: &dyn Reflect, before: &dyn Reflect) -> bool {
+    value.reflect_partial_eq(before) != Some(true)
+}
+
+/// Copy of a value from before showing it, for [`value_differs`] and for recording the edit.
+///
+/// Values are only copied if edits are [recorded](edit_log::is_recording) or if the input of this frame could edit them,
+/// so that values which are only looked at aren't cloned every frame.
+fn snapshot_before_edit(
+    world: Option<&mut RestrictedWorldView<'_>>,
+    value: &dyn Reflect,
+    ui: &egui::Ui,
+) -> Option<Box<dyn Reflect>> {
+    let recording = world.is_some_and(|world| edit_log::is_recording(world));
+    (recording || egui_utils::input_may_edit(ui)).then(|| value.clone_value())
+}
+
+/// Whether a value which was reported as `changed` should be marked as changed, see [`value_differs`].
+/// Without a snapshot, the report is trusted.
+fn edit_changed(changed: bool, value: &dyn Reflect, before: Option<&dyn Reflect>) -> bool {
+    changed && before.map_or(true, |before| value_differs(value, before))
+}
+
+/// Whether a component of the entity (or one of its descendants) which isn't hidden by the `options` changed.
+///
+/// Expects [`WorldInspectorOptions::only_changed`] to be set.
+fn has_changes(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    with_children: bool,
+    options: &WorldInspectorOptions,
+) -> bool {
+    debug_assert!(options.only_changed);
+    if components_of_entity(world, entity, options).is_some_and(|components| !components.is_empty())
+    {
+        return true;
+    }
+    if !with_children {
+        return false;
+    }
+
+    let Ok(children) = world.get_entity_component_mut::<Children>(entity) else {
+        return false;
+    };
+    let children: Vec<Entity> = children.iter().copied().collect();
+    children
+        .into_iter()
+        .any(|child| has_changes(world, child, true, options))
+}
+
+fn components_of_entity(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    options: &WorldInspectorOptions,
+) -> Option<Vec<(String, ComponentId, Option<TypeId>, usize)>> {
+    components_of_entity_among(world, entity, None, options)
+}
+
+/// Like [`components_of_entity`], but if `only` is set, just the components with these type ids are looked up
+/// and they are kept in the given order
+fn components_of_entity_among(
     world: &mut RestrictedWorldView<'_>,
     entity: Entity,
+    only: Option<&[TypeId]>,
+    options: &WorldInspectorOptions,
 ) -> Option<Vec<(String, ComponentId, Option<TypeId>, usize)>> {
     let entity_ref = world.world().get_entity(entity)?;
+    let formatter = world.get_resource::<TypeLabelFormatter>().ok().copied();
 
     let archetype = entity_ref.archetype();
-    let mut components: Vec<_> = archetype
-        .components()
-        .map(|component_id| {
+    let component_ids: Vec<ComponentId> = match only {
+        Some(type_ids) => type_ids
+            .iter()
+            .filter_map(|&type_id| world.world().components().get_id(type_id))
+            .filter(|&component_id| archetype.contains(component_id))
+            .collect(),
+        None => archetype.components().collect(),
+    };
+    let mut components: Vec<_> = component_ids
+        .into_iter()
+        .filter_map(|component_id| {
             let info = world.world().components().get_info(component_id).unwrap();
-            let name = pretty_type_name::pretty_type_name_str(info.name());
+            if options.is_hidden(info.type_id(), info.name()) {
+                return None;
+            }
+            if options.only_changed
+                && !info.type_id().is_some_and(|type_id| {
+                    world
+                        .is_entity_component_changed(entity, type_id)
+                        .unwrap_or(false)
+                })
+            {
+                return None;
+            }
+            let name = match (formatter, info.type_id()) {
+                (Some(formatter), Some(type_id)) => formatter.format(type_id, info.name()),
+                _ => pretty_type_name::pretty_type_name_str(info.name()),
+            };
 
-            (name, component_id, info.type_id(), info.layout().size())
+            Some((name, component_id, info.type_id(), info.layout().size()))
         })
         .collect();
-    components.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    if only.is_none() && options.component_order == ComponentOrder::Alphabetical {
+        components.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    }
     Some(components)
 }
 
@@ -455,14 +1701,16 @@ pub fn ui_for_entities_shared_components(
     entities: &[Entity],
     ui: &mut egui::Ui,
 ) {
-    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = inspector_type_registry(world);
     let type_registry = type_registry.read();
 
     let Some(&first) = entities.first() else {
         return;
     };
 
-    let Some(mut components) = components_of_entity(&mut world.into(), first) else {
+    let Some(mut components) =
+        components_of_entity(&mut world.into(), first, &WorldInspectorOptions::default())
+    else {
         return errors::entity_does_not_exist(ui, first);
     };
 
@@ -497,6 +1745,8 @@ pub fn ui_for_entities_shared_components(
 
                 let mut values = Vec::with_capacity(entities.len());
                 let mut mark_changeds = Vec::with_capacity(entities.len());
+                let snapshot = egui_utils::input_may_edit(ui);
+                let mut befores = Vec::new();
 
                 for (i, &entity) in entities.iter().enumerate() {
                     // skip duplicate entities
@@ -513,6 +1763,9 @@ pub fn ui_for_entities_shared_components(
                         )
                     } {
                         Ok((value, mark_changed)) => {
+                            if snapshot {
+                                befores.push(value.clone_value());
+                            }
                             values.push(value);
                             mark_changeds.push(mark_changed);
                         }
@@ -533,12 +1786,186 @@ pub fn ui_for_entities_shared_components(
                     &|a| a,
                 );
                 if changed {
-                    mark_changeds.into_iter().for_each(|f| f());
+                    for ((i, value), mark_changed) in values.iter().enumerate().zip(mark_changeds) {
+                        if edit_changed(true, &**value, befores.get(i).map(|before| &**before)) {
+                            mark_changed();
+                        }
+                    }
+                }
+            });
+    }
+
+    command_queue::apply_or_defer(world, &mut queue);
+}
+
+/// Display the components of two entities side by side, highlighting the fields whose values differ.
+///
+/// Components which only one of the entities has are flagged in their header.
+/// Values are only displayed, not edited.
+pub fn ui_for_entity_diff(world: &mut World, a: Entity, b: Entity, ui: &mut egui::Ui) {
+    let type_registry = inspector_type_registry(world);
+    let type_registry = type_registry.read();
+
+    if a == b {
+        ui.label("Both sides are the same entity");
+        return;
+    }
+    let names = [guess_entity_name(world, a), guess_entity_name(world, b)];
+
+    let options = WorldInspectorOptions::default();
+    let mut components: Vec<(String, ComponentId, Option<TypeId>, usize, [bool; 2])> = Vec::new();
+    for (side, entity) in [a, b].into_iter().enumerate() {
+        let Some(entity_components) = components_of_entity(&mut world.into(), entity, &options)
+        else {
+            return errors::entity_does_not_exist(ui, entity);
+        };
+        for (name, component_id, type_id, size) in entity_components {
+            match components
+                .iter_mut()
+                .find(|(_, id, ..)| *id == component_id)
+            {
+                Some((.., present)) => present[side] = true,
+                None => {
+                    let mut present = [false; 2];
+                    present[side] = true;
+                    components.push((name, component_id, type_id, size, present));
+                }
+            }
+        }
+    }
+    components.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+
+    let (resources_view, mut components_view) = RestrictedWorldView::resources_components(world);
+    let (mut view_a, mut view_b) = components_view.split_off_entity(a);
+    let mut cx = Context {
+        world: Some(resources_view),
+        queue: None,
+    };
+    let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+
+    let id = ui.id().with("entity diff");
+    for (name, component_id, component_type_id, size, present) in components {
+        let id = id.with(component_id);
+        let Some(component_type_id) = component_type_id else {
+            errors::no_type_id(ui, &name);
+            continue;
+        };
+
+        let mut values = [None, None];
+        for (side, (view, entity)) in [(&mut view_a, a), (&mut view_b, b)].into_iter().enumerate() {
+            if !present[side] || size == 0 {
+                continue;
+            }
+            match view.get_entity_component_reflect(entity, component_type_id, &type_registry) {
+                Ok((value, ..)) => values[side] = Some(&*value),
+                Err(error) => errors::show_error(error, ui, &name),
+            }
+        }
+
+        let fields = match values {
+            [Some(value_a), Some(value_b)] => field_diffs(value_a, value_b),
+            _ => Vec::new(),
+        };
+        let header = match present {
+            [true, true] if fields.iter().any(|(.., differs)| *differs) => {
+                egui::RichText::new(&name).color(ui.visuals().warn_fg_color)
+            }
+            [true, true] => egui::RichText::new(&name),
+            [true, false] => egui::RichText::new(format!("{name} (only on {})", names[0]))
+                .color(ui.visuals().error_fg_color),
+            _ => egui::RichText::new(format!("{name} (only on {})", names[1]))
+                .color(ui.visuals().error_fg_color),
+        };
+
+        egui::CollapsingHeader::new(header)
+            .id_source(id)
+            .show(ui, |ui| {
+                if size == 0 {
+                    return;
                 }
+                egui::Grid::new(id.with("fields"))
+                    .num_columns(3)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        ui.strong(&names[0]);
+                        ui.strong(&names[1]);
+                        ui.end_row();
+
+                        if !fields.is_empty() {
+                            for (i, (field, value_a, value_b, differs)) in fields.iter().enumerate()
+                            {
+                                match differs {
+                                    true => ui.colored_label(ui.visuals().warn_fg_color, field),
+                                    false => ui.label(field),
+                                };
+                                env.ui_for_reflect_readonly_with_options(
+                                    *value_a,
+                                    ui,
+                                    id.with((i, 0)),
+                                    &(),
+                                );
+                                env.ui_for_reflect_readonly_with_options(
+                                    *value_b,
+                                    ui,
+                                    id.with((i, 1)),
+                                    &(),
+                                );
+                                ui.end_row();
+                            }
+                            return;
+                        }
+
+                        ui.label("");
+                        for (side, value) in values.iter().enumerate() {
+                            match value {
+                                Some(value) => env.ui_for_reflect_readonly_with_options(
+                                    *value,
+                                    ui,
+                                    id.with(side),
+                                    &(),
+                                ),
+                                None => {
+                                    ui.weak("missing");
+                                }
+                            }
+                        }
+                        ui.end_row();
+                    });
             });
     }
+}
 
-    queue.apply(world);
+/// The fields of two values of the same type, along with whether they differ.
+///
+/// Values which aren't structs or tuple structs are compared as a whole.
+/// Fields which can't be compared aren't considered different.
+fn field_diffs<'a>(
+    a: &'a dyn Reflect,
+    b: &'a dyn Reflect,
+) -> Vec<(String, &'a dyn Reflect, &'a dyn Reflect, bool)> {
+    let differs = |a: &dyn Reflect, b: &dyn Reflect| a.reflect_partial_eq(b) == Some(false);
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => (0..a.field_len())
+            .filter_map(|i| {
+                let name = a.name_at(i)?;
+                let (field_a, field_b) = (a.field_at(i)?, b.field(name)?);
+                Some((
+                    name.to_string(),
+                    field_a,
+                    field_b,
+                    differs(field_a, field_b),
+                ))
+            })
+            .collect(),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => (0..a.field_len())
+            .filter_map(|i| {
+                let (field_a, field_b) = (a.field(i)?, b.field(i)?);
+                Some((i.to_string(), field_a, field_b, differs(field_a, field_b)))
+            })
+            .collect(),
+        _ => vec![("value".to_string(), a, b, differs(a, b))],
+    }
 }
 
 pub mod by_type_id {
@@ -546,14 +1973,15 @@ pub mod by_type_id {
 
     use bevy_asset::{HandleId, HandleUntyped, ReflectAsset, ReflectHandle};
     use bevy_ecs::{prelude::*, system::CommandQueue};
-    use bevy_reflect::TypeRegistry;
+    use bevy_reflect::{Reflect, TypeRegistry};
 
     use crate::{
         reflect_inspector::{Context, InspectorUi},
-        restricted_world_view::RestrictedWorldView,
+        restricted_world_view::{Error, RestrictedWorldView},
     };
 
     use super::errors::{self, name_of_type};
+    use super::history::{self, EditTarget};
 
     /// Display the resource with the given [`TypeId`]
     pub fn ui_for_resource(
@@ -569,26 +1997,107 @@ pub mod by_type_id {
             // create a context with access to the world except for the current resource
             let mut world_view = RestrictedWorldView::new(world);
             let (mut resource_view, world_view) = world_view.split_off_resource(resource_type_id);
-            let mut cx = Context {
-                world: Some(world_view),
-                queue: Some(&mut queue),
-            };
-            let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
 
-            let (resource, set_changed) = match resource_view
-                .get_resource_reflect_mut_by_id(resource_type_id, type_registry)
-            {
-                Ok(resource) => resource,
-                Err(err) => return errors::show_error(err, ui, name_of_type),
-            };
+            let resource =
+                resource_view.get_resource_reflect_mut_by_id(resource_type_id, type_registry);
+            match resource {
+                Ok((resource, set_changed)) => resource_ui(
+                    resource,
+                    set_changed,
+                    resource_type_id,
+                    world_view,
+                    &mut queue,
+                    ui,
+                    type_registry,
+                ),
+                Err(err) => errors::show_error(err, ui, name_of_type),
+            }
+        }
+
+        super::command_queue::apply_or_defer(world, &mut queue);
+    }
+
+    /// Display the resources with the given [`TypeId`]s, each in a collapsing header named after the type.
+    ///
+    /// Every resource is split off the same [`RestrictedWorldView`] in turn, so it can access the rest of the world
+    /// while it is displayed. Resources which don't exist in the world are listed without a header.
+    pub fn ui_for_resources(
+        world: &mut World,
+        resource_type_ids: &[TypeId],
+        ui: &mut egui::Ui,
+        type_registry: &TypeRegistry,
+    ) {
+        let mut queue = CommandQueue::default();
+
+        {
+            let mut world_view = RestrictedWorldView::new(world);
+            for &resource_type_id in resource_type_ids {
+                let name = match type_registry.get(resource_type_id) {
+                    Some(registration) => registration.short_name().to_owned(),
+                    None => name_of_type(resource_type_id, type_registry).into_owned(),
+                };
 
-            let changed = env.ui_for_reflect(resource, ui);
-            if changed {
-                set_changed();
+                let (mut resource_view, world_view) =
+                    world_view.split_off_resource(resource_type_id);
+                let resource =
+                    resource_view.get_resource_reflect_mut_by_id(resource_type_id, type_registry);
+                match resource {
+                    Err(Error::ResourceDoesNotExist(_)) => {
+                        ui.weak(format!("{name} does not exist"));
+                    }
+                    result => {
+                        ui.collapsing(&name, |ui| match result {
+                            Ok((resource, set_changed)) => resource_ui(
+                                resource,
+                                set_changed,
+                                resource_type_id,
+                                world_view,
+                                &mut queue,
+                                ui,
+                                type_registry,
+                            ),
+                            Err(err) => errors::show_error(err, ui, &name),
+                        });
+                    }
+                }
             }
         }
 
-        queue.apply(world);
+        super::command_queue::apply_or_defer(world, &mut queue);
+    }
+
+    /// UI for a resource which was split off, with the rest of the world as context
+    fn resource_ui(
+        resource: &mut dyn Reflect,
+        set_changed: impl FnOnce(),
+        resource_type_id: TypeId,
+        mut world_view: RestrictedWorldView<'_>,
+        queue: &mut CommandQueue,
+        ui: &mut egui::Ui,
+        type_registry: &TypeRegistry,
+    ) {
+        let before = super::snapshot_before_edit(Some(&mut world_view), resource, ui);
+
+        let mut cx = Context {
+            world: Some(world_view),
+            queue: Some(queue),
+        };
+        let filter =
+            super::field_filter_ui(ui, egui::Id::new(resource_type_id), resource.as_reflect());
+        let (changed, changes) = super::edit_log::collect_changes(|on_change| {
+            InspectorUi::for_bevy(type_registry, &mut cx)
+                .with_field_filter(&filter)
+                .with_field_changed_callback(on_change)
+                .ui_for_reflect(resource, ui)
+        });
+        if super::edit_changed(changed, resource, before.as_deref()) {
+            set_changed();
+            if let (Some(world), Some(before)) = (cx.world.as_mut(), before) {
+                let target = EditTarget::Resource(resource_type_id);
+                super::edit_log::send(world, target, &*before, changes);
+                history::record(world, target, before, ui.input(|input| input.time));
+            }
+        }
     }
 
     /// Display all assets of the given asset [`TypeId`]
@@ -645,7 +2154,7 @@ pub mod by_type_id {
                 });
         }
 
-        queue.apply(world)
+        super::command_queue::apply_or_defer(world, &mut queue)
     }
 
     /// Display a given asset by handle and asset [`TypeId`]
@@ -697,7 +2206,7 @@ pub mod by_type_id {
         let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
         env.ui_for_reflect_with_options(&mut *handle, ui, id, &());
 
-        queue.apply(world);
+        super::command_queue::apply_or_defer(world, &mut queue);
     }
 }
 
@@ -718,14 +2227,17 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
 }
 
 /// Short circuiting methods for the [`InspectorUi`] to enable it to display [`Handle`](bevy_asset::Handle)s
+/// and maps keyed by [`Entity`]
 pub mod short_circuit {
     use std::any::{Any, TypeId};
 
-    use bevy_asset::ReflectAsset;
-    use bevy_reflect::Reflect;
+    use bevy_asset::{AssetServer, HandleId, LoadState, ReflectAsset};
+    use bevy_reflect::{Reflect, ReflectMut, ReflectRef};
 
     use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::restricted_world_view::RestrictedWorldView;
 
+    use super::entity_map;
     use super::errors::{self, name_of_type};
 
     pub fn short_circuit(
@@ -735,6 +2247,11 @@ pub mod short_circuit {
         id: egui::Id,
         options: &dyn Any,
     ) -> Option<bool> {
+        if let ReflectMut::Map(map) = value.reflect_mut() {
+            let info = entity_map::entity_map_info(map)?;
+            return Some(entity_map::entity_map_ui(map, info, ui, id, env));
+        }
+
         if let Some(reflect_handle) = env
             .type_registry
             .get_type_data::<bevy_asset::ReflectHandle>(Any::type_id(value))
@@ -755,6 +2272,7 @@ pub mod short_circuit {
                 return Some(false);
             };
 
+            let settings = env.inherited_settings();
             let Context {
                 world: Some(world),
                 queue,
@@ -764,8 +2282,9 @@ pub mod short_circuit {
                 return Some(false);
             };
 
-            let (assets_view, world) =
+            let (assets_view, mut world) =
                 world.split_off_resource(reflect_asset.assets_resource_type_id());
+            let from_asset_server = load_state_ui(&mut world, handle_id, ui);
 
             let asset_value = {
                 assert!(
@@ -777,22 +2296,19 @@ pub mod short_circuit {
                 match asset_value {
                     Some(value) => value,
                     None => {
-                        errors::dead_asset_handle(ui, handle_id);
+                        if !from_asset_server {
+                            errors::dead_asset_handle(ui, handle_id);
+                        }
                         return Some(false);
                     }
                 }
             };
 
-            let mut restricted_env = InspectorUi {
-                type_registry: env.type_registry,
-                context: &mut Context {
-                    world: Some(world),
-                    queue: queue.as_deref_mut(),
-                },
-                short_circuit: env.short_circuit,
-                short_circuit_readonly: env.short_circuit_readonly,
-                short_circuit_many: env.short_circuit_many,
+            let mut context = Context {
+                world: Some(world),
+                queue: queue.as_deref_mut(),
             };
+            let mut restricted_env = settings.with_context(&mut context);
             return Some(restricted_env.ui_for_reflect_with_options(
                 asset_value,
                 ui,
@@ -804,6 +2320,47 @@ pub mod short_circuit {
         None
     }
 
+    /// Shows the [`LoadState`] and path of the asset if it was loaded through the [`AssetServer`].
+    ///
+    /// Returns whether the asset server knows about the handle.
+    pub(crate) fn load_state_ui(
+        world: &mut RestrictedWorldView<'_>,
+        handle: HandleId,
+        ui: &mut egui::Ui,
+    ) -> bool {
+        if !world.allows_access_to_resource(TypeId::of::<AssetServer>()) {
+            return false;
+        }
+        let Ok(asset_server) = world.get_resource_mut::<AssetServer>() else {
+            return false;
+        };
+        let Some(path) = asset_server.get_handle_path(handle) else {
+            return false;
+        };
+
+        let load_state = asset_server.get_load_state(handle);
+        let (label, color) = match load_state {
+            LoadState::NotLoaded => ("Not loaded", egui::Color32::GRAY),
+            LoadState::Loading => ("Loading", egui::Color32::GOLD),
+            LoadState::Loaded => ("Loaded", egui::Color32::DARK_GREEN),
+            LoadState::Failed => ("Failed", egui::Color32::DARK_RED),
+            LoadState::Unloaded => ("Unloaded", egui::Color32::GRAY),
+        };
+        ui.horizontal(|ui| {
+            let badge = ui.label(
+                egui::RichText::new(label)
+                    .small()
+                    .color(egui::Color32::WHITE)
+                    .background_color(color),
+            );
+            if load_state == LoadState::Failed {
+                badge.on_hover_text("The error of the asset server is in the log");
+            }
+            ui.weak(path.path().display().to_string());
+        });
+        true
+    }
+
     pub fn short_circuit_many(
         env: &mut InspectorUi,
         type_id: TypeId,
@@ -830,6 +2387,7 @@ pub mod short_circuit {
                 return Some(false);
             };
 
+            let settings = env.inherited_settings();
             let Context {
                 world: Some(world),
                 queue,
@@ -875,16 +2433,11 @@ pub mod short_circuit {
                 new_values.push(asset_value);
             }
 
-            let mut restricted_env = InspectorUi {
-                type_registry: env.type_registry,
-                context: &mut Context {
-                    world: Some(world),
-                    queue: queue.as_deref_mut(),
-                },
-                short_circuit: env.short_circuit,
-                short_circuit_readonly: env.short_circuit_readonly,
-                short_circuit_many: env.short_circuit_many,
+            let mut context = Context {
+                world: Some(world),
+                queue: queue.as_deref_mut(),
             };
+            let mut restricted_env = settings.with_context(&mut context);
             return Some(restricted_env.ui_for_reflect_many_with_options(
                 reflect_handle.asset_type_id(),
                 "",
@@ -906,6 +2459,12 @@ pub mod short_circuit {
         id: egui::Id,
         options: &dyn Any,
     ) -> Option<()> {
+        if let ReflectRef::Map(map) = value.reflect_ref() {
+            entity_map::entity_map_info(map)?;
+            entity_map::entity_map_ui_readonly(map, ui, id, env);
+            return Some(());
+        }
+
         if let Some(reflect_handle) = env
             .type_registry
             .get_type_data::<bevy_asset::ReflectHandle>(Any::type_id(value))
@@ -926,6 +2485,7 @@ pub mod short_circuit {
                 return Some(());
             };
 
+            let settings = env.inherited_settings();
             let Context {
                 world: Some(world),
                 queue,
@@ -935,8 +2495,9 @@ pub mod short_circuit {
                 return Some(());
             };
 
-            let (assets_view, world) =
+            let (assets_view, mut world) =
                 world.split_off_resource(reflect_asset.assets_resource_type_id());
+            let from_asset_server = load_state_ui(&mut world, handle_id, ui);
 
             let asset_value = {
                 // SAFETY: the following code only accesses a resources it has access to, `Assets<T>`
@@ -948,22 +2509,19 @@ pub mod short_circuit {
                 match asset_value {
                     Some(value) => value,
                     None => {
-                        errors::dead_asset_handle(ui, handle_id);
+                        if !from_asset_server {
+                            errors::dead_asset_handle(ui, handle_id);
+                        }
                         return Some(());
                     }
                 }
             };
 
-            let mut restricted_env = InspectorUi {
-                type_registry: env.type_registry,
-                context: &mut Context {
-                    world: Some(world),
-                    queue: queue.as_deref_mut(),
-                },
-                short_circuit: env.short_circuit,
-                short_circuit_readonly: env.short_circuit_readonly,
-                short_circuit_many: env.short_circuit_many,
+            let mut context = Context {
+                world: Some(world),
+                queue: queue.as_deref_mut(),
             };
+            let mut restricted_env = settings.with_context(&mut context);
             restricted_env.ui_for_reflect_readonly_with_options(
                 asset_value,
                 ui,
@@ -978,3 +2536,857 @@ pub mod short_circuit {
 }
 
 pub use crate::utils::guess_entity_name::guess_entity_name;
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_hierarchy::{BuildWorldChildren, Parent};
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+    use std::any::TypeId;
+    use std::cell::Cell;
+    use std::collections::HashMap;
+
+    use super::pins::InspectorPins;
+    use super::WorldInspectorOptions;
+    use crate::inspector_egui_impls::{InspectorEguiImpl, RegisterInspectorEguiImpl};
+    use crate::reflect_inspector::InspectorUi;
+    use crate::utils::tests::TestUi;
+
+    fn rename(world: &mut World, entity: Entity, new_name: &str, confirm: egui::Key) {
+        let old_name = super::guess_entity_name(world, entity);
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_world_entities(world, ui);
+        test_ui.run(&mut ui);
+
+        let pos = test_ui.text_position(&old_name).unwrap();
+        test_ui.click(pos, &mut ui);
+        test_ui.click(pos, &mut ui);
+        // the text field appears and takes focus
+        test_ui.run(&mut ui);
+
+        for _ in old_name.chars() {
+            test_ui.key(egui::Key::Backspace);
+        }
+        test_ui.type_text(new_name);
+        test_ui.run(&mut ui);
+        test_ui.key(confirm);
+        test_ui.run(&mut ui);
+    }
+
+    #[test]
+    fn rename_entity_inline() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let named = world.spawn(Name::new("Foo")).id();
+        let unnamed = world.spawn_empty().id();
+
+        rename(&mut world, named, "Bar", egui::Key::Enter);
+        assert_eq!(world.get::<Name>(named).unwrap().as_str(), "Bar");
+
+        rename(&mut world, named, "Baz", egui::Key::Escape);
+        assert_eq!(world.get::<Name>(named).unwrap().as_str(), "Bar");
+
+        rename(&mut world, unnamed, "Qux", egui::Key::Enter);
+        assert_eq!(world.get::<Name>(unnamed).unwrap().as_str(), "Qux");
+    }
+
+    #[test]
+    fn click_entity_name_to_expand() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        world.spawn((Name::new("Foo"), Health(7)));
+
+        let mut test_ui = TestUi::new();
+        // the entity should be open right after clicking it
+        let mut style = (*test_ui.ctx.style()).clone();
+        style.animation_time = 0.0;
+        test_ui.ctx.set_style(style);
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_world_entities(&mut world, ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Health (7)").is_none());
+
+        let pos = test_ui.text_position("Foo").unwrap();
+        test_ui.click(pos, &mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Health (7)").is_some());
+
+        // double-clicking renames the entity without toggling it,
+        // once enough time passed for the clicks not to count as a triple click
+        for _ in 0..30 {
+            test_ui.run(&mut ui);
+        }
+        test_ui.click(pos, &mut ui);
+        test_ui.click(pos, &mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Health (7)").is_some());
+        test_ui.key(egui::Key::Escape);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Health (7)").is_some());
+        assert!(test_ui.text_position("Foo").is_some());
+    }
+
+    #[test]
+    fn entity_title() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let named = world.spawn(Name::new("Foo")).id();
+        let unnamed = world.spawn_empty().id();
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, named, ui));
+        assert!(test_ui.text_position(&format!("{named:?}")).is_some());
+        let pos = test_ui.text_position("Foo").unwrap();
+        test_ui.click(pos, &mut |ui| super::ui_for_entity(&mut world, named, ui));
+        test_ui.click(pos, &mut |ui| super::ui_for_entity(&mut world, named, ui));
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, named, ui));
+        for _ in 0..3 {
+            test_ui.key(egui::Key::Backspace);
+        }
+        test_ui.type_text("Bar");
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, named, ui));
+        assert_eq!(world.get::<Name>(named).unwrap().as_str(), "Bar");
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, unnamed, ui));
+        let pos = test_ui.text_position("+ name").unwrap();
+        test_ui.click(pos, &mut |ui| super::ui_for_entity(&mut world, unnamed, ui));
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, unnamed, ui));
+        test_ui.type_text("Qux");
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, unnamed, ui));
+        assert_eq!(world.get::<Name>(unnamed).unwrap().as_str(), "Qux");
+    }
+
+    #[derive(Component)]
+    struct Shown;
+    #[derive(Component)]
+    struct Hidden;
+
+    fn rendered_components(options: &WorldInspectorOptions) -> [bool; 2] {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let entity = world.spawn((Shown, Hidden)).id();
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_entity_with_options(&mut world, entity, ui, options));
+        ["Shown", "Hidden"].map(|name| test_ui.text_position(name).is_some())
+    }
+
+    #[test]
+    fn hide_components() {
+        assert_eq!(
+            rendered_components(&WorldInspectorOptions::default()),
+            [true, true]
+        );
+
+        let mut options = WorldInspectorOptions::default();
+        options.hide_component::<Hidden>();
+        assert_eq!(rendered_components(&options), [true, false]);
+
+        let mut options = WorldInspectorOptions::default();
+        options.hide_module(module_path!());
+        assert_eq!(rendered_components(&options), [false, false]);
+    }
+
+    #[test]
+    fn hide_module_matches_whole_segments() {
+        let mut options = WorldInspectorOptions::default();
+        options.hide_module("bevy_render");
+        assert!(options.is_hidden(None, "bevy_render::view::Visibility"));
+        assert!(options.is_hidden(None, "bevy_render"));
+        assert!(!options.is_hidden(None, "bevy_render_foo::Marker"));
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Health(u32);
+
+    #[test]
+    fn add_and_remove_component() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn_empty().id();
+
+        let mut options = WorldInspectorOptions::default();
+        options.edit_components(true);
+        let mut ui =
+            |ui: &mut egui::Ui| super::ui_for_entity_with_options(&mut world, entity, ui, &options);
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position("Add Component").unwrap();
+        test_ui.click(pos, &mut ui);
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position("Health").unwrap();
+        test_ui.click(pos, &mut ui);
+        test_ui.run(&mut ui);
+        assert_eq!(world.get::<Health>(entity).map(|health| health.0), Some(0));
+
+        let mut ui =
+            |ui: &mut egui::Ui| super::ui_for_entity_with_options(&mut world, entity, ui, &options);
+        // already existing components are not offered again
+        let pos = test_ui.text_position("Add Component").unwrap();
+        test_ui.click(pos, &mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("No matching components").is_some());
+        test_ui.key(egui::Key::Escape);
+        test_ui.run(&mut ui);
+
+        let pos = test_ui.text_position("✕").unwrap();
+        test_ui.click(pos, &mut ui);
+        assert!(world.get::<Health>(entity).is_none());
+    }
+
+    #[derive(Component, Reflect, Default)]
+    struct Position(f32);
+
+    #[test]
+    fn only_changed() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let moving = world.spawn((Name::new("Moving"), Position(0.0))).id();
+        world.spawn((Name::new("Still"), Position(0.0)));
+        world.clear_trackers();
+
+        world.get_mut::<Position>(moving).unwrap().0 = 1.0;
+
+        let mut options = WorldInspectorOptions::default();
+        options.only_changed(true);
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_world_entities_with_options(&mut world, ui, &options));
+        assert!(test_ui.text_position("Moving").is_some());
+        assert!(test_ui.text_position("Still").is_none());
+
+        let world_view = super::RestrictedWorldView::new(&mut world);
+        assert!(world_view
+            .is_entity_component_changed(moving, TypeId::of::<Position>())
+            .unwrap());
+        assert!(!world_view
+            .is_entity_component_changed(moving, TypeId::of::<Name>())
+            .unwrap());
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Speed(f32);
+
+    #[test]
+    fn value_search_cached_until_world_changes() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Speed>();
+        world.spawn(Speed(2.0));
+        world.spawn(Speed(0.5));
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_world_entities(&mut world, ui);
+        test_ui.run(&mut ui);
+        let pos = test_ui
+            .text_position("Search values, e.g. Health.0 < 10")
+            .unwrap();
+        test_ui.replace_text(pos, "", "Speed.0 > 1", &mut ui);
+        assert!(test_ui.text_position("1 matching entity").is_some());
+
+        // the results are reused as long as the change tick stays the same
+        world.spawn(Speed(3.0));
+        test_ui.run(&mut |ui| super::ui_for_world_entities(&mut world, ui));
+        assert!(test_ui.text_position("1 matching entity").is_some());
+
+        world.increment_change_tick();
+        test_ui.run(&mut |ui| super::ui_for_world_entities(&mut world, ui));
+        assert!(test_ui.text_position("2 matching entities").is_some());
+    }
+
+    #[test]
+    fn change_ticks() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Position>();
+        let entity = world.spawn(Position(0.0)).id();
+        let ticks = world.entity(entity).get_change_ticks::<Position>().unwrap();
+        let (added, changed) = (ticks.added_tick().get(), ticks.last_changed_tick().get());
+
+        let mut options = WorldInspectorOptions::default();
+        options.show_change_ticks(true);
+        let mut test_ui = TestUi::new();
+        let mut ui =
+            |ui: &mut egui::Ui| super::ui_for_entity_with_options(&mut world, entity, ui, &options);
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position("Position (0.0)").unwrap();
+        test_ui.click(pos, &mut ui);
+        assert!(test_ui
+            .text_position(&format!(
+                "added at tick {added} (added this frame), changed at tick {changed}"
+            ))
+            .is_some());
+
+        world.clear_trackers();
+        test_ui.run(&mut |ui| super::ui_for_entity_with_options(&mut world, entity, ui, &options));
+        assert!(test_ui
+            .text_position(&format!("added at tick {added}, changed at tick {changed}"))
+            .is_some());
+    }
+
+    #[test]
+    fn not_reflectable_component_placeholder() {
+        #[derive(Component, Reflect)]
+        struct Registered(u32);
+        #[derive(Component)]
+        struct NotRegistered {
+            _value: u32,
+        }
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        // registered without its `ReflectFromPtr` type data
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .add_registration(bevy_reflect::TypeRegistration::of::<Registered>());
+        let entity = world
+            .spawn((Registered(1), NotRegistered { _value: 2 }))
+            .id();
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, entity, ui));
+        assert!(test_ui
+            .text_position("NotRegistered — not reflectable (not in the type registry)")
+            .is_some());
+        assert!(test_ui
+            .text_position("Registered — not reflectable (no ReflectFromPtr)")
+            .is_some());
+    }
+
+    #[test]
+    fn only_given_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Position>();
+            type_registry.register::<Health>();
+            type_registry.register::<Name>();
+        }
+        let entity = world
+            .spawn((Position(0.0), Health(10), Name::new("Player")))
+            .id();
+
+        let components = [TypeId::of::<Health>(), TypeId::of::<Position>()];
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_entity_components(&mut world, entity, &components, ui));
+        assert!(test_ui.text_position("Health (10)").is_some());
+        assert!(test_ui.text_position("Position (0.0)").is_some());
+        assert!(test_ui.text_position("Name").is_none());
+
+        // components the entity doesn't have are skipped
+        let mut world_view = super::RestrictedWorldView::new(&mut world);
+        let only = [TypeId::of::<Shown>(), TypeId::of::<Health>()];
+        let components = super::components_of_entity_among(
+            &mut world_view,
+            entity,
+            Some(&only),
+            &WorldInspectorOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].2, Some(TypeId::of::<Health>()));
+    }
+
+    #[test]
+    fn component_order() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((Position(0.0), Health(10), Name::new("Player")))
+            .id();
+
+        let mut world_view = super::RestrictedWorldView::new(&mut world);
+        let mut component_names = |options: &WorldInspectorOptions| {
+            super::components_of_entity(&mut world_view, entity, options)
+                .unwrap()
+                .into_iter()
+                .map(|(name, ..)| name)
+                .collect::<Vec<_>>()
+        };
+
+        let mut options = WorldInspectorOptions::default();
+        assert_eq!(component_names(&options), ["Health", "Name", "Position"]);
+        options.component_order(super::ComponentOrder::Archetype);
+        assert_eq!(component_names(&options), ["Position", "Health", "Name"]);
+    }
+
+    #[test]
+    fn group_components_by_crate() {
+        use bevy_transform::components::{GlobalTransform, Transform};
+
+        let mut world = World::new();
+        let entity = world
+            .spawn((
+                Name::new("Player"),
+                Position(0.0),
+                Transform::default(),
+                GlobalTransform::default(),
+            ))
+            .id();
+
+        let mut world_view = super::RestrictedWorldView::new(&mut world);
+        let options = WorldInspectorOptions::default();
+        let components = super::components_of_entity(&mut world_view, entity, &options).unwrap();
+        let groups = super::group_components_by_crate(&world_view, components);
+        let groups = groups
+            .iter()
+            .map(|(group, components)| {
+                let names = components.iter().map(|(name, ..)| name.as_str());
+                (group.as_str(), names.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            [
+                ("bevy_core", vec!["Name"]),
+                ("bevy_inspector_egui", vec!["Position"]),
+                ("bevy_transform", vec!["GlobalTransform", "Transform"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn type_label_formatter() {
+        fn label(type_id: TypeId, type_name: &str) -> String {
+            match type_id == TypeId::of::<Position>() {
+                true => "Pos".to_string(),
+                false => pretty_type_name::pretty_type_name_str(type_name),
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Name>();
+            type_registry.register::<Position>();
+        }
+        let entity = world.spawn((Name::new("Player"), Position(0.0))).id();
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, entity, ui));
+        assert!(test_ui.text_position("Position (0.0)").is_some());
+
+        world.insert_resource(super::TypeLabelFormatter(label));
+        test_ui.run(&mut |ui| super::ui_for_entity(&mut world, entity, ui));
+        assert!(test_ui.text_position("Position (0.0)").is_none());
+        assert!(test_ui.text_position("Pos (0.0)").is_some());
+        let name_summary = super::value_summary(&Name::new("Player"), super::HEADER_SUMMARY_LEN);
+        assert!(test_ui
+            .text_position(&format!("Name {name_summary}"))
+            .is_some());
+    }
+
+    #[test]
+    fn source_links() {
+        fn resolve(type_id: TypeId) -> Option<String> {
+            (type_id == TypeId::of::<Position>()).then(|| "file://src/position.rs".to_string())
+        }
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Position>();
+        let positioned = world.spawn(Position(0.0)).id();
+        let named = world.spawn(Name::new("Named")).id();
+
+        fn links_shown(world: &mut World, entity: Entity) -> bool {
+            let mut test_ui = TestUi::new();
+            test_ui.run(&mut |ui| super::ui_for_entity(world, entity, ui));
+            test_ui.text_position("🔗").is_some()
+        }
+        assert!(!links_shown(&mut world, positioned));
+
+        world.insert_resource(super::TypeSourceResolver(resolve));
+        assert!(links_shown(&mut world, positioned));
+        assert!(!links_shown(&mut world, named));
+    }
+
+    #[derive(Component)]
+    struct Selected;
+
+    #[test]
+    fn entities_matching_query() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let parent = world.spawn((Name::new("Parent"), Selected)).id();
+        let child = world.spawn((Name::new("Child"), Selected)).id();
+        world.spawn(Name::new("Other"));
+        world.entity_mut(parent).add_child(child);
+
+        let options = WorldInspectorOptions::default();
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| {
+            super::ui_for_world_entities_query::<&Name, With<Selected>>(&mut world, ui, &options)
+        });
+        assert!(test_ui.text_position("Parent").is_some());
+        // children are listed on their own instead of below their parent
+        assert!(test_ui.text_position("Child").is_some());
+        assert!(test_ui.text_position("Other").is_none());
+    }
+
+    #[test]
+    fn only_visible_entities_are_built() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        for i in 0..2000 {
+            world.spawn(Name::new(format!("Item {i}")));
+        }
+
+        let mut test_ui = TestUi::new();
+        let list_id = Cell::new(egui::Id::null());
+        let mut ui = |ui: &mut egui::Ui| {
+            egui::ScrollArea::vertical()
+                .max_height(500.0)
+                .show(ui, |ui| {
+                    list_id.set(ui.id());
+                    super::ui_for_world_entities(&mut world, ui);
+                });
+        };
+        // the scroll area doesn't know its size in the first frame
+        test_ui.run(&mut ui);
+
+        // every entity which was built in a frame has its row height remembered
+        let heights_id = list_id.get().with("world ui").with("row heights");
+        test_ui
+            .ctx
+            .data_mut(|data| data.remove::<HashMap<Entity, f32>>(heights_id));
+        test_ui.run(&mut ui);
+        let built = test_ui.ctx.data(|data| {
+            data.get_temp::<HashMap<Entity, f32>>(heights_id)
+                .unwrap()
+                .len()
+        });
+        assert!(test_ui.text_position("Item 0").is_some());
+        assert!(test_ui.text_position("Item 1999").is_none());
+        assert!(built < 50, "{built} entities were built");
+    }
+
+    #[test]
+    fn pin_entity_from_context_menu() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorPins>();
+        let entity = world.spawn(Name::new("Camera")).id();
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_world(&mut world, ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Pinned").is_none());
+
+        let pos = test_ui.text_position("Camera").unwrap();
+        test_ui.secondary_click(pos, &mut ui);
+        let pos = test_ui.text_position("Pin").unwrap();
+        test_ui.click(pos, &mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Pinned").is_some());
+
+        world.despawn(entity);
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_world(&mut world, ui));
+        assert!(test_ui.text_position("Pinned").is_none());
+        assert!(world.resource::<InspectorPins>().is_empty());
+    }
+
+    #[cfg(feature = "bevy_scene")]
+    #[test]
+    fn copy_entity_as_scene() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn((Name::new("Player"), Health(42))).id();
+
+        let scene = super::entity_scene_ron(&world, entity).unwrap();
+        assert!(scene.contains("Health"), "{scene}");
+        assert!(scene.contains("42"), "{scene}");
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_world(&mut world, ui);
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position("Player").unwrap();
+        test_ui.secondary_click(pos, &mut ui);
+        let pos = test_ui.text_position("Copy as scene").unwrap();
+        test_ui.click(pos, &mut ui);
+        assert_eq!(test_ui.copied_text(), scene);
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Lives(u32);
+
+    #[test]
+    fn multiple_resources() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<Score>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Score>();
+            type_registry.register::<Lives>();
+        }
+
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| {
+            super::by_type_id::ui_for_resources(
+                &mut world,
+                &[TypeId::of::<Score>(), TypeId::of::<Lives>()],
+                ui,
+                &type_registry,
+            )
+        });
+
+        assert!(test_ui.text_position("Score").is_some());
+        assert!(test_ui.text_position("Lives does not exist").is_some());
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Level(f32);
+
+    // like some custom widgets, this reports a change on every frame it is dragged
+    fn level_ui(
+        value: &mut dyn std::any::Any,
+        ui: &mut egui::Ui,
+        _: &dyn std::any::Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let value = value.downcast_mut::<Level>().unwrap();
+        ui.add(egui::DragValue::new(&mut value.0)).dragged()
+    }
+
+    fn level_ui_readonly(
+        _: &dyn std::any::Any,
+        _: &mut egui::Ui,
+        _: &dyn std::any::Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+    }
+
+    #[test]
+    fn held_drag_does_not_mark_changed() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .add_inspector_egui_impl::<Level>(InspectorEguiImpl::new(
+                level_ui,
+                level_ui_readonly,
+                crate::inspector_egui_impls::many_unimplemented::<Level>,
+            ));
+        let entity = world.spawn(Level(5.0)).id();
+        world.clear_trackers();
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_entity(&mut world, entity, ui);
+        test_ui.run(&mut ui);
+        let header = test_ui.text_position("Level (5.0)").unwrap();
+        test_ui.click(header, &mut ui);
+        // wait for the header to finish opening
+        for _ in 0..30 {
+            test_ui.run(&mut ui);
+        }
+        let pos = test_ui.text_position("5").unwrap();
+        // the pointer doesn't move while the button is held
+        test_ui.hover(pos, &mut ui);
+        test_ui.press(pos, &mut ui);
+        for _ in 0..5 {
+            test_ui.run(&mut ui);
+        }
+
+        let level = world
+            .get_entity(entity)
+            .unwrap()
+            .get_ref::<Level>()
+            .unwrap();
+        assert_eq!(level.0, 5.0);
+        assert!(!level.is_changed());
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Stats {
+        speed: f32,
+        armor: u32,
+    }
+
+    #[test]
+    fn entity_diff() {
+        let a = Stats {
+            speed: 1.0,
+            armor: 3,
+        };
+        let b = Stats {
+            speed: 2.0,
+            armor: 3,
+        };
+        let differing = super::field_diffs(&a, &b)
+            .into_iter()
+            .map(|(field, .., differs)| (field, differs))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            differing,
+            vec![("speed".to_string(), true), ("armor".to_string(), false)]
+        );
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Stats>();
+            type_registry.register::<Health>();
+        }
+        let a = world.spawn((Name::new("A"), a, Health(1))).id();
+        let b = world.spawn((Name::new("B"), b)).id();
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| super::ui_for_entity_diff(&mut world, a, b, ui));
+        assert!(test_ui.text_position("Health (only on A)").is_some());
+        assert!(test_ui.text_position("Stats").is_some());
+    }
+
+    #[test]
+    fn non_send_resource() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Stats>();
+        world.insert_non_send_resource(Stats {
+            speed: 1.5,
+            armor: 3,
+        });
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_non_send_resource::<Stats>(&mut world, ui);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("speed").is_some());
+        assert!(test_ui.text_position("1.5").is_some());
+    }
+
+    #[test]
+    fn fixed_time_step_while_paused() {
+        use bevy_time::{fixed_timestep::FixedTime, Time};
+
+        let mut world = World::new();
+        world.insert_resource(FixedTime::new_from_secs(0.25));
+        world.init_resource::<Time>();
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_fixed_time(&mut world, ui);
+        test_ui.run(&mut ui);
+        let pause = test_ui.text_position("⏸ Pause").unwrap();
+        test_ui.click(pause, &mut ui);
+        let step = test_ui.text_position("Step").unwrap();
+        test_ui.click(step, &mut ui);
+        assert!(test_ui.text_position("250.00 ms").is_some());
+        assert!(test_ui.text_position("▶ Resume").is_some());
+
+        assert!(world.resource::<Time>().is_paused());
+        let fixed_time = world.resource::<FixedTime>();
+        assert_eq!(fixed_time.accumulated(), fixed_time.period);
+    }
+
+    #[test]
+    fn collapsed_header_summary() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Stats>();
+        let entity = world
+            .spawn(Stats {
+                speed: 1.5,
+                armor: 3,
+            })
+            .id();
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_entity(&mut world, entity, ui);
+        test_ui.run(&mut ui);
+        let header = test_ui
+            .text_position("Stats { speed: 1.5, armor: 3 }")
+            .unwrap();
+        test_ui.click(header, &mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Stats").is_some());
+    }
+
+    /// Despawns `entity` through the confirmation menu of [`super::ui_for_entity`]
+    fn despawn_through_menu(world: &mut World, entity: Entity, recursive: bool) {
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_entity(world, entity, ui);
+        test_ui.run(&mut ui);
+
+        let pos = test_ui.text_position("✖ Despawn").unwrap();
+        test_ui.click(pos, &mut ui);
+        test_ui.run(&mut ui);
+        if !recursive {
+            let pos = test_ui.text_position("Including children").unwrap();
+            test_ui.click(pos, &mut ui);
+            test_ui.run(&mut ui);
+        }
+        // the menu is still open
+        let pos = test_ui.text_position("Despawn").unwrap();
+        test_ui.click(pos, &mut ui);
+    }
+
+    #[test]
+    fn despawn_entity() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let parent = world.spawn(Name::new("Parent")).id();
+        let child = world.spawn(Name::new("Child")).set_parent(parent).id();
+        let other = world.spawn(Name::new("Other")).id();
+
+        despawn_through_menu(&mut world, parent, true);
+        assert!(world.get_entity(parent).is_none());
+        assert!(world.get_entity(child).is_none());
+        assert!(world.get_entity(other).is_some());
+
+        let parent = world.spawn(Name::new("Parent")).id();
+        let child = world.spawn(Name::new("Child")).set_parent(parent).id();
+        despawn_through_menu(&mut world, parent, false);
+        assert!(world.get_entity(parent).is_none());
+        assert!(world.get_entity(child).is_some());
+        assert!(world.get::<Parent>(child).is_none());
+    }
+}
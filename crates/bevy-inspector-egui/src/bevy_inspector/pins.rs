@@ -0,0 +1,155 @@
+//! Keeping entities and resources at the top of the world inspector.
+//!
+//! Pinned values are stored in the [`InspectorPins`] resource and shown in a "Pinned" section of
+//! [`ui_for_world`](super::ui_for_world). If the resource exists, entities and resources also get a context menu
+//! entry to pin or unpin them:
+//! ```rust
+//! # use bevy_app::App;
+//! use bevy_inspector_egui::bevy_inspector::pins::InspectorPins;
+//!
+//! App::new().init_resource::<InspectorPins>();
+//! ```
+//! The [`WorldInspectorPlugin`](crate::quick::WorldInspectorPlugin) does this automatically.
+
+use std::any::TypeId;
+
+use bevy_ecs::prelude::*;
+
+/// A value which can be pinned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinTarget {
+    Entity(Entity),
+    Resource(TypeId),
+}
+
+/// List of pinned entities and resources, in the order they were pinned
+#[derive(Resource, Default, Debug, Clone)]
+pub struct InspectorPins {
+    pins: Vec<PinTarget>,
+}
+
+impl InspectorPins {
+    /// Pins `target`, if it isn't pinned yet
+    pub fn pin(&mut self, target: PinTarget) {
+        if !self.is_pinned(target) {
+            self.pins.push(target);
+        }
+    }
+
+    pub fn unpin(&mut self, target: PinTarget) {
+        self.pins.retain(|&pin| pin != target);
+    }
+
+    /// Pins or unpins `target`, returning whether it is pinned afterwards
+    pub fn toggle(&mut self, target: PinTarget) -> bool {
+        let pinned = !self.is_pinned(target);
+        match pinned {
+            true => self.pin(target),
+            false => self.unpin(target),
+        }
+        pinned
+    }
+
+    pub fn is_pinned(&self, target: PinTarget) -> bool {
+        self.pins.contains(&target)
+    }
+
+    pub fn pin_entity(&mut self, entity: Entity) {
+        self.pin(PinTarget::Entity(entity));
+    }
+
+    pub fn pin_resource<R: Resource>(&mut self) {
+        self.pin(PinTarget::Resource(TypeId::of::<R>()));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = PinTarget> + '_ {
+        self.pins.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pins.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.pins.is_empty()
+    }
+}
+
+/// Unpins all entities which no longer exist
+pub fn remove_despawned(world: &mut World) {
+    if !world.contains_resource::<InspectorPins>() {
+        return;
+    }
+    world.resource_scope(|world, mut pins: Mut<InspectorPins>| {
+        if pins.iter().any(|pin| is_despawned(world, pin)) {
+            pins.pins.retain(|&pin| !is_despawned(world, pin));
+        }
+    });
+}
+
+fn is_despawned(world: &World, pin: PinTarget) -> bool {
+    matches!(pin, PinTarget::Entity(entity) if world.get_entity(entity).is_none())
+}
+
+/// Adds a "Pin"/"Unpin" entry to the context menu of the `response`, if the world has [`InspectorPins`]
+pub(crate) fn context_menu(world: &mut World, response: egui::Response, target: PinTarget) {
+    if !world.contains_resource::<InspectorPins>() {
+        return;
+    }
+    response.context_menu(|ui| pin_button(world, ui, target));
+}
+
+/// The "Pin"/"Unpin" entry of a context menu, shown if the world has [`InspectorPins`]
+pub(crate) fn pin_button(world: &mut World, ui: &mut egui::Ui, target: PinTarget) {
+    let Some(pinned) = world
+        .get_resource::<InspectorPins>()
+        .map(|pins| pins.is_pinned(target))
+    else {
+        return;
+    };
+
+    let label = match pinned {
+        true => "Unpin",
+        false => "Pin",
+    };
+    if ui.button(label).clicked() {
+        world.resource_mut::<InspectorPins>().toggle(target);
+        ui.close_menu();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use super::{InspectorPins, PinTarget};
+
+    #[test]
+    fn toggle_pins() {
+        let mut pins = InspectorPins::default();
+        let target = PinTarget::Entity(Entity::from_raw(0));
+
+        assert!(pins.toggle(target));
+        pins.pin(target);
+        assert_eq!(pins.len(), 1);
+        assert!(!pins.toggle(target));
+        assert!(pins.is_empty());
+    }
+
+    #[test]
+    fn despawned_entities_are_unpinned() {
+        let mut world = World::new();
+        let kept = world.spawn_empty().id();
+        let despawned = world.spawn_empty().id();
+
+        let mut pins = InspectorPins::default();
+        pins.pin_entity(kept);
+        pins.pin_entity(despawned);
+        world.insert_resource(pins);
+
+        world.despawn(despawned);
+        super::remove_despawned(&mut world);
+
+        let pins = world.resource::<InspectorPins>();
+        assert_eq!(pins.iter().collect::<Vec<_>>(), [PinTarget::Entity(kept)]);
+    }
+}
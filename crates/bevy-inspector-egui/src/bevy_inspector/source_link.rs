@@ -0,0 +1,37 @@
+//! Links from component headers to where the component type is defined.
+//!
+//! If the [`TypeSourceResolver`] resource exists, a link is shown next to the header of every component for which it
+//! returns a location, and clicking it opens the location as a URL:
+//! ```rust
+//! # use std::any::TypeId;
+//! # use bevy_app::App;
+//! # use bevy_transform::components::Transform;
+//! use bevy_inspector_egui::bevy_inspector::source_link::TypeSourceResolver;
+//!
+//! fn resolve(type_id: TypeId) -> Option<String> {
+//!     (type_id == TypeId::of::<Transform>())
+//!         .then(|| "https://docs.rs/bevy/latest/bevy/transform/components/struct.Transform.html".to_string())
+//! }
+//!
+//! App::new().insert_resource(TypeSourceResolver(resolve));
+//! ```
+
+use std::any::TypeId;
+
+use bevy_ecs::prelude::*;
+
+/// Maps a type to the location of its definition, like a docs URL or a `file://` path
+#[derive(Resource, Clone, Copy)]
+pub struct TypeSourceResolver(pub fn(TypeId) -> Option<String>);
+
+impl TypeSourceResolver {
+    pub fn resolve(&self, type_id: TypeId) -> Option<String> {
+        (self.0)(type_id)
+    }
+}
+
+/// Small link which opens the `location` of a type
+pub(crate) fn source_link_ui(ui: &mut egui::Ui, location: String) {
+    ui.add(egui::Hyperlink::from_label_and_url("🔗", &location))
+        .on_hover_text(location);
+}
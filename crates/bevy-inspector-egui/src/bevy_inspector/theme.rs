@@ -0,0 +1,86 @@
+//! Colors for telling components apart in the world inspector.
+//!
+//! If the [`InspectorTheme`] resource exists, the header of every component is tinted, either with the color
+//! configured for its type or with one derived from the type name:
+//! ```rust
+//! # use bevy_app::App;
+//! # use bevy_transform::components::Transform;
+//! use bevy_inspector_egui::bevy_inspector::theme::InspectorTheme;
+//!
+//! let mut theme = InspectorTheme::default();
+//! theme.set_header_color::<Transform>(egui::Color32::LIGHT_BLUE);
+//! App::new().insert_resource(theme);
+//! ```
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use egui::Color32;
+
+/// Header colors of component types
+#[derive(Resource, Default, Debug, Clone)]
+pub struct InspectorTheme {
+    header_colors: HashMap<TypeId, Color32>,
+}
+
+impl InspectorTheme {
+    /// Use `color` for the headers of `T` instead of the automatic one
+    pub fn set_header_color<T: 'static>(&mut self, color: Color32) -> &mut Self {
+        self.header_colors.insert(TypeId::of::<T>(), color);
+        self
+    }
+
+    /// The configured color of the type, or its [`default_header_color`]
+    pub fn header_color(&self, type_id: Option<TypeId>, type_name: &str) -> Color32 {
+        type_id
+            .and_then(|type_id| self.header_colors.get(&type_id))
+            .copied()
+            .unwrap_or_else(|| default_header_color(type_name))
+    }
+}
+
+/// A color derived from the hash of the type name, which is the same in every run
+pub fn default_header_color(type_name: &str) -> Color32 {
+    // FNV-1a, since the std hashers don't guarantee stable output
+    let hash = type_name.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    let hue = (hash % 360) as f32 / 360.0;
+    egui::ecolor::Hsva::new(hue, 0.5, 0.9, 1.0).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use egui::Color32;
+
+    use super::InspectorTheme;
+
+    struct Position;
+    struct Velocity;
+
+    #[test]
+    fn header_colors() {
+        let mut theme = InspectorTheme::default();
+        let color = |theme: &InspectorTheme, type_id, type_name| {
+            theme.header_color(Some(type_id), type_name)
+        };
+
+        let position = color(&theme, TypeId::of::<Position>(), "game::Position");
+        let velocity = color(&theme, TypeId::of::<Velocity>(), "game::Velocity");
+        assert_ne!(position, velocity);
+        assert_eq!(position, super::default_header_color("game::Position"));
+
+        theme.set_header_color::<Position>(Color32::RED);
+        assert_eq!(
+            color(&theme, TypeId::of::<Position>(), "game::Position"),
+            Color32::RED
+        );
+        assert_eq!(
+            color(&theme, TypeId::of::<Velocity>(), "game::Velocity"),
+            velocity
+        );
+    }
+}
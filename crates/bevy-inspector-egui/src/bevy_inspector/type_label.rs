@@ -0,0 +1,34 @@
+//! Custom labels for the types shown in headers.
+//!
+//! Headers of components, resources and assets show the short name of their type, like `Handle<StandardMaterial>`.
+//! If the [`TypeLabelFormatter`] resource exists, its function produces the labels instead:
+//! ```rust
+//! # use std::any::TypeId;
+//! # use bevy_app::App;
+//! use bevy_inspector_egui::bevy_inspector::type_label::TypeLabelFormatter;
+//!
+//! /// Leaves out the generic arguments, e.g. `Handle<StandardMaterial>` becomes `Handle<..>`
+//! fn without_generics(_: TypeId, type_name: &str) -> String {
+//!     let label = pretty_type_name::pretty_type_name_str(type_name);
+//!     match label.split_once('<') {
+//!         Some((name, _)) => format!("{name}<..>"),
+//!         None => label,
+//!     }
+//! }
+//!
+//! App::new().insert_resource(TypeLabelFormatter(without_generics));
+//! ```
+
+use std::any::TypeId;
+
+use bevy_ecs::prelude::*;
+
+/// Produces the label of a type from its [`TypeId`] and full type name
+#[derive(Resource, Clone, Copy)]
+pub struct TypeLabelFormatter(pub fn(TypeId, &str) -> String);
+
+impl TypeLabelFormatter {
+    pub fn format(&self, type_id: TypeId, type_name: &str) -> String {
+        (self.0)(type_id, type_name)
+    }
+}
@@ -0,0 +1,107 @@
+//! Using a different [`TypeRegistry`](bevy_reflect::TypeRegistry) than the [`AppTypeRegistry`].
+//!
+//! If the [`InspectorTypeRegistry`] resource exists, the functions in [`bevy_inspector`](crate::bevy_inspector) and
+//! the [`quick`](crate::quick) plugins look up types in it instead, so editor tooling can keep types to itself.
+//! Unlike the [`AppTypeRegistry`], it isn't filled by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin),
+//! so register the default [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl)s yourself using
+//! [`register_default_config`](crate::register_default_config):
+//! ```rust
+//! # use bevy_app::App;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_reflect::{Reflect, TypeRegistry};
+//! use bevy_inspector_egui::bevy_inspector::type_registry::InspectorTypeRegistry;
+//!
+//! #[derive(Resource, Reflect, Default)]
+//! #[reflect(Resource)]
+//! struct EditorSettings {
+//!     grid_size: f32,
+//! }
+//!
+//! let mut type_registry = TypeRegistry::default();
+//! type_registry.register::<EditorSettings>();
+//! bevy_inspector_egui::register_default_config(&mut type_registry);
+//!
+//! App::new().insert_resource(InspectorTypeRegistry::new(type_registry));
+//! ```
+//!
+//! The resource is a handle to a shared, locked registry, so cloning it is cheap and all clones see the same types.
+//! The inspector holds a read lock while it is drawn, so only write to the registry outside of the inspector UI.
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::{TypeRegistry, TypeRegistryArc};
+
+/// Registry used by the inspector instead of the [`AppTypeRegistry`] if it exists, see the [module docs](self)
+#[derive(Resource, Clone, Default, Debug)]
+pub struct InspectorTypeRegistry(pub TypeRegistryArc);
+
+impl InspectorTypeRegistry {
+    pub fn new(type_registry: TypeRegistry) -> Self {
+        InspectorTypeRegistry(TypeRegistryArc {
+            internal: std::sync::Arc::new(type_registry.into()),
+        })
+    }
+}
+
+impl From<AppTypeRegistry> for InspectorTypeRegistry {
+    fn from(type_registry: AppTypeRegistry) -> Self {
+        InspectorTypeRegistry(type_registry.0)
+    }
+}
+
+/// The [`InspectorTypeRegistry`] if it exists, or the [`AppTypeRegistry`]
+pub(crate) fn inspector_type_registry(world: &World) -> TypeRegistryArc {
+    match world.get_resource::<InspectorTypeRegistry>() {
+        Some(type_registry) => type_registry.0.clone(),
+        None => world.resource::<AppTypeRegistry>().0.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_reflect::Reflect;
+
+    use super::InspectorTypeRegistry;
+    use crate::utils::tests::{std_type_registry, TestUi};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct EditorOnly {
+        value: f32,
+    }
+
+    #[test]
+    fn custom_type_registry() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let entity = world.spawn(EditorOnly { value: 1.0 }).id();
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| crate::bevy_inspector::ui_for_entity(&mut world, entity, ui));
+        assert!(test_ui
+            .text_position("EditorOnly — not reflectable (not in the type registry)")
+            .is_some());
+
+        let mut type_registry = std_type_registry();
+        type_registry.register::<EditorOnly>();
+        world.insert_resource(InspectorTypeRegistry::new(type_registry));
+
+        let mut ui =
+            |ui: &mut egui::Ui| crate::bevy_inspector::ui_for_entity(&mut world, entity, ui);
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut ui);
+        let header = test_ui.text_position("EditorOnly { value: 1.0 }").unwrap();
+        test_ui.click(header, &mut ui);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("value").is_some());
+        // the app's registry is left alone
+        assert!(world
+            .resource::<AppTypeRegistry>()
+            .read()
+            .get(std::any::TypeId::of::<EditorOnly>())
+            .is_none());
+    }
+}
@@ -0,0 +1,360 @@
+//! Finding entities by the values of their components.
+//!
+//! A [`ValueQuery`] is written as `Component.field.path <comparison> literal`, for example
+//! `Health.0 < 10`, `Transform.translation.y >= 2.5` or `Name.name == "Player"`.
+//! The component is matched by its short type name, ignoring case, and the field path is resolved with
+//! [`GetPath`]. Numbers can be compared with `==`, `!=`, `<`, `<=`, `>` and `>=`, strings and enum
+//! variant names are compared lexicographically.
+//!
+//! The search only reads the world, so it can be evaluated every frame.
+//!
+//! ```rust
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_reflect::TypeRegistry;
+//! use bevy_inspector_egui::bevy_inspector::value_search::{self, ValueQuery};
+//!
+//! fn low_health_entities(world: &World, type_registry: &TypeRegistry) -> Vec<Entity> {
+//!     let query: ValueQuery = "Health.0 < 10".parse().unwrap();
+//!     value_search::matching_entities(world, type_registry, &query)
+//! }
+//! ```
+
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{GetPath, Reflect, ReflectRef, TypeRegistry};
+
+/// How a field is compared to the literal of a [`ValueQuery`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    // two-character operators come first so that `<=` isn't parsed as `<`
+    const OPERATORS: [(&'static str, Comparison); 6] = [
+        ("==", Comparison::Equal),
+        ("!=", Comparison::NotEqual),
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+    ];
+
+    fn evaluate(self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Equal => ordering.is_eq(),
+            Comparison::NotEqual => ordering.is_ne(),
+            Comparison::Less => ordering.is_lt(),
+            Comparison::LessOrEqual => ordering.is_le(),
+            Comparison::Greater => ordering.is_gt(),
+            Comparison::GreaterOrEqual => ordering.is_ge(),
+        }
+    }
+}
+
+/// The right hand side of a [`ValueQuery`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Number(f64),
+    /// Either written in double quotes, or any bare word which isn't a number
+    String(String),
+}
+
+impl Literal {
+    fn parse(text: &str) -> Literal {
+        if let Some(quoted) = text
+            .strip_prefix('"')
+            .and_then(|text| text.strip_suffix('"'))
+        {
+            return Literal::String(quoted.to_string());
+        }
+        match text.parse::<f64>() {
+            Ok(number) => Literal::Number(number),
+            Err(_) => Literal::String(text.to_string()),
+        }
+    }
+}
+
+/// Why a [`ValueQuery`] couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingComparison,
+    MissingComponent,
+    MissingLiteral,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingComparison => {
+                f.write_str("expected one of `==`, `!=`, `<`, `<=`, `>`, `>=`")
+            }
+            ParseError::MissingComponent => f.write_str("expected a component name"),
+            ParseError::MissingLiteral => f.write_str("expected a value to compare with"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A predicate on a single field of a component, see the [module docs](self) for the syntax
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueQuery {
+    /// Short type name of the component
+    pub component: String,
+    /// Path of the field inside of the component, or empty for the component itself
+    pub path: String,
+    pub comparison: Comparison,
+    pub literal: Literal,
+}
+
+impl FromStr for ValueQuery {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (index, operator, comparison) = Comparison::OPERATORS
+            .iter()
+            .filter_map(|&(operator, comparison)| {
+                text.find(operator)
+                    .map(|index| (index, operator, comparison))
+            })
+            .min_by_key(|&(index, ..)| index)
+            .ok_or(ParseError::MissingComparison)?;
+
+        let (field, literal) = (text[..index].trim(), text[index + operator.len()..].trim());
+        let (component, path) = field.split_once('.').unwrap_or((field, ""));
+        if component.is_empty() {
+            return Err(ParseError::MissingComponent);
+        }
+        if literal.is_empty() {
+            return Err(ParseError::MissingLiteral);
+        }
+
+        Ok(ValueQuery {
+            component: component.to_string(),
+            path: path.to_string(),
+            comparison,
+            literal: Literal::parse(literal),
+        })
+    }
+}
+
+impl ValueQuery {
+    /// Whether the field at [`ValueQuery::path`] of the `component` satisfies the comparison.
+    ///
+    /// Fields which don't exist or can't be compared to the literal never match.
+    pub fn matches(&self, component: &dyn Reflect) -> bool {
+        let field = if self.path.is_empty() {
+            component
+        } else {
+            match component.reflect_path(self.path.as_str()) {
+                Ok(field) => field,
+                Err(_) => return false,
+            }
+        };
+
+        let ordering = match &self.literal {
+            Literal::Number(literal) => {
+                as_number(field).and_then(|number| number.partial_cmp(literal))
+            }
+            Literal::String(literal) => as_str(field).map(|text| text.cmp(literal.as_str())),
+        };
+        ordering.is_some_and(|ordering| self.comparison.evaluate(ordering))
+    }
+}
+
+fn as_number(value: &dyn Reflect) -> Option<f64> {
+    macro_rules! downcast {
+        ($($ty:ty),*) => {
+            $(if let Some(value) = value.downcast_ref::<$ty>() {
+                return Some(*value as f64);
+            })*
+        };
+    }
+    downcast!(f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    None
+}
+
+fn as_str(value: &dyn Reflect) -> Option<&str> {
+    if let Some(value) = value.downcast_ref::<String>() {
+        return Some(value);
+    }
+    if let Some(value) = value.downcast_ref::<Cow<'static, str>>() {
+        return Some(value);
+    }
+    match value.reflect_ref() {
+        ReflectRef::Enum(value) => Some(value.variant_name()),
+        _ => None,
+    }
+}
+
+/// All entities with a component matching the `query`, sorted by their id
+///
+/// This looks up the component in the type registry every time, use [`ValueQuery::resolve`] to do it only once.
+pub fn matching_entities(
+    world: &World,
+    type_registry: &TypeRegistry,
+    query: &ValueQuery,
+) -> Vec<Entity> {
+    query
+        .clone()
+        .resolve(type_registry)
+        .matching_entities(world)
+}
+
+impl ValueQuery {
+    /// Looks up the reflected components named by [`ValueQuery::component`] in the type registry
+    pub fn resolve(self, type_registry: &TypeRegistry) -> ResolvedValueQuery {
+        let components = type_registry
+            .iter()
+            .filter(|registration| {
+                registration
+                    .short_name()
+                    .eq_ignore_ascii_case(&self.component)
+            })
+            .filter_map(|registration| {
+                let reflect_component = registration.data::<ReflectComponent>()?;
+                Some((registration.type_id(), reflect_component.clone()))
+            })
+            .collect();
+        ResolvedValueQuery {
+            query: self,
+            components,
+        }
+    }
+}
+
+/// A [`ValueQuery`] together with the components it applies to, created by [`ValueQuery::resolve`]
+#[derive(Clone)]
+pub struct ResolvedValueQuery {
+    query: ValueQuery,
+    components: Vec<(TypeId, ReflectComponent)>,
+}
+
+impl ResolvedValueQuery {
+    /// The query which was resolved
+    pub fn query(&self) -> &ValueQuery {
+        &self.query
+    }
+
+    /// All entities with a component matching the query, sorted by their id.
+    ///
+    /// Only the archetypes containing one of the components are visited.
+    pub fn matching_entities(&self, world: &World) -> Vec<Entity> {
+        let mut entities = Vec::new();
+        for (type_id, reflect_component) in &self.components {
+            let Some(component_id) = world.components().get_id(*type_id) else {
+                continue;
+            };
+            let archetypes = world
+                .archetypes()
+                .iter()
+                .filter(|archetype| archetype.contains(component_id));
+            for archetype in archetypes {
+                entities.extend(
+                    archetype
+                        .entities()
+                        .iter()
+                        .map(|archetype_entity| archetype_entity.entity())
+                        .filter(|&entity| {
+                            reflect_component
+                                .reflect(world.entity(entity))
+                                .is_some_and(|component| self.query.matches(component))
+                        }),
+                );
+            }
+        }
+        entities.sort();
+        entities.dedup();
+        entities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    use super::{Comparison, Literal, ParseError, ValueQuery};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Enemy {
+        kind: String,
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "Transform.translation.y <= -2.5".parse(),
+            Ok(ValueQuery {
+                component: "Transform".to_string(),
+                path: "translation.y".to_string(),
+                comparison: Comparison::LessOrEqual,
+                literal: Literal::Number(-2.5),
+            })
+        );
+        assert_eq!(
+            "Name.name == \"a < b\""
+                .parse::<ValueQuery>()
+                .map(|query| query.literal),
+            Ok(Literal::String("a < b".to_string()))
+        );
+        assert_eq!(
+            "Health.0 10".parse::<ValueQuery>(),
+            Err(ParseError::MissingComparison)
+        );
+        assert_eq!(
+            "< 10".parse::<ValueQuery>(),
+            Err(ParseError::MissingComponent)
+        );
+        assert_eq!(
+            "Health >".parse::<ValueQuery>(),
+            Err(ParseError::MissingLiteral)
+        );
+    }
+
+    #[test]
+    fn numeric_threshold() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Health>();
+        type_registry.register::<Enemy>();
+
+        let mut world = World::new();
+        let wounded = world.spawn(Health(5.0)).id();
+        let healthy = world.spawn(Health(50.0)).id();
+        let dying = world
+            .spawn((
+                Health(0.5),
+                Enemy {
+                    kind: "Goblin".to_string(),
+                },
+            ))
+            .id();
+        world.spawn(Enemy {
+            kind: "Troll".to_string(),
+        });
+
+        let matching =
+            |query: &str| super::matching_entities(&world, &type_registry, &query.parse().unwrap());
+        assert_eq!(matching("Health.0 < 10"), vec![wounded, dying]);
+        assert_eq!(matching("health.0 >= 50"), vec![healthy]);
+        assert_eq!(matching("Enemy.kind == Goblin"), vec![dying]);
+        assert_eq!(matching("Enemy.missing < 10"), vec![]);
+        assert_eq!(matching("Health.0 == Goblin"), vec![]);
+    }
+}
@@ -19,3 +19,89 @@ pub fn label_button(ui: &mut egui::Ui, text: &str, text_color: egui::Color32) ->
     ui.add(egui::Button::new(egui::RichText::new(text).color(text_color)).frame(false))
         .clicked()
 }
+
+/// Runs `add_contents` in a child `Ui` whose automatically generated widget ids are derived from `id`.
+///
+/// Widgets like [`egui::DragValue`] take their id from a counter in the parent `Ui`, so adding or removing widgets
+/// before them changes their id and they lose keyboard focus.
+pub fn with_stable_ids<R>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let mut child_ui = egui::Ui::new(
+        ui.ctx().clone(),
+        ui.layer_id(),
+        id,
+        ui.available_rect_before_wrap(),
+        ui.clip_rect(),
+    );
+    child_ui.set_style(ui.style().clone());
+    child_ui.set_enabled(ui.is_enabled());
+    child_ui.set_visible(ui.is_visible());
+
+    let layout = *ui.layout();
+    let inner = child_ui.with_layout(layout, add_contents).inner;
+    ui.allocate_rect(child_ui.min_rect(), egui::Sense::hover());
+    inner
+}
+
+/// Whether there was input in this frame other than moving the pointer, which widgets could respond to by editing their value
+pub fn input_may_edit(ui: &egui::Ui) -> bool {
+    ui.input(|input| {
+        input.pointer.any_down()
+            || input
+                .events
+                .iter()
+                .any(|event| !matches!(event, egui::Event::PointerMoved(_)))
+    })
+}
+
+/// The `title` followed by the `summary` in a weaker color
+pub fn header_title(
+    ui: &egui::Ui,
+    title: egui::RichText,
+    modified: bool,
+    summary: Option<String>,
+) -> egui::WidgetText {
+    if !modified && summary.is_none() {
+        return title.into();
+    }
+    let font_id = egui::TextStyle::Button.resolve(ui.style());
+    let mut job = egui::WidgetText::from(title).into_text_job(
+        ui.style(),
+        egui::FontSelection::FontId(font_id.clone()),
+        egui::Align::Center,
+    );
+    if !job.job_has_color {
+        for section in &mut job.job.sections {
+            section.format.color = ui.visuals().text_color();
+        }
+    }
+    if modified {
+        job.job.append(
+            " •",
+            0.0,
+            egui::TextFormat {
+                font_id: font_id.clone(),
+                color: ui.visuals().strong_text_color(),
+                valign: egui::Align::Center,
+                ..Default::default()
+            },
+        );
+    }
+    let Some(summary) = summary else {
+        return job.job.into();
+    };
+    job.job.append(
+        &format!(" {summary}"),
+        0.0,
+        egui::TextFormat {
+            font_id,
+            color: ui.visuals().weak_text_color(),
+            valign: egui::Align::Center,
+            ..Default::default()
+        },
+    );
+    job.job.into()
+}
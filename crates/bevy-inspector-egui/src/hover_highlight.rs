@@ -0,0 +1,119 @@
+//! Outlining the entity hovered in the inspector in the viewport, drawn with [`bevy_gizmos`].
+//!
+//! The [`HoverHighlightPlugin`] draws the bounding box of the entity reported to the [`InspectorHover`] resource,
+//! which happens while its row in the [`hierarchy_ui`](crate::bevy_inspector::hierarchy::hierarchy_ui) or its components are hovered.
+//! Entities without an [`Aabb`], like lights and cameras, aren't highlighted.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::hover_highlight::HoverHighlightPlugin;
+//! use bevy_inspector_egui::quick::WorldInspectorPlugin;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(WorldInspectorPlugin::new())
+//!         .add_plugins(HoverHighlightPlugin)
+//!         .run();
+//! }
+//! ```
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::prelude::*;
+use bevy_gizmos::{gizmos::Gizmos, GizmoConfig};
+use bevy_render::{color::Color, primitives::Aabb};
+use bevy_time::Time;
+use bevy_transform::components::{GlobalTransform, Transform};
+
+use crate::bevy_inspector::hover::InspectorHover;
+
+/// How long the box stays after the pointer left the entity in the inspector, in seconds
+const LINGER: f32 = 0.25;
+
+/// Initializes the [`InspectorHover`] resource and draws the bounding box of the hovered entity.
+///
+/// Nothing is drawn without the `GizmoPlugin` from `bevy_gizmos`, which is part of the `DefaultPlugins`.
+pub struct HoverHighlightPlugin;
+
+impl Plugin for HoverHighlightPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InspectorHover>().add_systems(
+            PostUpdate,
+            draw_hover_highlight.run_if(resource_exists::<GizmoConfig>()),
+        );
+    }
+}
+
+fn draw_hover_highlight(
+    mut hover: ResMut<InspectorHover>,
+    time: Res<Time>,
+    mut last_hovered: Local<Option<(Entity, f32)>>,
+    entities: Query<(&Aabb, &GlobalTransform)>,
+    mut gizmos: Gizmos,
+) {
+    let now = time.elapsed_seconds();
+    let Some(entity) = highlighted_entity(&mut last_hovered, hover.take(), now) else {
+        return;
+    };
+    let Ok((aabb, global_transform)) = entities.get(entity) else {
+        return;
+    };
+    gizmos.cuboid(aabb_transform(aabb, global_transform), Color::YELLOW);
+}
+
+/// The entity hovered at the time `now`, or the one hovered before if that was less than [`LINGER`] seconds ago
+fn highlighted_entity(
+    last_hovered: &mut Option<(Entity, f32)>,
+    hovered: Option<Entity>,
+    now: f32,
+) -> Option<Entity> {
+    if let Some(entity) = hovered {
+        *last_hovered = Some((entity, now));
+    }
+    let (entity, hovered_at) = (*last_hovered)?;
+    if now - hovered_at > LINGER {
+        *last_hovered = None;
+        return None;
+    }
+    Some(entity)
+}
+
+/// Transform of the unit cube in the position and size of the `aabb`
+fn aabb_transform(aabb: &Aabb, global_transform: &GlobalTransform) -> GlobalTransform {
+    let local = Transform::from_translation(aabb.center.into())
+        .with_scale((aabb.half_extents * 2.0).into());
+    *global_transform * GlobalTransform::from(local)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::entity::Entity;
+
+    #[test]
+    fn highlight_lingers() {
+        let (a, b) = (Entity::from_raw(1), Entity::from_raw(2));
+        let mut last_hovered = None;
+
+        assert_eq!(
+            super::highlighted_entity(&mut last_hovered, None, 0.0),
+            None
+        );
+        assert_eq!(
+            super::highlighted_entity(&mut last_hovered, Some(a), 1.0),
+            Some(a)
+        );
+        assert_eq!(
+            super::highlighted_entity(&mut last_hovered, None, 1.1),
+            Some(a)
+        );
+        assert_eq!(
+            super::highlighted_entity(&mut last_hovered, Some(b), 1.2),
+            Some(b)
+        );
+        assert_eq!(
+            super::highlighted_entity(&mut last_hovered, None, 2.0),
+            None
+        );
+        assert_eq!(last_hovered, None);
+    }
+}
@@ -1,16 +1,34 @@
 use bevy_asset::{AssetServer, Assets, Handle, HandleId};
+use bevy_core::Name;
 use bevy_ecs::{entity::Entity, system::CommandQueue};
+use bevy_hierarchy::{Children, Parent};
+use bevy_math::{Quat, Rect, Vec2, Vec3, Vec3A};
+use bevy_reflect::Reflect;
 use bevy_render::mesh::Mesh;
-use bevy_render::{color::Color, view::RenderLayers};
+use bevy_render::{
+    color::Color,
+    primitives::Aabb,
+    view::{ComputedVisibility, RenderLayers, Visibility},
+};
+use bevy_time::Time;
+use bevy_transform::components::Transform;
 use egui::{ecolor::Hsva, Color32};
 use std::any::{Any, TypeId};
+use std::time::Duration;
 
 use crate::{
-    bevy_inspector::errors::{dead_asset_handle, no_world_in_context, show_error},
+    bevy_inspector::{
+        errors::{dead_asset_handle, no_world_in_context, show_error},
+        hierarchy::entity_link,
+    },
     egui_utils,
-    inspector_options::std_options::{EntityDisplay, EntityOptions},
+    inspector_options::{
+        std_options::{EntityDisplay, EntityOptions},
+        InspectorOptions, Target,
+    },
     many_ui,
     reflect_inspector::{Context, InspectorUi},
+    restricted_world_view::{RestrictedWorldView, TypeRegistryCache},
 };
 
 pub fn entity_ui(
@@ -30,6 +48,17 @@ pub fn entity_ui(
         EntityDisplay::Id => {
             ui.label(format!("{entity:?}"));
         }
+        EntityDisplay::NamePicker => {
+            let Some(world) = &env.context.world else {
+                no_world_in_context(ui, "Entity");
+                return false;
+            };
+            let Some(picked) = entity_name_picker(world, entity, ui, id) else {
+                return false;
+            };
+            *value.downcast_mut::<Entity>().unwrap() = picked;
+            return true;
+        }
         EntityDisplay::Components => {
             let Context {
                 world: Some(world),
@@ -46,13 +75,15 @@ pub fn entity_ui(
                 .id_source(id)
                 .show(ui, |ui| {
                     let _queue = CommandQueue::default();
-                    crate::bevy_inspector::ui_for_entity_components(
+                    crate::bevy_inspector::entity_components_ui(
                         world,
                         queue.as_deref_mut(),
                         entity,
                         ui,
                         id,
-                        env.type_registry,
+                        &TypeRegistryCache::new(env.type_registry),
+                        &Default::default(),
+                        None,
                     );
                     if options.despawnable && world.contains_entity(entity) {
                         if let Some(queue) = queue {
@@ -67,6 +98,76 @@ pub fn entity_ui(
 
     false
 }
+
+/// Dropdown of the named entities, returning the one which was picked if it isn't `entity` already.
+///
+/// The named entities are only collected while the dropdown is open.
+fn entity_name_picker(
+    world: &RestrictedWorldView<'_>,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> Option<Entity> {
+    let selected_text = entity_name(world, entity).unwrap_or_else(|| format!("{entity:?}"));
+
+    let mut picked = None;
+    egui::ComboBox::from_id_source(id.with("name picker"))
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            for (named_entity, label) in named_entities(world) {
+                if ui.selectable_label(named_entity == entity, label).clicked() {
+                    picked = Some(named_entity);
+                }
+            }
+        });
+    picked.filter(|picked| *picked != entity)
+}
+
+/// The [`Name`] of the `entity`, if it has one and the world allows access to it
+fn entity_name(world: &RestrictedWorldView<'_>, entity: Entity) -> Option<String> {
+    if !world.allows_access_to_component((entity, TypeId::of::<Name>())) {
+        return None;
+    }
+    // SAFETY: the world allows access to the component and the reference is dropped right away
+    let name = unsafe { world.world().get_entity(entity)?.get::<Name>()? };
+    Some(name.as_str().to_owned())
+}
+
+/// The accessible entities with a [`Name`] sorted by it, labelled with the id as well if the name is used more than once
+fn named_entities(world: &RestrictedWorldView<'_>) -> Vec<(Entity, String)> {
+    let cell = world.world();
+    let Some(component_id) = cell.components().get_id(TypeId::of::<Name>()) else {
+        return Vec::new();
+    };
+
+    let mut named: Vec<(Entity, String)> = cell
+        .archetypes()
+        .iter()
+        .filter(|archetype| archetype.contains(component_id))
+        .flat_map(|archetype| archetype.entities())
+        .map(|archetype_entity| archetype_entity.entity())
+        .filter(|entity| world.allows_access_to_component((*entity, TypeId::of::<Name>())))
+        .filter_map(|entity| {
+            // SAFETY: the world allows access to the component and the reference is dropped right away
+            let name = unsafe { cell.get_entity(entity)?.get::<Name>()? };
+            Some((entity, name.as_str().to_owned()))
+        })
+        .collect();
+    named.sort_by(|(a, a_name), (b, b_name)| a_name.cmp(b_name).then(a.cmp(b)));
+
+    let duplicate = |i: usize| {
+        let name = &named[i].1;
+        (i > 0 && named[i - 1].1 == *name) || named.get(i + 1).is_some_and(|(_, next)| next == name)
+    };
+    let duplicates: Vec<bool> = (0..named.len()).map(duplicate).collect();
+    for ((entity, label), duplicate) in named.iter_mut().zip(duplicates) {
+        if duplicate {
+            *label = format!("{label} ({entity:?})");
+        }
+    }
+    named
+}
+
 pub fn entity_ui_readonly(
     value: &dyn Any,
     ui: &mut egui::Ui,
@@ -78,6 +179,62 @@ pub fn entity_ui_readonly(
     ui.label(format!("{entity:?}"));
 }
 
+pub fn parent_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let parent = value.downcast_ref::<Parent>().unwrap();
+    entity_link(parent.get(), ui, &mut env);
+    false
+}
+pub fn parent_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) {
+    let parent = value.downcast_ref::<Parent>().unwrap();
+    entity_link(parent.get(), ui, &mut env);
+}
+
+pub fn children_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let children = value.downcast_ref::<Children>().unwrap();
+    children_ui_inner(children, ui, &mut env);
+    false
+}
+pub fn children_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) {
+    let children = value.downcast_ref::<Children>().unwrap();
+    children_ui_inner(children, ui, &mut env);
+}
+
+fn children_ui_inner(children: &Children, ui: &mut egui::Ui, env: &mut InspectorUi<'_, '_>) {
+    ui.vertical(|ui| {
+        ui.weak(match children.len() {
+            1 => "1 child".to_string(),
+            n => format!("{n} children"),
+        });
+        for &child in children {
+            entity_link(child, ui, env);
+        }
+    });
+}
+
 pub fn mesh_ui(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
@@ -189,6 +346,143 @@ fn mesh_ui_inner(mesh: &Mesh, ui: &mut egui::Ui) {
     });
 }
 
+/// Dropdown of the assets in `Assets<A>` to reassign the handle, followed by the asset itself if
+/// `A` is registered with `register_asset_reflect`.
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite"))]
+pub fn asset_picker_ui<A: bevy_asset::Asset>(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let handle = value.downcast_mut::<Handle<A>>().unwrap();
+
+    let Some(world) = &mut env.context.world else {
+        no_world_in_context(ui, &pretty_type_name::pretty_type_name::<Handle<A>>());
+        return false;
+    };
+    let Some(selected) = asset_combo_box::<A>(handle.id(), world, ui, id, true) else {
+        return false;
+    };
+    let mut changed = false;
+    if selected != handle.id() {
+        // the combo box only lists assets which exist, so `Assets<A>` is accessible
+        let assets = world.get_resource_mut::<Assets<A>>().unwrap();
+        *handle = assets.get_handle(selected);
+        changed = true;
+    }
+
+    changed |=
+        crate::bevy_inspector::short_circuit::short_circuit(&mut env, handle, ui, id, options)
+            .unwrap_or(false);
+    changed
+}
+
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite"))]
+pub fn asset_picker_ui_readonly<A: bevy_asset::Asset>(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) {
+    let handle = value.downcast_ref::<Handle<A>>().unwrap();
+
+    let Some(world) = &mut env.context.world else {
+        no_world_in_context(ui, &pretty_type_name::pretty_type_name::<Handle<A>>());
+        return;
+    };
+    if asset_combo_box::<A>(handle.id(), world, ui, id, false).is_none() {
+        return;
+    }
+
+    crate::bevy_inspector::short_circuit::short_circuit_readonly(&mut env, handle, ui, id, options);
+}
+
+/// Editing several handles at once works like it does without a picker, by editing the shared asset
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite"))]
+pub fn asset_picker_ui_many<A: bevy_asset::Asset>(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn bevy_reflect::Reflect],
+    projector: &dyn Fn(&mut dyn bevy_reflect::Reflect) -> &mut dyn bevy_reflect::Reflect,
+) -> bool {
+    let type_name = pretty_type_name::pretty_type_name::<Handle<A>>();
+    crate::bevy_inspector::short_circuit::short_circuit_many(
+        &mut env,
+        TypeId::of::<Handle<A>>(),
+        &type_name,
+        ui,
+        id,
+        options,
+        values,
+        projector,
+    )
+    .unwrap_or_else(|| {
+        crate::reflect_inspector::errors::no_multiedit(ui, &type_name);
+        false
+    })
+}
+
+/// Shows the assets sorted by id and labeled by their path if they were loaded through the asset server.
+///
+/// Returns the selected id, or `None` if `Assets<A>` can't be accessed.
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite"))]
+fn asset_combo_box<A: bevy_asset::Asset>(
+    current: HandleId,
+    world: &mut RestrictedWorldView<'_>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    enabled: bool,
+) -> Option<HandleId> {
+    if let Err(error) = world.get_resource_mut::<Assets<A>>() {
+        show_error(
+            error,
+            ui,
+            &pretty_type_name::pretty_type_name::<Assets<A>>(),
+        );
+        return None;
+    }
+
+    let mut selected = current;
+    ui.add_enabled_ui(enabled, |ui| {
+        egui::ComboBox::from_id_source(id.with("asset picker"))
+            .selected_text(asset_label(world, current))
+            .show_ui(ui, |ui| {
+                let Ok(assets) = world.get_resource_mut::<Assets<A>>() else {
+                    return;
+                };
+                let mut ids: Vec<_> = assets.ids().collect();
+                ids.sort();
+
+                for id in ids {
+                    let label = asset_label(world, id);
+                    ui.selectable_value(&mut selected, id, label);
+                }
+            });
+    });
+    Some(selected)
+}
+
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite"))]
+fn asset_label(world: &mut RestrictedWorldView<'_>, handle: HandleId) -> String {
+    world
+        .allows_access_to_resource(TypeId::of::<AssetServer>())
+        .then(|| world.get_resource_mut::<AssetServer>().ok())
+        .flatten()
+        .and_then(|asset_server| {
+            let path = asset_server.get_handle_path(handle)?;
+            Some(match path.label() {
+                Some(label) => format!("{}#{label}", path.path().display()),
+                None => path.path().display().to_string(),
+            })
+        })
+        .unwrap_or_else(|| format!("{handle:?}"))
+}
+
 pub fn handle_id_ui(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
@@ -233,32 +527,41 @@ pub fn color_ui(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
     _: &dyn Any,
-    _: egui::Id,
+    id: egui::Id,
     _: InspectorUi<'_, '_>,
 ) -> bool {
     let value = value.downcast_mut::<Color>().unwrap();
 
-    color_ui_inner(value, ui)
+    color_ui_inner(value, ui, id)
 }
 
 pub fn color_ui_readonly(
     value: &dyn Any,
     ui: &mut egui::Ui,
     _: &dyn Any,
-    _: egui::Id,
+    id: egui::Id,
     _: InspectorUi<'_, '_>,
 ) {
     let value = value.downcast_ref::<Color>().unwrap();
 
     ui.add_enabled_ui(false, |ui| {
         let mut color = *value;
-        color_ui_inner(&mut color, ui);
+        color_ui_inner(&mut color, ui, id);
     });
 }
 
 many_ui!(color_ui_many color_ui Color);
 
-fn color_ui_inner(value: &mut Color, ui: &mut egui::Ui) -> bool {
+fn color_ui_inner(value: &mut Color, ui: &mut egui::Ui, id: egui::Id) -> bool {
+    ui.horizontal(|ui| {
+        let mut changed = color_picker_ui(value, ui);
+        changed |= color_hex_ui(value, ui, id);
+        changed
+    })
+    .inner
+}
+
+fn color_picker_ui(value: &mut Color, ui: &mut egui::Ui) -> bool {
     match value {
         Color::Rgba {
             red,
@@ -326,6 +629,61 @@ fn color_ui_inner(value: &mut Color, ui: &mut egui::Ui) -> bool {
     false
 }
 
+/// Text field with the color as a `#RRGGBB` or `#RRGGBBAA` hex code. Edits are applied once they are a valid
+/// hex code, until then the text is kept and the field gets a red border.
+fn color_hex_ui(value: &mut Color, ui: &mut egui::Ui, id: egui::Id) -> bool {
+    let edited_id = id.with("hex");
+    let mut text = ui
+        .memory_mut(|mem| mem.data.get_temp::<String>(edited_id))
+        .unwrap_or_else(|| color_to_hex(*value));
+
+    let valid = Color::hex(&text).is_ok();
+    let response = ui
+        .scope(|ui| {
+            if !valid {
+                let stroke = egui::Stroke::new(1.0, ui.visuals().error_fg_color);
+                let visuals = ui.visuals_mut();
+                visuals.selection.stroke = stroke;
+                visuals.widgets.inactive.bg_stroke = stroke;
+                visuals.widgets.hovered.bg_stroke = stroke;
+            }
+            ui.add(
+                egui::TextEdit::singleline(&mut text)
+                    .font(egui::TextStyle::Monospace)
+                    .desired_width(80.0),
+            )
+        })
+        .inner;
+
+    let mut changed = false;
+    let parsed = Color::hex(&text);
+    if let (true, &Ok(color)) = (response.changed(), &parsed) {
+        // keep the color space of the value
+        *value = match value {
+            Color::Rgba { .. } => color,
+            Color::RgbaLinear { .. } => color.as_rgba_linear(),
+            Color::Hsla { .. } => color.as_hsla(),
+            Color::Lcha { .. } => color.as_lcha(),
+        };
+        changed = true;
+    }
+
+    let keep_text = parsed.is_err() || response.has_focus();
+    ui.memory_mut(|mem| match keep_text {
+        true => mem.data.insert_temp(edited_id, text),
+        false => mem.data.remove::<String>(edited_id),
+    });
+    changed
+}
+
+fn color_to_hex(color: Color) -> String {
+    // unlike `Color::as_rgba_u8` this rounds, so that converting from linear colors doesn't turn `FF` into `FE`
+    match color.as_rgba_f32().map(|c| (c * 255.0).round() as u8) {
+        [r, g, b, 255] => format!("#{r:02X}{g:02X}{b:02X}"),
+        [r, g, b, a] => format!("#{r:02X}{g:02X}{b:02X}{a:02X}"),
+    }
+}
+
 pub fn render_layers_ui(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
@@ -386,3 +744,865 @@ pub fn render_layers_ui_readonly(
 }
 
 many_ui!(render_layers_ui_many render_layers_ui RenderLayers);
+
+/// Segmented button for the three [`Visibility`] states
+pub fn visibility_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) -> bool {
+    let value = value.downcast_mut::<Visibility>().unwrap();
+
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(visibility_icon(*value));
+        for (visibility, label) in [
+            (Visibility::Inherited, "Inherited"),
+            (Visibility::Hidden, "Hidden"),
+            (Visibility::Visible, "Visible"),
+        ] {
+            changed |= ui.selectable_value(value, visibility, label).changed();
+        }
+    });
+    changed
+}
+
+pub fn visibility_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let value = value.downcast_ref::<Visibility>().unwrap();
+    ui.label(format!("{} {value:?}", visibility_icon(*value)));
+}
+
+many_ui!(visibility_ui_many visibility_ui Visibility);
+
+fn visibility_icon(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Inherited => "👁 ⬆",
+        Visibility::Hidden => "🚫",
+        Visibility::Visible => "👁",
+    }
+}
+
+/// [`ComputedVisibility`] is computed every frame from the [`Visibility`] of the entity and its ancestors,
+/// so it is only displayed
+pub fn computed_visibility_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+) -> bool {
+    computed_visibility_ui_readonly(value, ui, options, id, env);
+    false
+}
+
+pub fn computed_visibility_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let value = value.downcast_ref::<ComputedVisibility>().unwrap();
+    let yes_no = |visible: bool| if visible { "yes" } else { "no" };
+
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        ui.label("Visible in hierarchy");
+        ui.weak(yes_no(value.is_visible_in_hierarchy()));
+        ui.end_row();
+        ui.label("Visible in view");
+        ui.weak(yes_no(value.is_visible_in_view()));
+        ui.end_row();
+    });
+}
+
+pub fn computed_visibility_ui_many(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn Reflect],
+    projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+) -> bool {
+    let same = super::iter_all_eq(values.iter_mut().map(|value| {
+        projector(*value)
+            .downcast_ref::<ComputedVisibility>()
+            .unwrap()
+    }));
+    match same {
+        Some(value) => computed_visibility_ui_readonly(value, ui, options, id, env),
+        None => {
+            ui.weak("differs between the entities");
+        }
+    }
+    false
+}
+
+/// Only the [`Time::relative_speed`] can be edited, the elapsed and delta time are advanced by bevy every frame
+pub fn time_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    _: InspectorUi<'_, '_>,
+) -> bool {
+    let value = value.downcast_mut::<Time>().unwrap();
+
+    let mut changed = false;
+    time_grid(value, ui, id, |value, ui| {
+        let mut relative_speed = value.relative_speed_f64();
+        changed = ui
+            .add(
+                egui::DragValue::new(&mut relative_speed)
+                    .clamp_range(0.0..=f64::MAX)
+                    .speed(0.01)
+                    .suffix("×"),
+            )
+            .changed();
+        if changed {
+            value.set_relative_speed_f64(relative_speed);
+        }
+    });
+    changed
+}
+
+pub fn time_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let value = value.downcast_ref::<Time>().unwrap();
+    time_grid(value, ui, id, |value, ui| {
+        ui.weak(format!("{:.2}×", value.relative_speed_f64()));
+    });
+}
+
+fn time_grid<T: std::ops::Deref<Target = Time>>(
+    mut value: T,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    relative_speed_ui: impl FnOnce(&mut T, &mut egui::Ui),
+) {
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        ui.label("Elapsed");
+        ui.weak(format_elapsed(value.elapsed()));
+        ui.end_row();
+        ui.label("Delta");
+        ui.weak(format!("{:.2} ms", value.delta().as_secs_f64() * 1000.0));
+        ui.end_row();
+        ui.label("Relative speed");
+        relative_speed_ui(&mut value, ui);
+        ui.end_row();
+        ui.label("Paused");
+        ui.weak(if value.is_paused() { "yes" } else { "no" });
+        ui.end_row();
+    });
+}
+
+/// `hh:mm:ss.mmm`, with the hours growing past two digits if necessary
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        elapsed.subsec_millis()
+    )
+}
+
+/// Which pair of values is used to edit a [`Rect`]
+#[derive(Clone, Copy, PartialEq, Default)]
+enum RectEditMode {
+    #[default]
+    MinMax,
+    CenterSize,
+}
+
+pub fn rect_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let value = value.downcast_mut::<Rect>().unwrap();
+
+    let mode_id = id.with("rect_mode");
+    let mut mode = ui.data_mut(|data| *data.get_temp_mut_or_default::<RectEditMode>(mode_id));
+
+    let mut changed = false;
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut mode, RectEditMode::MinMax, "min/max");
+            ui.selectable_value(&mut mode, RectEditMode::CenterSize, "center/size");
+        });
+        egui::Grid::new(id).show(ui, |ui| match mode {
+            RectEditMode::MinMax => {
+                let (mut min, mut max) = (value.min, value.max);
+                ui.label("min");
+                changed |= env.ui_for_reflect_with_options(&mut min, ui, id.with("min"), &());
+                ui.end_row();
+                ui.label("max");
+                changed |= env.ui_for_reflect_with_options(&mut max, ui, id.with("max"), &());
+                ui.end_row();
+                if changed {
+                    *value = edit_rect_corners(*value, min, max);
+                }
+            }
+            RectEditMode::CenterSize => {
+                let (mut center, mut size) = (value.center(), value.size());
+                ui.label("center");
+                changed |= env.ui_for_reflect_with_options(&mut center, ui, id.with("center"), &());
+                ui.end_row();
+                ui.label("size");
+                changed |= env.ui_for_reflect_with_options(&mut size, ui, id.with("size"), &());
+                ui.end_row();
+                if changed {
+                    *value = Rect::from_center_size(center, size.max(Vec2::ZERO));
+                }
+            }
+        });
+    });
+    ui.data_mut(|data| data.insert_temp(mode_id, mode));
+
+    changed
+}
+
+pub fn rect_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let value = value.downcast_ref::<Rect>().unwrap();
+    ui.label(format!(
+        "min: ({:.2}, {:.2}), max: ({:.2}, {:.2})",
+        value.min.x, value.min.y, value.max.x, value.max.y
+    ));
+}
+
+/// Edits the corners of all rects together, where a minimum moved past the maximum pushes it along
+pub fn rect_ui_many(
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn Reflect],
+    projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+) -> bool {
+    let mut changed = false;
+    egui::Grid::new(id).show(ui, |ui| {
+        ui.label("min");
+        changed |= env.ui_for_reflect_many(
+            TypeId::of::<Vec2>(),
+            "Vec2",
+            ui,
+            id.with("min"),
+            values,
+            &|value| &mut projector(value).downcast_mut::<Rect>().unwrap().min,
+        );
+        ui.end_row();
+        ui.label("max");
+        changed |= env.ui_for_reflect_many(
+            TypeId::of::<Vec2>(),
+            "Vec2",
+            ui,
+            id.with("max"),
+            values,
+            &|value| &mut projector(value).downcast_mut::<Rect>().unwrap().max,
+        );
+        ui.end_row();
+    });
+    if changed {
+        for value in values.iter_mut() {
+            let rect = projector(*value).downcast_mut::<Rect>().unwrap();
+            rect.max = rect.max.max(rect.min);
+        }
+    }
+    changed
+}
+
+/// Applies edited corners to `rect`, clamping the one which was moved past the other so that `min <= max` still holds
+fn edit_rect_corners(rect: Rect, min: Vec2, max: Vec2) -> Rect {
+    let min = min.min(rect.max);
+    let max = max.max(min);
+    Rect { min, max }
+}
+
+pub fn aabb_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let value = value.downcast_mut::<Aabb>().unwrap();
+
+    let mut changed = false;
+    egui::Grid::new(id).show(ui, |ui| {
+        ui.label("center");
+        changed |= env.ui_for_reflect_with_options(&mut value.center, ui, id.with("center"), &());
+        ui.end_row();
+        ui.label("half extents");
+        let mut half_extents = value.half_extents;
+        changed |=
+            env.ui_for_reflect_with_options(&mut half_extents, ui, id.with("half_extents"), &());
+        ui.end_row();
+        if changed {
+            value.half_extents = valid_half_extents(half_extents);
+        }
+    });
+    changed
+}
+
+pub fn aabb_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let value = value.downcast_ref::<Aabb>().unwrap();
+    let (min, max) = (value.min(), value.max());
+    ui.label(format!(
+        "min: ({:.2}, {:.2}, {:.2}), max: ({:.2}, {:.2}, {:.2})",
+        min.x, min.y, min.z, max.x, max.y, max.z
+    ));
+}
+
+/// Negative half extents would mean `min > max`
+fn valid_half_extents(half_extents: Vec3A) -> Vec3A {
+    half_extents.max(Vec3A::ZERO)
+}
+
+/// Edits the fields of the transform in a grid. The rotation is shown like any other [`Quat`],
+/// so the [`QuatOptions`](crate::inspector_options::std_options::QuatOptions) of the `rotation` field pick its display.
+pub fn transform_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool {
+    let value = value.downcast_mut::<Transform>().unwrap();
+    let field_options = |field: usize| {
+        options
+            .downcast_ref::<InspectorOptions>()
+            .and_then(|options| options.get(Target::Field(field)))
+            .unwrap_or(&())
+    };
+
+    let mut changed = false;
+    egui::Grid::new(id).show(ui, |ui| {
+        ui.label("translation");
+        changed |= env.ui_for_reflect_with_options(
+            &mut value.translation,
+            ui,
+            id.with("translation"),
+            field_options(0),
+        );
+        ui.end_row();
+
+        ui.label("rotation");
+        changed |= env.ui_for_reflect_with_options(
+            &mut value.rotation,
+            ui,
+            id.with("rotation"),
+            field_options(1),
+        );
+        ui.end_row();
+
+        ui.label("scale");
+        changed |= env.ui_for_reflect_with_options(
+            &mut value.scale,
+            ui,
+            id.with("scale"),
+            field_options(2),
+        );
+        ui.end_row();
+    });
+
+    changed
+}
+
+pub fn transform_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+) {
+    let mut value = *value.downcast_ref::<Transform>().unwrap();
+    ui.add_enabled_ui(false, |ui| {
+        transform_ui(&mut value, ui, options, id, env);
+    });
+}
+
+/// Edits the fields of all transforms together, leaving the fields which weren't changed alone
+pub fn transform_ui_many(
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn Reflect],
+    projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+) -> bool {
+    let mut changed = false;
+    egui::Grid::new(id).show(ui, |ui| {
+        ui.label("translation");
+        changed |= env.ui_for_reflect_many(
+            TypeId::of::<Vec3>(),
+            "Vec3",
+            ui,
+            id.with("translation"),
+            values,
+            &|value| {
+                &mut projector(value)
+                    .downcast_mut::<Transform>()
+                    .unwrap()
+                    .translation
+            },
+        );
+        ui.end_row();
+
+        ui.label("rotation");
+        changed |= env.ui_for_reflect_many(
+            TypeId::of::<Quat>(),
+            "Quat",
+            ui,
+            id.with("rotation"),
+            values,
+            &|value| {
+                &mut projector(value)
+                    .downcast_mut::<Transform>()
+                    .unwrap()
+                    .rotation
+            },
+        );
+        ui.end_row();
+
+        ui.label("scale");
+        changed |= env.ui_for_reflect_many(
+            TypeId::of::<Vec3>(),
+            "Vec3",
+            ui,
+            id.with("scale"),
+            values,
+            &|value| &mut projector(value).downcast_mut::<Transform>().unwrap().scale,
+        );
+        ui.end_row();
+    });
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+    use std::cell::RefCell;
+
+    use bevy_core::Name;
+    use bevy_core_pipeline::clear_color::ClearColor;
+    use bevy_ecs::{entity::Entity, world::World};
+    use bevy_hierarchy::{BuildWorldChildren, Children};
+    use bevy_math::{Quat, Rect, Vec2, Vec3, Vec3A};
+    use bevy_reflect::{Reflect, TypeRegistry};
+    use bevy_render::{color::Color, view::Visibility};
+    use bevy_time::Time;
+    use bevy_transform::components::Transform;
+    use bevy_utils::{Duration, Instant};
+
+    use crate::bevy_inspector::hierarchy::SelectedEntities;
+    use crate::inspector_egui_impls::{
+        many_unimplemented, InspectorEguiImpl, RegisterInspectorEguiImpl,
+    };
+    use crate::inspector_options::std_options::{
+        EntityDisplay, EntityOptions, QuatDisplay, QuatOptions,
+    };
+    use crate::inspector_options::{InspectorOptions, Target};
+    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::restricted_world_view::RestrictedWorldView;
+    use crate::utils::tests::{std_type_registry, TestUi};
+
+    #[test]
+    fn children_links_select() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.add_inspector_egui_impl::<Children>(InspectorEguiImpl::new(
+            super::children_ui,
+            super::children_ui_readonly,
+            many_unimplemented::<Children>,
+        ));
+
+        let mut world = World::new();
+        world.init_resource::<SelectedEntities>();
+        let mut children = Vec::new();
+        let parent = world
+            .spawn(Name::new("Parent"))
+            .with_children(|parent| {
+                children.push(parent.spawn(Name::new("Child A")).id());
+                children.push(parent.spawn(Name::new("Child B")).id());
+            })
+            .id();
+        // despawned without updating the `Children` of the parent
+        world.despawn(children[0]);
+
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let world = RestrictedWorldView::new(&mut world);
+            let (mut value, world) = world
+                .try_split_off_component_typed::<Children>(parent)
+                .unwrap()
+                .unwrap();
+            let mut cx = Context {
+                world: Some(world),
+                queue: None,
+            };
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut *value, ui);
+        };
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("2 children").is_some());
+        assert!(test_ui
+            .text_position(&format!("{:?} (despawned)", children[0]))
+            .is_some());
+
+        let link = test_ui.text_position("Child B").unwrap();
+        test_ui.click(link, &mut add_contents);
+        assert_eq!(
+            world.resource::<SelectedEntities>().as_slice(),
+            &[children[1]]
+        );
+    }
+
+    #[test]
+    fn entity_name_picker() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.add_inspector_egui_impl::<Entity>(InspectorEguiImpl::new(
+            super::entity_ui,
+            super::entity_ui_readonly,
+            many_unimplemented::<Entity>,
+        ));
+        let options = EntityOptions {
+            display: EntityDisplay::NamePicker,
+            ..Default::default()
+        };
+
+        let mut world = World::new();
+        let player = world.spawn(Name::new("Player")).id();
+        let _first_enemy = world.spawn(Name::new("Enemy")).id();
+        let second_enemy = world.spawn(Name::new("Enemy")).id();
+        world.spawn_empty();
+        let mut target = player;
+
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let mut cx = Context {
+                world: Some(RestrictedWorldView::new(&mut world)),
+                queue: None,
+            };
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut target,
+                ui,
+                egui::Id::new("target"),
+                &options,
+            );
+        };
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+
+        let combo_box = test_ui.text_position("Player").unwrap();
+        test_ui.click(combo_box, &mut add_contents);
+        test_ui.run(&mut add_contents);
+        // the nameless entity isn't listed and the duplicate names are told apart by their id
+        assert!(test_ui.text_position("Enemy").is_none());
+        let pick = test_ui
+            .text_position(&format!("Enemy ({second_enemy:?})"))
+            .unwrap();
+        test_ui.click(pick, &mut add_contents);
+
+        assert_eq!(target, second_enemy);
+    }
+
+    #[test]
+    fn transform_rotation_options() {
+        let mut type_registry = std_type_registry();
+        crate::inspector_egui_impls::register_glam_impls(&mut type_registry);
+        crate::inspector_egui_impls::register_bevy_impls(&mut type_registry);
+
+        let mut options = InspectorOptions::new();
+        options.insert(
+            Target::Field(1),
+            QuatOptions {
+                display: QuatDisplay::AxisAngle,
+            },
+        );
+
+        let rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        let mut transform = Transform::from_rotation(rotation);
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut transform,
+                ui,
+                egui::Id::new("transform"),
+                &options,
+            );
+        };
+        let mut test_ui = TestUi::new();
+        // grids are only painted from the second frame on
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+        // the rotation is edited like any other quaternion
+        assert!(test_ui.text_position("Axis").is_some());
+        assert!(test_ui.text_position("xyzw").is_some());
+
+        let toggle = test_ui.text_position("euler").unwrap();
+        test_ui.click(toggle, &mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("Axis").is_none());
+        assert!(transform.rotation.abs_diff_eq(rotation, 1e-6));
+    }
+
+    #[test]
+    fn transform_multi_edit() {
+        let mut type_registry = std_type_registry();
+        crate::inspector_egui_impls::register_glam_impls(&mut type_registry);
+        crate::inspector_egui_impls::register_bevy_impls(&mut type_registry);
+
+        let mut transforms = [
+            Transform::from_xyz(3.0, 4.0, 5.0),
+            Transform::from_xyz(3.0, 4.0, 6.0),
+        ];
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let [a, b] = &mut transforms;
+            let mut values: [&mut dyn Reflect; 2] = [a, b];
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_many(
+                TypeId::of::<Transform>(),
+                "Transform",
+                ui,
+                egui::Id::new("transforms"),
+                &mut values,
+                &|value| value,
+            );
+        };
+        let mut test_ui = TestUi::new();
+        // grids are only painted from the second frame on
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert!(test_ui
+            .text_position("Transform doesn't support multi-editing.")
+            .is_none());
+
+        let x = test_ui.text_position("3.0").unwrap();
+        test_ui.replace_text(x, "3.0", "7.0", &mut add_contents);
+
+        // only the edited component is written to both
+        assert_eq!(transforms[0].translation, Vec3::new(7.0, 4.0, 5.0));
+        assert_eq!(transforms[1].translation, Vec3::new(7.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn color_hex_input() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.add_inspector_egui_impl::<Color>(InspectorEguiImpl::new(
+            super::color_ui,
+            super::color_ui_readonly,
+            super::color_ui_many,
+        ));
+
+        let color = RefCell::new(Color::rgb(1.0, 1.0, 1.0).as_rgba_linear());
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut *color.borrow_mut(), ui);
+        };
+        test_ui.run(&mut ui);
+        let text_field = test_ui.text_position("#FFFFFF").unwrap();
+        test_ui.click(text_field, &mut ui);
+        test_ui.key(egui::Key::End);
+        for _ in 0.."#FFFFFF".len() {
+            test_ui.key(egui::Key::Backspace);
+        }
+        test_ui.type_text("#FF8000");
+        test_ui.run(&mut ui);
+
+        let expected = Color::rgb_u8(255, 128, 0).as_rgba_linear();
+        assert_eq!(*color.borrow(), expected);
+        assert!(matches!(*color.borrow(), Color::RgbaLinear { .. }));
+
+        // an incomplete hex code isn't applied
+        test_ui.key(egui::Key::Backspace);
+        test_ui.run(&mut ui);
+        assert_eq!(*color.borrow(), expected);
+    }
+
+    #[test]
+    fn color_through_newtype() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.add_inspector_egui_impl::<Color>(InspectorEguiImpl::new(
+            super::color_ui,
+            super::color_ui_readonly,
+            super::color_ui_many,
+        ));
+
+        let clear_color = RefCell::new(ClearColor(Color::BLACK));
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut *clear_color.borrow_mut(), ui);
+        };
+        test_ui.run(&mut ui);
+        let text_field = test_ui.text_position("#000000").unwrap();
+        test_ui.click(text_field, &mut ui);
+        test_ui.key(egui::Key::End);
+        for _ in 0.."#000000".len() {
+            test_ui.key(egui::Key::Backspace);
+        }
+        test_ui.type_text("#FF8000");
+        test_ui.run(&mut ui);
+
+        assert_eq!(clear_color.borrow().0, Color::rgb_u8(255, 128, 0));
+    }
+
+    #[test]
+    fn color_to_hex() {
+        assert_eq!(super::color_to_hex(Color::rgb_u8(255, 128, 0)), "#FF8000");
+        assert_eq!(
+            super::color_to_hex(Color::rgba_u8(18, 52, 86, 120)),
+            "#12345678"
+        );
+        let color = Color::hex("#12345678").unwrap();
+        assert_eq!(color.as_rgba_u8(), [0x12, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    fn visibility_buttons() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.add_inspector_egui_impl::<Visibility>(InspectorEguiImpl::new(
+            super::visibility_ui,
+            super::visibility_ui_readonly,
+            super::visibility_ui_many,
+        ));
+
+        let mut visibility = Visibility::Inherited;
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut visibility, ui);
+        };
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position("Hidden").unwrap();
+        test_ui.click(pos, &mut ui);
+        assert_eq!(visibility, Visibility::Hidden);
+    }
+
+    #[test]
+    fn time_relative_speed_edit() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.add_inspector_egui_impl::<Time>(InspectorEguiImpl::new(
+            super::time_ui,
+            super::time_ui_readonly,
+            many_unimplemented::<Time>,
+        ));
+
+        let startup = Instant::now();
+        let mut time = Time::new(startup);
+        time.update_with_instant(startup + Duration::from_secs_f64(3723.5));
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut time, ui);
+        };
+        // grids are only painted from the second frame on
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("01:02:03.500").is_some());
+
+        let drag_value = test_ui.text_position("1.00×").unwrap();
+        test_ui.replace_text(drag_value, "1.00", "0.5", &mut ui);
+
+        assert_eq!(time.relative_speed(), 0.5);
+    }
+
+    #[test]
+    fn rect_corners_stay_ordered() {
+        let rect = Rect::new(0.0, 0.0, 1.0, 1.0);
+
+        let moved_min = super::edit_rect_corners(rect, Vec2::new(2.0, 0.5), rect.max);
+        assert_eq!(moved_min.min, Vec2::new(1.0, 0.5));
+        assert_eq!(moved_min.max, rect.max);
+
+        let moved_max = super::edit_rect_corners(rect, rect.min, Vec2::new(-1.0, 3.0));
+        assert_eq!(moved_max.min, rect.min);
+        assert_eq!(moved_max.max, Vec2::new(0.0, 3.0));
+
+        let untouched = super::edit_rect_corners(rect, rect.min, rect.max);
+        assert_eq!(untouched, rect);
+    }
+
+    #[test]
+    fn aabb_half_extents_non_negative() {
+        let half_extents = super::valid_half_extents(Vec3A::new(-1.0, 0.0, 2.0));
+        assert_eq!(half_extents, Vec3A::new(0.0, 0.0, 2.0));
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_pbr")]
+    fn pick_other_material() {
+        use bevy_app::App;
+        use bevy_asset::{AddAsset, AssetPlugin, Assets, Handle};
+        use bevy_pbr::StandardMaterial;
+
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Handle<StandardMaterial>>();
+        type_registry.add_inspector_egui_impl::<Handle<StandardMaterial>>(InspectorEguiImpl::new(
+            super::asset_picker_ui::<StandardMaterial>,
+            super::asset_picker_ui_readonly::<StandardMaterial>,
+            super::asset_picker_ui_many::<StandardMaterial>,
+        ));
+
+        let mut app = App::new();
+        app.add_plugins(AssetPlugin::default())
+            .add_asset::<StandardMaterial>();
+        let mut materials = app.world.resource_mut::<Assets<StandardMaterial>>();
+        let red = materials.add(Color::RED.into());
+        let blue = materials.add(Color::BLUE.into());
+
+        let handle = RefCell::new(red.clone());
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let mut cx = Context {
+                world: Some(RestrictedWorldView::new(&mut app.world)),
+                queue: None,
+            };
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut *handle.borrow_mut(), ui);
+        };
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut add_contents);
+        let combo_box = test_ui.text_position(&format!("{:?}", red.id())).unwrap();
+        test_ui.click(combo_box, &mut add_contents);
+        test_ui.run(&mut add_contents);
+        let option = test_ui.text_position(&format!("{:?}", blue.id())).unwrap();
+        test_ui.click(option, &mut add_contents);
+
+        assert_eq!(handle.borrow().id(), blue.id());
+        assert!(handle.borrow().is_strong());
+    }
+}
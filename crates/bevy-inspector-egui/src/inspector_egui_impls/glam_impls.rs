@@ -3,7 +3,7 @@ use std::any::Any;
 use bevy_math::{prelude::*, DMat2, DMat3, DMat4, DVec2, DVec3, DVec4, Mat3A, Vec3A};
 use bevy_reflect::Reflect;
 
-use crate::inspector_options::std_options::NumberOptions;
+use crate::inspector_options::std_options::{NumberOptions, Vec2Options, Vec3Options, Vec4Options};
 use crate::reflect_inspector::InspectorUi;
 
 macro_rules! vec_ui_many {
@@ -54,7 +54,7 @@ macro_rules! vec_ui_many {
 }
 
 macro_rules! vec_ui {
-    ($name:ident $name_readonly:ident $ty:ty: $count:literal $($component:ident)*) => {
+    ($name:ident $name_readonly:ident $ty:ty => $options:ident: $count:literal $($component:ident)*) => {
         pub fn $name(
             value: &mut dyn Any,
             ui: &mut egui::Ui,
@@ -64,21 +64,14 @@ macro_rules! vec_ui {
         ) -> bool {
             let value = value.downcast_mut::<$ty>().unwrap();
 
-            let options = options
-                .downcast_ref::<NumberOptions<$ty>>()
-                .cloned()
-                .unwrap_or_default();
+            let options = vec_options::<$options<$ty>, $ty>(options);
+            let [$($component),*] = options.components();
 
             let mut changed = false;
-            ui.scope(|ui| {
-                ui.style_mut().spacing.item_spacing = egui::Vec2::new(4.0, 0.);
-
-                ui.columns($count, |ui| match ui {
-                    [$($component),*] => {
-                        $(changed |= env.ui_for_reflect_with_options(&mut value.$component, $component, id.with(stringify!($component)), &options.map(|vec| vec.$component));)*
-                    }
-                    _ => unreachable!(),
-                });
+            vec_layout(ui, id, $count, options.expanded, |ui| {
+                $(for_component(ui, stringify!($component), options.expanded, |ui| {
+                    changed |= env.ui_for_reflect_with_options(&mut value.$component, ui, id.with(stringify!($component)), &$component);
+                });)*
             });
             changed
         }
@@ -86,26 +79,78 @@ macro_rules! vec_ui {
         pub fn $name_readonly(
             value: &dyn Any,
             ui: &mut egui::Ui,
-            _: &dyn Any,
-            _: egui::Id,
+            options: &dyn Any,
+            id: egui::Id,
             mut env: InspectorUi<'_, '_>,
         ) {
             let value = value.downcast_ref::<$ty>().unwrap();
+            let expanded = vec_options::<$options<$ty>, $ty>(options).expanded;
 
-            ui.scope(|ui| {
-                ui.style_mut().spacing.item_spacing = egui::Vec2::new(4.0, 0.);
-
-                ui.columns($count, |ui| match ui {
-                    [$($component),*] => {
-                        $(env.ui_for_reflect_readonly(&value.$component, $component);)*
-                    }
-                    _ => unreachable!(),
-                });
+            vec_layout(ui, id, $count, expanded, |ui| {
+                $(for_component(ui, stringify!($component), expanded, |ui| {
+                    env.ui_for_reflect_readonly(&value.$component, ui);
+                });)*
             });
         }
     };
 }
 
+/// The options of a vector, which are either per-component options like [`Vec3Options`] or [`NumberOptions`] for all components
+fn vec_options<O, V>(options: &dyn Any) -> O
+where
+    O: Clone + Default + From<NumberOptions<V>> + 'static,
+    V: Clone + 'static,
+{
+    if let Some(options) = options.downcast_ref::<O>() {
+        return options.clone();
+    }
+    options
+        .downcast_ref::<NumberOptions<V>>()
+        .cloned()
+        .map_or_else(O::default, O::from)
+}
+
+/// Lays out the components of a vector on a single line, or in a grid with one row per component if `expanded`
+fn vec_layout(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    count: usize,
+    expanded: bool,
+    add_components: impl FnOnce(&mut egui::Ui),
+) {
+    if expanded {
+        egui::Grid::new(id.with("expanded"))
+            .num_columns(2)
+            .show(ui, add_components);
+        return;
+    }
+
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+        // share the line between the components, their labels and the spacing take up about 16 points each
+        let width = ui.available_width() / count as f32 - 16.0;
+        ui.spacing_mut().interact_size.x = width.max(24.0);
+        add_components(ui);
+    });
+}
+
+/// Shows the name of the component in front of its value, ending the grid row if `expanded`
+fn for_component(
+    ui: &mut egui::Ui,
+    name: &str,
+    expanded: bool,
+    add_value: impl FnOnce(&mut egui::Ui),
+) {
+    if expanded {
+        ui.label(name);
+        add_value(ui);
+        ui.end_row();
+    } else {
+        ui.label(egui::RichText::new(name).small().weak());
+        add_value(ui);
+    }
+}
+
 macro_rules! mat_ui {
     ($name:ident $name_readonly:ident $ty:ty: $($component:ident)*) => {
         pub fn $name(
@@ -140,22 +185,22 @@ macro_rules! mat_ui {
     };
 }
 
-vec_ui!(vec2_ui vec2_ui_readonly Vec2: 2 x y);
-vec_ui!(vec3_ui vec3_ui_readonly Vec3: 3 x y z);
-vec_ui!(vec3a_ui vec3a_ui_readonly Vec3A: 3 x y z);
-vec_ui!(vec4_ui vec4_ui_readonly Vec4: 4 x y z w);
-vec_ui!(uvec2_ui uvec2_ui_readonly UVec2: 2 x y);
-vec_ui!(uvec3_ui uvec3_ui_readonly UVec3: 3 x y z);
-vec_ui!(uvec4_ui uvec4_ui_readonly UVec4: 4 x y z w);
-vec_ui!(ivec2_ui ivec2_ui_readonly IVec2: 2 x y);
-vec_ui!(ivec3_ui ivec3_ui_readonly IVec3: 3 x y z);
-vec_ui!(ivec4_ui ivec4_ui_readonly IVec4: 4 x y z w);
-vec_ui!(dvec2_ui dvec2_ui_readonly DVec2: 2 x y);
-vec_ui!(dvec3_ui dvec3_ui_readonly DVec3: 3 x y z);
-vec_ui!(dvec4_ui dvec4_ui_readonly DVec4: 4 x y z w);
-vec_ui!(bvec2_ui bvec2_ui_readonly BVec2: 2 x y);
-vec_ui!(bvec3_ui bvec3_ui_readonly BVec3: 3 x y z);
-vec_ui!(bvec4_ui bvec4_ui_readonly BVec4: 4 x y z w);
+vec_ui!(vec2_ui vec2_ui_readonly Vec2 => Vec2Options: 2 x y);
+vec_ui!(vec3_ui vec3_ui_readonly Vec3 => Vec3Options: 3 x y z);
+vec_ui!(vec3a_ui vec3a_ui_readonly Vec3A => Vec3Options: 3 x y z);
+vec_ui!(vec4_ui vec4_ui_readonly Vec4 => Vec4Options: 4 x y z w);
+vec_ui!(uvec2_ui uvec2_ui_readonly UVec2 => Vec2Options: 2 x y);
+vec_ui!(uvec3_ui uvec3_ui_readonly UVec3 => Vec3Options: 3 x y z);
+vec_ui!(uvec4_ui uvec4_ui_readonly UVec4 => Vec4Options: 4 x y z w);
+vec_ui!(ivec2_ui ivec2_ui_readonly IVec2 => Vec2Options: 2 x y);
+vec_ui!(ivec3_ui ivec3_ui_readonly IVec3 => Vec3Options: 3 x y z);
+vec_ui!(ivec4_ui ivec4_ui_readonly IVec4 => Vec4Options: 4 x y z w);
+vec_ui!(dvec2_ui dvec2_ui_readonly DVec2 => Vec2Options: 2 x y);
+vec_ui!(dvec3_ui dvec3_ui_readonly DVec3 => Vec3Options: 3 x y z);
+vec_ui!(dvec4_ui dvec4_ui_readonly DVec4 => Vec4Options: 4 x y z w);
+vec_ui!(bvec2_ui bvec2_ui_readonly BVec2 => Vec2Options: 2 x y);
+vec_ui!(bvec3_ui bvec3_ui_readonly BVec3 => Vec3Options: 3 x y z);
+vec_ui!(bvec4_ui bvec4_ui_readonly BVec4 => Vec4Options: 4 x y z w);
 vec_ui_many!(vec2_ui_many Vec2>f32: 2 x y);
 vec_ui_many!(vec3_ui_many Vec3>f32: 3 x y z);
 vec_ui_many!(vec3a_ui_many Vec3A>f32: 3 x y z);
@@ -182,7 +227,7 @@ pub mod quat {
     use std::any::Any;
 
     use bevy_egui::egui;
-    use bevy_math::{prelude::*, EulerRot};
+    use bevy_math::{prelude::*, DQuat, DVec4, EulerRot};
 
     use crate::{
         inspector_options::std_options::{QuatDisplay, QuatOptions},
@@ -213,8 +258,14 @@ pub mod quat {
             Quat::from_euler(EulerRot::XYZ, self.0.x, self.0.y, self.0.z)
         }
 
-        fn ui(&mut self, ui: &mut egui::Ui, mut env: InspectorUi<'_, '_>) -> bool {
-            env.ui_for_reflect(&mut self.0, ui)
+        fn ui(&mut self, ui: &mut egui::Ui, _env: InspectorUi<'_, '_>) -> bool {
+            let mut changed = false;
+            ui.horizontal(|ui| {
+                for angle in [&mut self.0.x, &mut self.0.y, &mut self.0.z] {
+                    changed |= ui.drag_angle(angle).changed();
+                }
+            });
+            changed
         }
     }
 
@@ -256,12 +307,7 @@ pub mod quat {
 
         fn to_quat(self) -> Quat {
             let (axis, angle) = self.0;
-            let axis = axis.normalize();
-            if axis.is_nan() {
-                Quat::IDENTITY
-            } else {
-                Quat::from_axis_angle(axis.normalize(), angle)
-            }
+            axis_angle_to_quat(axis, angle)
         }
 
         fn ui(&mut self, ui: &mut egui::Ui, mut env: InspectorUi<'_, '_>) -> bool {
@@ -282,59 +328,121 @@ pub mod quat {
         }
     }
 
+    /// Rotation by `angle` around the `axis`, which doesn't need to be normalized.
+    /// A zero axis means no rotation.
+    pub(super) fn axis_angle_to_quat(axis: Vec3, angle: f32) -> Quat {
+        match axis.try_normalize() {
+            Some(axis) => Quat::from_axis_angle(axis, angle),
+            None => Quat::IDENTITY,
+        }
+    }
+
     fn quat_ui_kind<T: Send + Sync + 'static + Copy + RotationEdit>(
         val: &mut Quat,
         ui: &mut egui::Ui,
+        id: egui::Id,
         env: InspectorUi<'_, '_>,
     ) -> bool {
-        let id = ui.id();
         let mut intermediate = ui.memory_mut(|memory| {
             *memory
                 .data
                 .get_temp_mut_or_insert_with(id, || T::from_quat(*val))
         });
 
-        let externally_changed = !intermediate.to_quat().abs_diff_eq(*val, std::f32::EPSILON);
+        // converting back and forth isn't exact, so the value is only written when it was edited
+        let externally_changed = !same_rotation(intermediate.to_quat(), *val);
         if externally_changed {
             intermediate = T::from_quat(*val);
         }
 
         let changed = intermediate.ui(ui, env);
-
+        if changed {
+            *val = intermediate.to_quat().normalize();
+        }
         if changed || externally_changed {
-            *val = intermediate.to_quat();
             ui.memory_mut(|memory| memory.data.insert_temp(id, intermediate));
         }
 
         changed
     }
 
+    /// `q` and `-q` describe the same rotation
+    fn same_rotation(a: Quat, b: Quat) -> bool {
+        a.dot(b).abs() >= 1.0 - 1e-6
+    }
+
+    impl QuatDisplay {
+        const ALL: [(QuatDisplay, &'static str); 4] = [
+            (QuatDisplay::Euler, "euler"),
+            (QuatDisplay::YawPitchRoll, "yaw/pitch/roll"),
+            (QuatDisplay::AxisAngle, "axis-angle"),
+            (QuatDisplay::Raw, "xyzw"),
+        ];
+    }
+
+    /// Buttons switching how the rotation is edited. The choice is kept in egui memory for the widget `id`,
+    /// starting out with the display from the [`QuatOptions`].
+    fn display_toggle_ui(ui: &mut egui::Ui, id: egui::Id, options: &dyn Any) -> QuatDisplay {
+        let display_id = id.with("quat display");
+        let mut display = ui.data_mut(|data| data.get_temp::<QuatDisplay>(display_id));
+        let display = display.get_or_insert_with(|| {
+            options
+                .downcast_ref::<QuatOptions>()
+                .map_or_else(QuatDisplay::default, |options| options.display)
+        });
+
+        ui.horizontal(|ui| {
+            for (value, label) in QuatDisplay::ALL {
+                ui.selectable_value(display, value, egui::RichText::new(label).small());
+            }
+        });
+        ui.data_mut(|data| data.insert_temp(display_id, *display));
+        *display
+    }
+
+    /// Edits the quaternion in its components, normalizing it after every change
+    pub(crate) fn raw_quat_ui(
+        value: &mut Quat,
+        ui: &mut egui::Ui,
+        env: &mut InspectorUi<'_, '_>,
+    ) -> bool {
+        let mut vec4 = Vec4::from(*value);
+        let changed = env.ui_for_reflect(&mut vec4, ui);
+        if changed {
+            *value = Quat::from_vec4(vec4).normalize();
+        }
+        changed
+    }
+
+    fn quat_ui_with_display(
+        value: &mut Quat,
+        ui: &mut egui::Ui,
+        display: QuatDisplay,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        match display {
+            QuatDisplay::Raw => raw_quat_ui(value, ui, &mut env),
+            QuatDisplay::Euler => quat_ui_kind::<Euler>(value, ui, id, env),
+            QuatDisplay::YawPitchRoll => quat_ui_kind::<YawPitchRoll>(value, ui, id, env),
+            QuatDisplay::AxisAngle => quat_ui_kind::<AxisAngle>(value, ui, id, env),
+        }
+    }
+
+    /// Edits the rotation as euler angles in degrees, yaw/pitch/roll, axis-angle or the raw quaternion,
+    /// selected with buttons above the widget. The [`QuatOptions`] set which one is selected at first.
     pub fn quat_ui(
         value: &mut dyn Any,
         ui: &mut egui::Ui,
         options: &dyn Any,
-        _: egui::Id,
-        mut env: InspectorUi<'_, '_>,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
     ) -> bool {
         let value = value.downcast_mut::<Quat>().unwrap();
 
-        let options = options
-            .downcast_ref::<QuatOptions>()
-            .cloned()
-            .unwrap_or_default();
-
-        ui.vertical(|ui| match options.display {
-            QuatDisplay::Raw => {
-                let mut vec4 = Vec4::from(*value);
-                let changed = env.ui_for_reflect(&mut vec4, ui);
-                if changed {
-                    *value = Quat::from_vec4(vec4).normalize();
-                }
-                changed
-            }
-            QuatDisplay::Euler => quat_ui_kind::<Euler>(value, ui, env),
-            QuatDisplay::YawPitchRoll => quat_ui_kind::<YawPitchRoll>(value, ui, env),
-            QuatDisplay::AxisAngle => quat_ui_kind::<AxisAngle>(value, ui, env),
+        ui.vertical(|ui| {
+            let display = display_toggle_ui(ui, id, options);
+            quat_ui_with_display(value, ui, display, id, env)
         })
         .inner
     }
@@ -347,8 +455,277 @@ pub mod quat {
         env: InspectorUi<'_, '_>,
     ) {
         let mut value = *value.downcast_ref::<Quat>().unwrap();
-        ui.add_enabled_ui(false, |ui| quat_ui(&mut value, ui, options, id, env));
+        // only the editing is disabled, the display can still be switched
+        ui.vertical(|ui| {
+            let display = display_toggle_ui(ui, id, options);
+            ui.add_enabled_ui(false, |ui| {
+                quat_ui_with_display(&mut value, ui, display, id, env)
+            });
+        });
     }
 
     many_ui!(quat_ui_many quat_ui Quat);
+
+    /// Like [`quat_ui`], but the non-raw displays edit the rotation with `f32` precision.
+    /// The value is only written back when it was changed.
+    pub fn dquat_ui(
+        value: &mut dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let value = value.downcast_mut::<DQuat>().unwrap();
+
+        ui.vertical(|ui| {
+            let display = display_toggle_ui(ui, id, options);
+            dquat_ui_with_display(value, ui, display, id, env)
+        })
+        .inner
+    }
+
+    pub fn dquat_ui_readonly(
+        value: &dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut value = *value.downcast_ref::<DQuat>().unwrap();
+        ui.vertical(|ui| {
+            let display = display_toggle_ui(ui, id, options);
+            ui.add_enabled_ui(false, |ui| {
+                dquat_ui_with_display(&mut value, ui, display, id, env)
+            });
+        });
+    }
+
+    fn dquat_ui_with_display(
+        value: &mut DQuat,
+        ui: &mut egui::Ui,
+        display: QuatDisplay,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        match display {
+            QuatDisplay::Raw => {
+                let mut vec4 = DVec4::from(*value);
+                let changed = env.ui_for_reflect(&mut vec4, ui);
+                if changed {
+                    *value = DQuat::from_vec4(vec4).normalize();
+                }
+                changed
+            }
+            display => {
+                let mut quat = value.as_f32();
+                let changed = quat_ui_with_display(&mut quat, ui, display, id, env);
+                if changed {
+                    *value = quat.as_f64();
+                }
+                changed
+            }
+        }
+    }
+
+    many_ui!(dquat_ui_many dquat_ui DQuat);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use bevy_app::App;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use bevy_math::{DQuat, DVec3, Quat, Vec3};
+    use bevy_reflect::TypeRegistry;
+
+    use crate::inspector_options::std_options::{NumberOptions, QuatOptions, Vec3Options};
+    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::utils::tests::TestUi;
+
+    fn glam_type_registry() -> TypeRegistry {
+        let mut app = App::new();
+        app.add_plugins(bevy_core::TypeRegistrationPlugin);
+        let mut type_registry =
+            std::mem::take(&mut *app.world.resource::<AppTypeRegistry>().write());
+        crate::inspector_egui_impls::register_std_impls(&mut type_registry);
+        crate::inspector_egui_impls::register_glam_impls(&mut type_registry);
+        type_registry
+    }
+
+    /// Focuses the first field of the value using `Tab` and presses the `keys`
+    fn press_in_first_field(
+        value: &mut dyn bevy_reflect::Reflect,
+        options: &dyn Any,
+        keys: &[egui::Key],
+    ) {
+        let type_registry = glam_type_registry();
+        let mut test_ui = TestUi::new();
+        let mut ui =
+            |ui: &mut egui::Ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_options(value, ui, egui::Id::new("value"), options);
+            };
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Tab);
+        test_ui.run(&mut ui);
+        for &key in keys {
+            test_ui.key(key);
+            test_ui.run(&mut ui);
+        }
+    }
+
+    #[test]
+    fn edit_dvec3_with_f64_precision() {
+        let mut value = DVec3::new(1e9, 2.0, 3.0);
+        press_in_first_field(&mut value, &(), &[egui::Key::ArrowUp]);
+        assert_eq!(value, DVec3::new(1e9 + 0.1, 2.0, 3.0));
+    }
+
+    #[test]
+    fn dvec3_number_options() {
+        let mut value = DVec3::ZERO;
+        let options = NumberOptions::at_least(DVec3::ZERO);
+        // the value is clamped once the edit is committed
+        press_in_first_field(
+            &mut value,
+            &options,
+            &[egui::Key::ArrowDown, egui::Key::Enter],
+        );
+        assert_eq!(value, DVec3::ZERO);
+    }
+
+    #[test]
+    fn vec3_per_component_options() {
+        let type_registry = glam_type_registry();
+        let mut value = Vec3::splat(-1.0);
+        let options = Vec3Options {
+            min_y: Some(0.0),
+            ..Default::default()
+        };
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut value,
+                ui,
+                egui::Id::new("vec"),
+                &options,
+            );
+        });
+        assert_eq!(value, Vec3::new(-1.0, 0.0, -1.0));
+
+        // the uniform options apply to the components which don't override them
+        let options = Vec3Options {
+            max: Some(Vec3::splat(1.0)),
+            max_z: Some(2.0),
+            ..options
+        };
+        let components = options.components();
+        assert_eq!(
+            components.each_ref().map(|options| options.min),
+            [None, Some(0.0), None]
+        );
+        assert_eq!(
+            components.each_ref().map(|options| options.max),
+            [Some(1.0), Some(1.0), Some(2.0)]
+        );
+    }
+
+    #[test]
+    fn vec3_layout() {
+        let type_registry = glam_type_registry();
+        let component_positions = |options: &NumberOptions<Vec3>| {
+            let mut value = Vec3::ZERO;
+            let mut test_ui = TestUi::new();
+            let mut ui = |ui: &mut egui::Ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_options(&mut value, ui, egui::Id::new("vec"), options);
+            };
+            // grids are only shown after their first frame
+            test_ui.run(&mut ui);
+            test_ui.run(&mut ui);
+            ["x", "y", "z"].map(|label| test_ui.text_position(label).unwrap())
+        };
+
+        let [x, y, z] = component_positions(&NumberOptions::default());
+        assert!(x.y == y.y && y.y == z.y);
+        assert!(x.x < y.x && y.x < z.x);
+
+        let expanded = NumberOptions {
+            expanded: true,
+            ..Default::default()
+        };
+        let [x, y, z] = component_positions(&expanded);
+        // the labels are centered and have slightly different widths
+        assert!((x.x - y.x).abs() < 2.0 && (y.x - z.x).abs() < 2.0);
+        assert!(x.y < y.y && y.y < z.y);
+    }
+
+    #[test]
+    fn axis_angle_to_quat() {
+        let rotation = super::quat::axis_angle_to_quat(Vec3::new(0.0, 2.0, 0.0), FRAC_PI_2);
+        assert!(rotation.is_normalized());
+        assert!(rotation.abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2), 1e-6));
+
+        let rotation = super::quat::axis_angle_to_quat(Vec3::new(1.0, 1.0, 0.0), PI);
+        let expected = Quat::from_axis_angle(Vec3::new(1.0, 1.0, 0.0).normalize(), PI);
+        assert!(rotation.is_normalized());
+        assert!(rotation.abs_diff_eq(expected, 1e-6));
+
+        assert_eq!(
+            super::quat::axis_angle_to_quat(Vec3::ZERO, PI),
+            Quat::IDENTITY
+        );
+    }
+
+    #[test]
+    fn quat_display_is_remembered() {
+        let type_registry = glam_type_registry();
+        let mut value = Quat::from_rotation_y(FRAC_PI_2);
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut value,
+                ui,
+                egui::Id::new("rotation"),
+                &QuatOptions::default(),
+            );
+        };
+        // grids are only shown after their first frame
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Axis").is_none());
+
+        let toggle = test_ui.text_position("axis-angle").unwrap();
+        test_ui.click(toggle, &mut ui);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Axis").is_some());
+        assert!(test_ui.text_position("Angle").is_some());
+        // switching the display doesn't change the value
+        assert!(value.abs_diff_eq(Quat::from_rotation_y(FRAC_PI_2), 1e-6));
+    }
+
+    #[test]
+    fn dquat_is_kept_until_edited() {
+        let type_registry = glam_type_registry();
+        let mut value = DQuat::from_rotation_y(0.1234567890123);
+        let original = value;
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut value, ui);
+        });
+        assert_eq!(value, original);
+    }
 }
@@ -0,0 +1,104 @@
+//! UI for [`half::f16`], which is edited as an `f32` and rounded back after every edit.
+//!
+//! `bevy_reflect` doesn't implement [`Reflect`](bevy_reflect::Reflect) for [`f16`], so it can't be registered in the
+//! [`TypeRegistry`](bevy_reflect::TypeRegistry) directly. Wrap it in a `#[reflect_value]` newtype instead, and
+//! register an [`InspectorEguiImpl`](super::InspectorEguiImpl) for that which calls [`f16_ui`]:
+//!
+//! ```rust
+//! # use std::any::Any;
+//! # use bevy_reflect::Reflect;
+//! # use bevy_inspector_egui::inspector_egui_impls::{half_impls, InspectorEguiImpl};
+//! # use bevy_inspector_egui::reflect_inspector::InspectorUi;
+//! #[derive(Reflect, Clone, Copy)]
+//! #[reflect_value]
+//! struct Half(half::f16);
+//!
+//! fn half_ui(value: &mut dyn Any, ui: &mut egui::Ui, options: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+//!     half_impls::f16_ui(&mut value.downcast_mut::<Half>().unwrap().0, ui, options, id)
+//! }
+//! fn half_ui_readonly(value: &dyn Any, ui: &mut egui::Ui, options: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) {
+//!     half_impls::f16_ui_readonly(&value.downcast_ref::<Half>().unwrap().0, ui, options, id);
+//! }
+//!
+//! # fn half_ui_many(_: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>, _: &mut [&mut dyn Reflect], _: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect) -> bool { false }
+//!
+//! let half_impl = InspectorEguiImpl::new(half_ui, half_ui_readonly, half_ui_many);
+//! ```
+
+use std::any::Any;
+
+use half::f16;
+
+use super::std_impls::display_number;
+use crate::inspector_options::std_options::NumberOptions;
+
+/// Drag value for an [`f16`], which respects [`NumberOptions<f32>`].
+///
+/// Edits smaller than the precision of the `f16` accumulate in an `f32` remembered under the `id`,
+/// so slowly dragging still changes the value once it reaches the next representable number.
+/// The value itself is only written if the edit rounds to a different `f16`, and values out of its range are clamped
+/// instead of becoming infinite.
+pub fn f16_ui(value: &mut f16, ui: &mut egui::Ui, options: &dyn Any, id: egui::Id) -> bool {
+    let options = options
+        .downcast_ref::<NumberOptions<f32>>()
+        .cloned()
+        .unwrap_or_default();
+
+    let edited_id = id.with("f16");
+    let mut edited = ui
+        .data_mut(|data| data.get_temp::<f32>(edited_id))
+        // the value was changed elsewhere in the meantime
+        .filter(|&edited| f16::from_f32(edited).to_bits() == value.to_bits())
+        .unwrap_or_else(|| value.to_f32());
+
+    if !display_number(&mut edited, &options, ui, 0.1) {
+        return false;
+    }
+    let edited = edited.clamp(f16::MIN.to_f32(), f16::MAX.to_f32());
+    ui.data_mut(|data| data.insert_temp(edited_id, edited));
+
+    let rounded = f16::from_f32(edited);
+    let changed = rounded.to_bits() != value.to_bits();
+    *value = rounded;
+    changed
+}
+
+/// Read-only version of [`f16_ui`]
+pub fn f16_ui_readonly(value: &f16, ui: &mut egui::Ui, options: &dyn Any, id: egui::Id) {
+    ui.add_enabled_ui(false, |ui| {
+        let mut value = *value;
+        f16_ui(&mut value, ui, options, id);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use half::f16;
+
+    use crate::inspector_options::std_options::NumberOptions;
+    use crate::utils::tests::TestUi;
+
+    #[test]
+    fn edit_f16() {
+        let value = RefCell::new(f16::from_f32(1.5));
+        let options = NumberOptions::<f32> {
+            max: Some(10.0),
+            ..Default::default()
+        };
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            super::f16_ui(&mut value.borrow_mut(), ui, &options, egui::Id::new("half"));
+        };
+        test_ui.run(&mut ui);
+        let number = test_ui.text_position("1.5").unwrap();
+        test_ui.replace_text(number, "1.5", "2.25", &mut ui);
+        assert_eq!(*value.borrow(), f16::from_f32(2.25));
+
+        // the options are applied in `f32` space
+        test_ui.replace_text(number, "2.25", "100000", &mut ui);
+        assert_eq!(*value.borrow(), f16::from_f32(10.0));
+    }
+}
@@ -7,12 +7,18 @@ use std::{
 use bevy_asset::{Assets, Handle};
 use bevy_egui::EguiUserTextures;
 use bevy_reflect::Reflect;
-use bevy_render::texture::Image;
+use bevy_render::{
+    render_resource::{TextureDimension, TextureFormat, TextureSampleType},
+    texture::Image,
+};
 use once_cell::sync::Lazy;
 use pretty_type_name::pretty_type_name;
 
 use crate::{
-    bevy_inspector::errors::{no_world_in_context, show_error},
+    bevy_inspector::{
+        errors::{no_world_in_context, show_error},
+        short_circuit::load_state_ui,
+    },
     reflect_inspector::InspectorUi,
 };
 
@@ -32,7 +38,7 @@ pub fn image_handle_ui_readonly(
     value: &dyn Any,
     ui: &mut egui::Ui,
     _: &dyn Any,
-    _: egui::Id,
+    id: egui::Id,
     env: InspectorUi<'_, '_>,
 ) {
     let value = value.downcast_ref::<Handle<Image>>().unwrap();
@@ -40,6 +46,7 @@ pub fn image_handle_ui_readonly(
         no_world_in_context(ui, value.type_name());
         return;
     };
+    load_state_ui(world, value.id(), ui);
     let (mut egui_user_textures, mut images) =
         match world.get_two_resources_mut::<bevy_egui::EguiUserTextures, Assets<Image>>() {
             (Ok(a), Ok(b)) => (a, b),
@@ -63,16 +70,166 @@ pub fn image_handle_ui_readonly(
         &mut images,
         &mut egui_user_textures,
     );
-    let (rescaled_handle, texture_id) = match rescaled {
-        Some(it) => it,
-        None => {
-            ui.label("<texture>");
-            return;
+    if let Some((rescaled_handle, texture_id)) = rescaled {
+        let rescaled_image = images.get(&rescaled_handle).unwrap();
+        show_image(rescaled_image, texture_id, ui);
+    }
+
+    let Some(image) = images.get(value) else {
+        ui.label("<texture>");
+        return;
+    };
+    image_metadata_ui(image, ui);
+
+    if is_previewable(image) {
+        egui::CollapsingHeader::new("Zoom")
+            .id_source(id.with("zoom"))
+            .show(ui, |ui| {
+                let texture_id = egui_user_textures.add_image(value.clone_weak());
+                zoom_ui(image, texture_id, ui, id);
+            });
+    }
+}
+
+/// Displays the metadata of an [`Image`] asset, which is all that can be shown for images without a [`Handle`]
+pub fn image_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+) -> bool {
+    image_ui_readonly(value, ui, options, id, env);
+    false
+}
+
+pub fn image_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let image = value.downcast_ref::<Image>().unwrap();
+    image_metadata_ui(image, ui);
+}
+
+fn image_metadata_ui(image: &Image, ui: &mut egui::Ui) {
+    let descriptor = &image.texture_descriptor;
+    let size = descriptor.size;
+    let dimensions = match descriptor.dimension {
+        TextureDimension::D1 => format!("{}", size.width),
+        TextureDimension::D2 if size.depth_or_array_layers == 1 => {
+            format!("{} × {}", size.width, size.height)
         }
+        TextureDimension::D2 => format!(
+            "{} × {} ({} layers)",
+            size.width, size.height, size.depth_or_array_layers
+        ),
+        TextureDimension::D3 => format!(
+            "{} × {} × {}",
+            size.width, size.height, size.depth_or_array_layers
+        ),
     };
 
-    let rescaled_image = images.get(&rescaled_handle).unwrap();
-    show_image(rescaled_image, texture_id, ui);
+    ui.weak(format!(
+        "{dimensions}, {:?}, {} mip levels",
+        descriptor.format, descriptor.mip_level_count
+    ));
+}
+
+/// Whether the image can be displayed by egui, which is limited to single layer 2D images with a filterable float format
+fn is_previewable(image: &Image) -> bool {
+    let descriptor = &image.texture_descriptor;
+    descriptor.dimension == TextureDimension::D2
+        && descriptor.size.depth_or_array_layers == 1
+        && !descriptor.format.is_compressed()
+        && descriptor.format.sample_type(None)
+            == Some(TextureSampleType::Float { filterable: true })
+}
+
+fn zoom_ui(image: &Image, texture_id: egui::TextureId, ui: &mut egui::Ui, id: egui::Id) {
+    let zoom_id = id.with("zoom factor");
+    let mut zoom = ui.data_mut(|data| *data.get_temp_mut_or(zoom_id, 1.0f32));
+    ui.add(
+        egui::Slider::new(&mut zoom, 0.1..=16.0)
+            .logarithmic(true)
+            .text("Zoom"),
+    );
+    ui.data_mut(|data| data.insert_temp(zoom_id, zoom));
+
+    let size = image.texture_descriptor.size;
+    let size = egui::vec2(size.width as f32, size.height as f32);
+    let response = egui::ScrollArea::both()
+        .id_source(id.with("zoom scroll"))
+        .max_height(512.0)
+        .show(ui, |ui| {
+            ui.add(egui::Image::new(texture_id, size * zoom).sense(egui::Sense::hover()))
+        })
+        .inner;
+
+    let hovered_pixel = response
+        .hover_pos()
+        .and_then(|pos| pixel_at((pos - response.rect.min) / zoom, size));
+    match hovered_pixel {
+        Some((x, y)) => match pixel_color(image, x, y) {
+            Some(color) => {
+                ui.horizontal(|ui| {
+                    let (rect, _) =
+                        ui.allocate_exact_size(egui::Vec2::splat(12.0), egui::Sense::hover());
+                    ui.painter().rect_filled(rect, 2.0, color);
+                    let [r, g, b, a] = color.to_srgba_unmultiplied();
+                    ui.label(format!("({x}, {y}): rgba({r}, {g}, {b}, {a})"));
+                });
+            }
+            None => {
+                ui.label(format!("({x}, {y})"));
+            }
+        },
+        None => {
+            ui.weak("Hover the image to read a pixel");
+        }
+    }
+}
+
+/// The pixel at the `offset` from the top left corner of an image with the given `size`, clamped to the image.
+///
+/// Returns `None` if the image is empty.
+fn pixel_at(offset: egui::Vec2, size: egui::Vec2) -> Option<(u32, u32)> {
+    let (width, height) = (size.x as u32, size.y as u32);
+    if width == 0 || height == 0 {
+        return None;
+    }
+    Some((
+        (offset.x as u32).min(width - 1),
+        (offset.y as u32).min(height - 1),
+    ))
+}
+
+/// Color of the pixel at `(x, y)` in the first mip level, for the 8 bit per channel formats
+fn pixel_color(image: &Image, x: u32, y: u32) -> Option<egui::Color32> {
+    let descriptor = &image.texture_descriptor;
+    if x >= descriptor.size.width || y >= descriptor.size.height {
+        return None;
+    }
+    let pixel_size = descriptor.format.block_size(None)? as usize;
+    let offset = (y as usize * descriptor.size.width as usize + x as usize) * pixel_size;
+    let pixel = image.data.get(offset..offset + pixel_size)?;
+
+    let color = match (descriptor.format, pixel) {
+        (TextureFormat::R8Unorm, &[value]) => egui::Color32::from_gray(value),
+        (TextureFormat::Rg8Unorm, &[value, alpha]) => {
+            egui::Color32::from_rgba_unmultiplied(value, value, value, alpha)
+        }
+        (TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb, &[r, g, b, a]) => {
+            egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+        }
+        (TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb, &[b, g, r, a]) => {
+            egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+        }
+        _ => return None,
+    };
+    Some(color)
 }
 
 static SCALED_DOWN_TEXTURES: Lazy<Mutex<ScaledDownTextures>> = Lazy::new(Default::default);
@@ -121,6 +278,9 @@ fn rescaled_image<'a>(
             }
 
             let original = textures.get(handle)?;
+            if !is_previewable(original) {
+                return None;
+            }
 
             let (image, is_srgb) = image_texture_conversion::try_into_dynamic(original)?;
             let resized = image.resize(
@@ -142,3 +302,105 @@ fn rescaled_image<'a>(
 
     Some((texture, texture_id))
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin, AssetServer, Handle, LoadState};
+    use bevy_egui::EguiUserTextures;
+    use bevy_reflect::TypeRegistry;
+    use bevy_render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::Image,
+    };
+    use bevy_tasks::{IoTaskPool, TaskPoolBuilder};
+
+    use crate::inspector_egui_impls::{InspectorEguiImpl, RegisterInspectorEguiImpl};
+    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::restricted_world_view::RestrictedWorldView;
+    use crate::utils::tests::TestUi;
+
+    #[test]
+    fn failed_image_load_state() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Handle<Image>>();
+        type_registry.add_inspector_egui_impl::<Handle<Image>>(InspectorEguiImpl::new(
+            super::image_handle_ui,
+            super::image_handle_ui_readonly,
+            crate::inspector_egui_impls::many_unimplemented::<Handle<Image>>,
+        ));
+
+        // without threads, the load task only runs when this thread ticks the pool in `scope`
+        let io_pool = IoTaskPool::init(|| TaskPoolBuilder::new().num_threads(0).build());
+        let mut app = App::new();
+        app.add_plugins(AssetPlugin::default())
+            .add_asset::<Image>()
+            .init_resource::<EguiUserTextures>();
+        // no loader is registered for images, so loading one always fails
+        let mut handle: Handle<Image> = app.world.resource::<AssetServer>().load("missing.png");
+        let asset_server = app.world.resource::<AssetServer>();
+        io_pool.scope(|scope| {
+            scope.spawn(async {
+                while asset_server.get_load_state(&handle) != LoadState::Failed {
+                    futures_lite::future::yield_now().await;
+                }
+            });
+        });
+
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let mut cx = Context {
+                world: Some(RestrictedWorldView::new(&mut app.world)),
+                queue: None,
+            };
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut handle, ui);
+        };
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("Failed").is_some());
+        assert!(test_ui.text_position("missing.png").is_some());
+    }
+
+    #[test]
+    fn pixel_colors() {
+        let size = Extent3d {
+            width: 2,
+            height: 2,
+            depth_or_array_layers: 1,
+        };
+        #[rustfmt::skip]
+        let data = vec![
+            255, 0, 0, 255,   0, 255, 0, 255,
+            0, 0, 255, 255,   0, 0, 0, 0,
+        ];
+        let image = Image::new(
+            size,
+            TextureDimension::D2,
+            data.clone(),
+            TextureFormat::Rgba8UnormSrgb,
+        );
+        assert!(super::is_previewable(&image));
+        assert_eq!(super::pixel_color(&image, 1, 0), Some(egui::Color32::GREEN));
+        assert_eq!(super::pixel_color(&image, 0, 1), Some(egui::Color32::BLUE));
+        assert_eq!(super::pixel_color(&image, 2, 0), None);
+
+        let image = Image::new(
+            size,
+            TextureDimension::D3,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+        );
+        assert!(!super::is_previewable(&image));
+    }
+
+    #[test]
+    fn hovered_pixel() {
+        let size = egui::vec2(4.0, 2.0);
+        assert_eq!(super::pixel_at(egui::vec2(1.5, 0.5), size), Some((1, 0)));
+        assert_eq!(super::pixel_at(egui::vec2(4.0, 2.0), size), Some((3, 1)));
+        assert_eq!(
+            super::pixel_at(egui::vec2(0.0, 0.0), egui::Vec2::ZERO),
+            None
+        );
+    }
+}
@@ -91,7 +91,7 @@ pub fn from_dynamic(dyn_img: DynamicImage, is_srgb: bool) -> Image {
                 let r = pixel[0];
                 let g = pixel[1];
                 let b = pixel[2];
-                let a = u16::max_value();
+                let a = u16::MAX;
 
                 local_data.extend_from_slice(&r.to_ne_bytes());
                 local_data.extend_from_slice(&g.to_ne_bytes());
@@ -122,7 +122,7 @@ pub fn from_dynamic(dyn_img: DynamicImage, is_srgb: bool) -> Image {
                 let r = pixel[0];
                 let g = pixel[1];
                 let b = pixel[2];
-                let a = u16::max_value();
+                let a = u16::MAX;
 
                 local_data.extend_from_slice(&r.to_ne_bytes());
                 local_data.extend_from_slice(&g.to_ne_bytes());
@@ -1,7 +1,8 @@
 //! UI implementations for leaf types
 
 use crate::reflect_inspector::{errors::no_multiedit, InspectorUi};
-use bevy_reflect::{Reflect, TypeRegistry};
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::{GetTypeRegistration, Reflect, TypeRegistry};
 use bevy_utils::Instant;
 use std::{
     any::{Any, TypeId},
@@ -10,8 +11,12 @@ use std::{
 
 mod bevy_impls;
 mod glam_impls;
+#[cfg(feature = "half")]
+pub mod half_impls;
 mod image;
-mod std_impls;
+#[cfg(feature = "bevy_scene")]
+mod scene;
+pub(crate) mod std_impls;
 
 type InspectorEguiImplFn =
     fn(&mut dyn Any, &mut egui::Ui, &dyn Any, egui::Id, InspectorUi<'_, '_>) -> bool;
@@ -51,6 +56,28 @@ impl InspectorEguiImpl {
         }
     }
 
+    /// Displays a `bevy_utils::HashSet<T>`, whose elements are opaque to `bevy_reflect`.
+    ///
+    /// The [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin) registers this for sets of strings and
+    /// some integer types, other element types can be registered with [`RegisterInspectorEguiImpl`]:
+    /// ```rust
+    /// # use bevy_app::App;
+    /// # use bevy_utils::HashSet;
+    /// # use bevy_inspector_egui::inspector_egui_impls::{InspectorEguiImpl, RegisterInspectorEguiImpl};
+    /// App::new()
+    ///     .add_inspector_egui_impl::<HashSet<i32>>(InspectorEguiImpl::for_hash_set::<i32>());
+    /// ```
+    pub fn for_hash_set<T>() -> Self
+    where
+        T: Reflect + GetTypeRegistration + Default + Clone + Eq + std::hash::Hash,
+    {
+        InspectorEguiImpl::new(
+            std_impls::hash_set_ui::<T>,
+            std_impls::hash_set_ui_readonly::<T>,
+            many_unimplemented::<bevy_utils::HashSet<T>>,
+        )
+    }
+
     pub fn execute<'a, 'c: 'a>(
         &'a self,
         value: &mut dyn Any,
@@ -84,7 +111,63 @@ impl InspectorEguiImpl {
     }
 }
 
-fn many_unimplemented<T: Any>(
+/// Extension trait for registering a custom [`InspectorEguiImpl`] for a type, replacing the default one.
+///
+/// The [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin) registers its impls when it is added,
+/// so the last registration wins: add your impls after the plugin to override the defaults.
+///
+/// ```rust
+/// # use bevy_app::App;
+/// # use bevy_inspector_egui::inspector_egui_impls::{InspectorEguiImpl, RegisterInspectorEguiImpl};
+/// # use bevy_inspector_egui::reflect_inspector::InspectorUi;
+/// # use bevy_math::Vec3;
+/// # use std::any::Any;
+/// fn vec3_ui(value: &mut dyn Any, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+///     let value = value.downcast_mut::<Vec3>().unwrap();
+///     ui.add(egui::Slider::new(&mut value.x, 0.0..=1.0)).changed()
+/// }
+/// # fn vec3_ui_readonly(_: &dyn Any, _: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {}
+/// # fn vec3_ui_many(_: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>, _: &mut [&mut dyn bevy_reflect::Reflect], _: &dyn Fn(&mut dyn bevy_reflect::Reflect) -> &mut dyn bevy_reflect::Reflect) -> bool { false }
+///
+/// App::new()
+///     // .add_plugins(DefaultInspectorConfigPlugin)
+///     .add_inspector_egui_impl::<Vec3>(InspectorEguiImpl::new(vec3_ui, vec3_ui_readonly, vec3_ui_many));
+/// ```
+pub trait RegisterInspectorEguiImpl {
+    /// Registers `T` if necessary and sets its [`InspectorEguiImpl`], overriding any previous one
+    fn add_inspector_egui_impl<T: Reflect + GetTypeRegistration>(
+        &mut self,
+        inspector_egui_impl: InspectorEguiImpl,
+    ) -> &mut Self;
+}
+
+impl RegisterInspectorEguiImpl for TypeRegistry {
+    fn add_inspector_egui_impl<T: Reflect + GetTypeRegistration>(
+        &mut self,
+        inspector_egui_impl: InspectorEguiImpl,
+    ) -> &mut Self {
+        self.register::<T>();
+        self.get_mut(TypeId::of::<T>())
+            .unwrap()
+            .insert(inspector_egui_impl);
+        self
+    }
+}
+
+impl RegisterInspectorEguiImpl for bevy_app::App {
+    fn add_inspector_egui_impl<T: Reflect + GetTypeRegistration>(
+        &mut self,
+        inspector_egui_impl: InspectorEguiImpl,
+    ) -> &mut Self {
+        self.world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .add_inspector_egui_impl::<T>(inspector_egui_impl);
+        self
+    }
+}
+
+pub(crate) fn many_unimplemented<T: Any>(
     ui: &mut egui::Ui,
     _options: &dyn Any,
     _id: egui::Id,
@@ -96,29 +179,48 @@ fn many_unimplemented<T: Any>(
     false
 }
 
-fn add_no_many<T: 'static>(
+/// `T` is registered if necessary, since the plugins registering it might be added later or not at all,
+/// e.g. when filling a `TypeRegistry` without an `App` with [`register_default_config`](crate::register_default_config)
+fn add_no_many<T: Reflect + GetTypeRegistration>(
     type_registry: &mut TypeRegistry,
     fn_mut: InspectorEguiImplFn,
     fn_readonly: InspectorEguiImplFnReadonly,
 ) {
+    type_registry.register::<T>();
     type_registry
         .get_mut(TypeId::of::<T>())
-        .unwrap_or_else(|| panic!("{} not registered", std::any::type_name::<T>()))
+        .unwrap()
         .insert(InspectorEguiImpl::new(
             fn_mut,
             fn_readonly,
             many_unimplemented::<T>,
         ));
 }
-fn add<T: 'static>(
+fn add_ranges<T: egui::emath::Numeric + bevy_reflect::TypePath + Send + Sync>(
+    type_registry: &mut TypeRegistry,
+) {
+    add_no_many::<std::ops::Range<T>>(
+        type_registry,
+        std_impls::range_ui::<T>,
+        std_impls::range_ui_readonly::<T>,
+    );
+    add_no_many::<std::ops::RangeInclusive<T>>(
+        type_registry,
+        std_impls::range_inclusive_ui::<T>,
+        std_impls::range_inclusive_ui_readonly::<T>,
+    );
+}
+/// Like [`add_no_many`], with support for multi-editing
+fn add<T: Reflect + GetTypeRegistration>(
     type_registry: &mut TypeRegistry,
     fn_mut: InspectorEguiImplFn,
     fn_readonly: InspectorEguiImplFnReadonly,
     fn_many: InspectorEguiImplFnMany,
 ) {
+    type_registry.register::<T>();
     type_registry
         .get_mut(TypeId::of::<T>())
-        .unwrap_or_else(|| panic!("{} not registered", std::any::type_name::<T>()))
+        .unwrap()
         .insert(InspectorEguiImpl::new(fn_mut, fn_readonly, fn_many));
 }
 
@@ -137,11 +239,35 @@ pub fn register_std_impls(type_registry: &mut TypeRegistry) {
     add::<u32>(type_registry, std_impls::number_ui::<u32>, std_impls::number_ui_readonly::<u32>, std_impls::number_ui_many::<u32>);
     add::<u64>(type_registry, std_impls::number_ui::<u64>, std_impls::number_ui_readonly::<u64>, std_impls::number_ui_many::<u64>);
     add::<usize>(type_registry, std_impls::number_ui::<usize>, std_impls::number_ui_readonly::<usize>, std_impls::number_ui_many::<usize>);
+    add_no_many::<std::num::NonZeroU8>(type_registry, std_impls::nonzero_u8_ui, std_impls::nonzero_u8_ui_readonly);
+    add_no_many::<std::num::NonZeroU16>(type_registry, std_impls::nonzero_u16_ui, std_impls::nonzero_u16_ui_readonly);
+    add_no_many::<std::num::NonZeroU32>(type_registry, std_impls::nonzero_u32_ui, std_impls::nonzero_u32_ui_readonly);
+    add_no_many::<std::num::NonZeroU64>(type_registry, std_impls::nonzero_u64_ui, std_impls::nonzero_u64_ui_readonly);
+    add_no_many::<std::num::NonZeroUsize>(type_registry, std_impls::nonzero_usize_ui, std_impls::nonzero_usize_ui_readonly);
+    add_no_many::<std::num::NonZeroI8>(type_registry, std_impls::nonzero_i8_ui, std_impls::nonzero_i8_ui_readonly);
+    add_no_many::<std::num::NonZeroI16>(type_registry, std_impls::nonzero_i16_ui, std_impls::nonzero_i16_ui_readonly);
+    add_no_many::<std::num::NonZeroI32>(type_registry, std_impls::nonzero_i32_ui, std_impls::nonzero_i32_ui_readonly);
+    add_no_many::<std::num::NonZeroI64>(type_registry, std_impls::nonzero_i64_ui, std_impls::nonzero_i64_ui_readonly);
+    add_no_many::<std::num::NonZeroIsize>(type_registry, std_impls::nonzero_isize_ui, std_impls::nonzero_isize_ui_readonly);
+    add_ranges::<f32>(type_registry);
+    add_ranges::<f64>(type_registry);
+    add_ranges::<i32>(type_registry);
+    add_ranges::<i64>(type_registry);
+    add_ranges::<u32>(type_registry);
+    add_ranges::<u64>(type_registry);
+    add_ranges::<usize>(type_registry);
     add::<bool>(type_registry, std_impls::bool_ui, std_impls::bool_ui_readonly, std_impls::bool_ui_many);
     add::<String>(type_registry, std_impls::string_ui, std_impls::string_ui_readonly, std_impls::string_ui_many);
     add::<Cow<str>>(type_registry, std_impls::cow_str_ui, std_impls::cow_str_ui_readonly, std_impls::cow_str_ui_many);
+    add::<std::path::PathBuf>(type_registry, std_impls::path_buf_ui, std_impls::path_buf_ui_readonly, std_impls::path_buf_ui_many);
     add_no_many::<std::time::Duration>(type_registry, std_impls::duration_ui, std_impls::duration_ui_readonly);
     add_no_many::<Instant>(type_registry, std_impls::instant_ui, std_impls::instant_ui_readonly);
+
+    // `bevy_utils::HashSet` is a reflect value, so the sets aren't registered by `bevy_reflect`
+    type_registry.add_inspector_egui_impl::<bevy_utils::HashSet<String>>(InspectorEguiImpl::for_hash_set::<String>());
+    type_registry.add_inspector_egui_impl::<bevy_utils::HashSet<u32>>(InspectorEguiImpl::for_hash_set::<u32>());
+    type_registry.add_inspector_egui_impl::<bevy_utils::HashSet<u64>>(InspectorEguiImpl::for_hash_set::<u64>());
+    type_registry.add_inspector_egui_impl::<bevy_utils::HashSet<usize>>(InspectorEguiImpl::for_hash_set::<usize>());
 }
 
 /// Register [`InspectorEguiImpl`]s for [`bevy_math`](bevy_math)/`glam` types
@@ -172,6 +298,7 @@ pub fn register_glam_impls(type_registry: &mut TypeRegistry) {
     add_no_many::<bevy_math::DMat4>(type_registry, glam_impls::dmat4_ui, glam_impls::dmat4_ui_readonly);
 
     add::<bevy_math::Quat>(type_registry, glam_impls::quat::quat_ui, glam_impls::quat::quat_ui_readonly, glam_impls::quat::quat_ui_many);
+    add::<bevy_math::DQuat>(type_registry, glam_impls::quat::dquat_ui, glam_impls::quat::dquat_ui_readonly, glam_impls::quat::dquat_ui_many);
 }
 
 /// Register [`InspectorEguiImpl`]s for `bevy` types
@@ -179,10 +306,37 @@ pub fn register_glam_impls(type_registry: &mut TypeRegistry) {
 pub fn register_bevy_impls(type_registry: &mut TypeRegistry) {
     add_no_many::<bevy_asset::HandleId>(type_registry, bevy_impls::handle_id_ui, bevy_impls::handle_id_ui_readonly);
     add_no_many::<bevy_asset::Handle<bevy_render::texture::Image>>(type_registry, image::image_handle_ui, image::image_handle_ui_readonly);
+    add_no_many::<bevy_render::texture::Image>(type_registry, image::image_ui, image::image_ui_readonly);
     add_no_many::<bevy_asset::Handle<bevy_render::mesh::Mesh>>(type_registry, bevy_impls::mesh_ui, bevy_impls::mesh_ui_readonly);
     add_no_many::<bevy_ecs::entity::Entity>(type_registry, bevy_impls::entity_ui, bevy_impls::entity_ui_readonly);
     add::<bevy_render::color::Color>(type_registry, bevy_impls::color_ui, bevy_impls::color_ui_readonly, bevy_impls::color_ui_many);
+    add::<bevy_math::Rect>(type_registry, bevy_impls::rect_ui, bevy_impls::rect_ui_readonly, bevy_impls::rect_ui_many);
+    add_no_many::<bevy_render::primitives::Aabb>(type_registry, bevy_impls::aabb_ui, bevy_impls::aabb_ui_readonly);
     add::<bevy_render::view::RenderLayers>(type_registry, bevy_impls::render_layers_ui, bevy_impls::render_layers_ui_readonly, bevy_impls::render_layers_ui_many);
+    add::<bevy_render::view::Visibility>(type_registry, bevy_impls::visibility_ui, bevy_impls::visibility_ui_readonly, bevy_impls::visibility_ui_many);
+    add::<bevy_render::view::ComputedVisibility>(type_registry, bevy_impls::computed_visibility_ui, bevy_impls::computed_visibility_ui_readonly, bevy_impls::computed_visibility_ui_many);
+    add::<bevy_transform::components::Transform>(type_registry, bevy_impls::transform_ui, bevy_impls::transform_ui_readonly, bevy_impls::transform_ui_many);
+
+    add_no_many::<bevy_hierarchy::Parent>(type_registry, bevy_impls::parent_ui, bevy_impls::parent_ui_readonly);
+    add_no_many::<bevy_hierarchy::Children>(type_registry, bevy_impls::children_ui, bevy_impls::children_ui_readonly);
+
+    add_no_many::<bevy_time::Time>(type_registry, bevy_impls::time_ui, bevy_impls::time_ui_readonly);
+
+    // materials get a dropdown to pick another asset
+    #[cfg(feature = "bevy_pbr")]
+    {
+        add::<bevy_asset::Handle<bevy_pbr::StandardMaterial>>(type_registry, bevy_impls::asset_picker_ui::<bevy_pbr::StandardMaterial>, bevy_impls::asset_picker_ui_readonly::<bevy_pbr::StandardMaterial>, bevy_impls::asset_picker_ui_many::<bevy_pbr::StandardMaterial>);
+    }
+    #[cfg(feature = "bevy_sprite")]
+    {
+        add::<bevy_asset::Handle<bevy_sprite::ColorMaterial>>(type_registry, bevy_impls::asset_picker_ui::<bevy_sprite::ColorMaterial>, bevy_impls::asset_picker_ui_readonly::<bevy_sprite::ColorMaterial>, bevy_impls::asset_picker_ui_many::<bevy_sprite::ColorMaterial>);
+    }
+
+    #[cfg(feature = "bevy_scene")]
+    {
+        add_no_many::<bevy_asset::Handle<bevy_scene::Scene>>(type_registry, scene::scene_handle_ui, scene::scene_handle_ui_readonly);
+        add_no_many::<bevy_asset::Handle<bevy_scene::DynamicScene>>(type_registry, scene::dynamic_scene_handle_ui, scene::dynamic_scene_handle_ui_readonly);
+    }
 }
 
 pub(crate) fn change_slider<T>(
@@ -227,9 +381,7 @@ where
 }
 
 pub(crate) fn iter_all_eq<T: Copy + PartialEq>(mut iter: impl Iterator<Item = T>) -> Option<T> {
-    let Some(first) = iter.next() else {
-        return None;
-    };
+    let first = iter.next()?;
 
     iter.all(|elem| elem == first).then_some(first)
 }
@@ -265,3 +417,51 @@ macro_rules! many_ui {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use super::{InspectorEguiImpl, RegisterInspectorEguiImpl};
+    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::utils::tests::{std_type_registry, TestUi};
+
+    fn custom_ui(
+        _: &mut dyn Any,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        ui.label("custom");
+        false
+    }
+    fn custom_ui_readonly(
+        _: &dyn Any,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        ui.label("custom");
+    }
+
+    #[test]
+    fn custom_impl_overrides_default() {
+        let mut type_registry = std_type_registry();
+        type_registry.add_inspector_egui_impl::<f32>(InspectorEguiImpl::new(
+            custom_ui,
+            custom_ui_readonly,
+            super::many_unimplemented::<f32>,
+        ));
+
+        let mut value = 1.0f32;
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut value, ui);
+        });
+        assert!(test_ui.text_position("custom").is_some());
+    }
+}
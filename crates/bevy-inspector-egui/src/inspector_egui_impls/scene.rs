@@ -0,0 +1,231 @@
+use std::any::{Any, TypeId};
+
+use bevy_asset::{Asset, AssetServer, Handle};
+use bevy_core::Name;
+use bevy_ecs::{bundle::Bundle, world::World};
+use bevy_scene::{DynamicScene, DynamicSceneBundle, Scene, SceneBundle};
+use pretty_type_name::pretty_type_name;
+
+use crate::{
+    bevy_inspector::{errors::no_world_in_context, short_circuit::load_state_ui},
+    reflect_inspector::InspectorUi,
+    restricted_world_view::RestrictedWorldView,
+};
+
+pub fn scene_handle_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    env: InspectorUi<'_, '_>,
+) -> bool {
+    let handle = value.downcast_ref::<Handle<Scene>>().unwrap();
+    scene_handle_ui_inner(handle, ui, env, true, |scene| SceneBundle {
+        scene,
+        ..Default::default()
+    });
+    false
+}
+pub fn scene_handle_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    env: InspectorUi<'_, '_>,
+) {
+    let handle = value.downcast_ref::<Handle<Scene>>().unwrap();
+    scene_handle_ui_inner(handle, ui, env, false, |scene| SceneBundle {
+        scene,
+        ..Default::default()
+    });
+}
+
+pub fn dynamic_scene_handle_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    env: InspectorUi<'_, '_>,
+) -> bool {
+    let handle = value.downcast_ref::<Handle<DynamicScene>>().unwrap();
+    scene_handle_ui_inner(handle, ui, env, true, |scene| DynamicSceneBundle {
+        scene,
+        ..Default::default()
+    });
+    false
+}
+pub fn dynamic_scene_handle_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    env: InspectorUi<'_, '_>,
+) {
+    let handle = value.downcast_ref::<Handle<DynamicScene>>().unwrap();
+    scene_handle_ui_inner(handle, ui, env, false, |scene| DynamicSceneBundle {
+        scene,
+        ..Default::default()
+    });
+}
+
+/// Shows where the scene was loaded from and how often it is instantiated,
+/// with a button to spawn another instance through the command queue of the [`Context`](crate::reflect_inspector::Context).
+fn scene_handle_ui_inner<S: Asset, B: Bundle>(
+    handle: &Handle<S>,
+    ui: &mut egui::Ui,
+    env: InspectorUi<'_, '_>,
+    spawn_button: bool,
+    bundle: fn(Handle<S>) -> B,
+) {
+    let Some(world) = &mut env.context.world else {
+        no_world_in_context(ui, &pretty_type_name::<Handle<S>>());
+        return;
+    };
+
+    if !load_state_ui(world, handle.id(), ui) {
+        ui.weak("not loaded through the asset server");
+    }
+
+    let instances = scene_instances(world, handle);
+    ui.label(match instances {
+        1 => "1 instance".to_string(),
+        n => format!("{n} instances"),
+    });
+
+    if !spawn_button {
+        return;
+    }
+    let name = instance_name(world, handle);
+    let queue = env.context.queue.as_deref_mut();
+    let response = ui.add_enabled(queue.is_some(), egui::Button::new("Spawn instance"));
+    let response = response.on_disabled_hover_text("No command queue in the context");
+    if let (true, Some(queue)) = (response.clicked(), queue) {
+        let bundle = bundle(handle.clone());
+        queue.push(move |world: &mut World| {
+            world.spawn((Name::new(name), bundle));
+        });
+    }
+}
+
+/// Name of newly spawned instances, based on the file name of the scene if it has one
+fn instance_name<S: Asset>(world: &mut RestrictedWorldView<'_>, handle: &Handle<S>) -> String {
+    let from_path = world
+        .allows_access_to_resource(TypeId::of::<AssetServer>())
+        .then(|| world.get_resource_mut::<AssetServer>().ok())
+        .flatten()
+        .and_then(|asset_server| {
+            let path = asset_server.get_handle_path(handle)?;
+            let stem = path.path().file_stem()?.to_string_lossy().into_owned();
+            Some(match path.label() {
+                Some(label) => format!("{stem}#{label}"),
+                None => stem,
+            })
+        });
+    from_path.unwrap_or_else(|| "Scene".to_string()) + " instance"
+}
+
+/// Number of entities with a `Handle<S>` component pointing to the same scene as `handle`,
+/// like the ones spawned with a [`SceneBundle`].
+///
+/// The handle being displayed is usually such a component which was split off from the world,
+/// so inaccessible components are compared by address instead of by value.
+fn scene_instances<S: Asset>(world: &RestrictedWorldView<'_>, handle: &Handle<S>) -> usize {
+    let cell = world.world();
+    let Some(component_id) = cell.components().get_id(TypeId::of::<Handle<S>>()) else {
+        return 0;
+    };
+
+    cell.archetypes()
+        .iter()
+        .filter(|archetype| archetype.contains(component_id))
+        .flat_map(|archetype| archetype.entities())
+        .filter(|archetype_entity| {
+            let entity = archetype_entity.entity();
+            let Some(entity_cell) = cell.get_entity(entity) else {
+                return false;
+            };
+            if world.allows_access_to_component((entity, TypeId::of::<Handle<S>>())) {
+                // SAFETY: the world allows access to the component and the reference is dropped right away
+                let other = unsafe { entity_cell.get::<Handle<S>>() };
+                other.is_some_and(|other| other.id() == handle.id())
+            } else {
+                // SAFETY: the pointer is only compared, never dereferenced
+                let ptr = unsafe { entity_cell.get_by_id(component_id) };
+                ptr.is_some_and(|ptr| std::ptr::eq(ptr.as_ptr().cast::<Handle<S>>(), handle))
+            }
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{Handle, HandleId};
+    use bevy_core::Name;
+    use bevy_ecs::{prelude::*, system::CommandQueue};
+    use bevy_reflect::TypeRegistry;
+    use bevy_scene::Scene;
+
+    use crate::inspector_egui_impls::{
+        many_unimplemented, InspectorEguiImpl, RegisterInspectorEguiImpl,
+    };
+    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::restricted_world_view::RestrictedWorldView;
+    use crate::utils::tests::TestUi;
+
+    #[test]
+    fn count_and_spawn_instances() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Handle<Scene>>();
+        type_registry.add_inspector_egui_impl::<Handle<Scene>>(InspectorEguiImpl::new(
+            super::scene_handle_ui,
+            super::scene_handle_ui_readonly,
+            many_unimplemented::<Handle<Scene>>,
+        ));
+
+        let scene = Handle::<Scene>::weak(HandleId::random::<Scene>());
+        let other_scene = Handle::<Scene>::weak(HandleId::random::<Scene>());
+        let mut world = World::new();
+        let displayed = world.spawn(scene.clone()).id();
+        world.spawn(scene.clone());
+        world.spawn(other_scene);
+
+        let mut queue = CommandQueue::default();
+        let mut add_contents = |ui: &mut egui::Ui| {
+            let world = RestrictedWorldView::new(&mut world);
+            let (mut handle, world) = world
+                .try_split_off_component_typed::<Handle<Scene>>(displayed)
+                .unwrap()
+                .unwrap();
+            let mut cx = Context {
+                world: Some(world),
+                queue: Some(&mut queue),
+            };
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut *handle, ui);
+        };
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("2 instances").is_some());
+
+        let button = test_ui.text_position("Spawn instance").unwrap();
+        test_ui.click(button, &mut add_contents);
+        queue.apply(&mut world);
+
+        let instances = world
+            .query::<(&Handle<Scene>, &Name)>()
+            .iter(&world)
+            .filter(|(handle, _)| **handle == scene)
+            .map(|(_, name)| name.as_str().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(instances, vec!["Scene instance"]);
+        assert_eq!(
+            world
+                .query::<&Handle<Scene>>()
+                .iter(&world)
+                .filter(|handle| **handle == scene)
+                .count(),
+            3
+        );
+    }
+}
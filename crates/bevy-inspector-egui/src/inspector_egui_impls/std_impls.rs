@@ -1,11 +1,21 @@
-use std::{borrow::Cow, ops::AddAssign, time::Instant};
+use std::{
+    borrow::Cow,
+    hash::Hash,
+    ops::{AddAssign, Range, RangeInclusive},
+    path::PathBuf,
+    time::Instant,
+};
 
 use bevy_reflect::Reflect;
+use bevy_utils::HashSet;
 use egui::{DragValue, RichText};
 
 use super::{change_slider, iter_all_eq, InspectorUi};
 use crate::{
-    inspector_options::std_options::{NumberDisplay, NumberOptions},
+    egui_utils,
+    inspector_options::std_options::{
+        BitflagsOptions, NumberDisplay, NumberOptions, StringOptions,
+    },
     many_ui,
 };
 use std::{any::Any, time::Duration};
@@ -17,6 +27,12 @@ pub fn number_ui<T: egui::emath::Numeric>(
     _: egui::Id,
     _: InspectorUi<'_, '_>,
 ) -> bool {
+    if let Some(flags) = options.downcast_ref::<BitflagsOptions>() {
+        if let Some(changed) = bitflags_ui(value, flags, ui) {
+            return changed;
+        }
+    }
+
     let value = value.downcast_mut::<T>().unwrap();
     let options = options
         .downcast_ref::<NumberOptions<T>>()
@@ -31,34 +47,94 @@ pub fn number_ui_readonly<T: egui::emath::Numeric>(
     _: egui::Id,
     _: InspectorUi<'_, '_>,
 ) {
+    if let Some(flags) = options.downcast_ref::<BitflagsOptions>() {
+        if let Some(mut bits) = unsigned_bits(value) {
+            ui.add_enabled_ui(false, |ui| flag_checkboxes(&mut bits, flags, ui));
+            return;
+        }
+    }
+
     let value = value.downcast_ref::<T>().unwrap();
     let options = options
         .downcast_ref::<NumberOptions<T>>()
         .cloned()
         .unwrap_or_default();
     let decimal_range = 0..=1usize;
+    let number = match options.display_hex && T::INTEGRAL {
+        true => format_hex(value.to_f64()),
+        false => egui::emath::format_with_decimals_in_range(value.to_f64(), decimal_range),
+    };
     ui.add(
         egui::Button::new(
-            RichText::new(format!(
-                "{}{}{}",
-                options.prefix,
-                egui::emath::format_with_decimals_in_range(value.to_f64(), decimal_range),
-                options.suffix
-            ))
-            .monospace(),
+            RichText::new(format!("{}{}{}", options.prefix, number, options.suffix)).monospace(),
         )
         .wrap(false)
         .sense(egui::Sense::hover()),
     );
 }
 
-fn display_number<T: egui::emath::Numeric>(
+/// Shows checkboxes for the flags if the value is an unsigned integer
+fn bitflags_ui(value: &mut dyn Any, flags: &BitflagsOptions, ui: &mut egui::Ui) -> Option<bool> {
+    let mut bits = unsigned_bits(value)?;
+    let changed = flag_checkboxes(&mut bits, flags, ui);
+    if changed {
+        set_unsigned_bits(value, bits);
+    }
+    Some(changed)
+}
+
+fn flag_checkboxes(bits: &mut u64, flags: &BitflagsOptions, ui: &mut egui::Ui) -> bool {
+    ui.vertical(|ui| {
+        let mut changed = false;
+        for &(name, mask) in &flags.flags {
+            let mut set = *bits & mask == mask;
+            if ui.checkbox(&mut set, name).changed() {
+                if set {
+                    *bits |= mask;
+                } else {
+                    *bits &= !mask;
+                }
+                changed = true;
+            }
+        }
+        changed
+    })
+    .inner
+}
+
+macro_rules! unsigned_bits {
+    ($($ty:ty),*) => {
+        fn unsigned_bits(value: &dyn Any) -> Option<u64> {
+            $(if let Some(value) = value.downcast_ref::<$ty>() {
+                return Some(*value as u64);
+            })*
+            None
+        }
+
+        fn set_unsigned_bits(value: &mut dyn Any, bits: u64) {
+            $(if let Some(value) = value.downcast_mut::<$ty>() {
+                *value = bits as $ty;
+            })*
+        }
+    };
+}
+unsigned_bits!(u8, u16, u32, u64, usize);
+
+pub(crate) fn display_number<T: egui::emath::Numeric>(
     value: &mut T,
     options: &NumberOptions<T>,
     ui: &mut egui::Ui,
     default_speed: f32,
 ) -> bool {
-    let mut changed = match options.display {
+    let speed = match options.speed {
+        0.0 => default_speed,
+        speed => speed,
+    };
+    let hex = options.display_hex && T::INTEGRAL;
+    // the `DragValue` has the next auto id, and is a text field while it has focus
+    let editing = matches!(options.display, NumberDisplay::Drag)
+        && ui.memory(|memory| memory.has_focus(ui.next_auto_id()));
+    let response = match options.display {
         NumberDisplay::Drag => {
             let mut widget = egui::DragValue::new(value);
             if !options.prefix.is_empty() {
@@ -68,46 +144,121 @@ fn display_number<T: egui::emath::Numeric>(
                 widget = widget.suffix(&options.suffix);
             }
             match (options.min, options.max) {
+                _ if editing => {}
                 (Some(min), Some(max)) => widget = widget.clamp_range(min.to_f64()..=max.to_f64()),
                 (Some(min), None) => widget = widget.clamp_range(min.to_f64()..=f64::MAX),
                 (None, Some(max)) => widget = widget.clamp_range(f64::MIN..=max.to_f64()),
                 (None, None) => {}
             }
-            if options.speed != 0.0 {
-                widget = widget.speed(options.speed);
-            } else {
-                widget = widget.speed(default_speed);
+            if hex {
+                widget = widget
+                    .custom_formatter(|n, _| format_hex(n))
+                    .custom_parser(parse_hex::<T>);
             }
-            ui.add(widget).changed()
+            ui.add(widget.speed(speed))
         }
         NumberDisplay::Slider => {
             let min = options.min.unwrap_or_else(|| T::from_f64(0.0));
             let max = options.max.unwrap_or_else(|| T::from_f64(1.0));
             let range = min..=max;
-            let widget = egui::Slider::new(value, range);
-            ui.add(widget).changed()
+            let mut widget = egui::Slider::new(value, range);
+            if hex {
+                widget = widget
+                    .custom_formatter(|n, _| format_hex(n))
+                    .custom_parser(parse_hex::<T>);
+            }
+            ui.add(widget)
         }
     };
 
-    if let Some(min) = options.min {
-        let as_f64 = value.to_f64();
-        let min = min.to_f64();
-        if as_f64 < min {
-            *value = T::from_f64(min);
-            changed = true;
-        }
+    let mut changed = response.changed();
+    if matches!(options.display, NumberDisplay::Drag) && response.hovered() {
+        changed |= scroll_number(value, ui, speed);
     }
-    if let Some(max) = options.max {
-        let as_f64 = value.to_f64();
-        let max = max.to_f64();
-        if as_f64 > max {
-            *value = T::from_f64(max);
-            changed = true;
-        }
+    // While a number is typed, it is only clamped once the edit is committed with enter or by clicking elsewhere,
+    // so that it doesn't jump around mid-edit.
+    if !editing || response.lost_focus() {
+        changed |= clamp_number(value, options);
     }
     changed
 }
 
+/// Formats an integer like `0x1F`, or `-0x1F` if it is negative
+fn format_hex(n: f64) -> String {
+    let sign = if n < 0.0 { "-" } else { "" };
+    format!("{sign}0x{:X}", n.abs() as u128)
+}
+
+/// Parses a hexadecimal integer with an optional `0x` prefix, clamped to the range of `T`
+fn parse_hex<T: egui::emath::Numeric>(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let digits = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+        .unwrap_or(digits);
+    let magnitude = u128::from_str_radix(digits, 16).ok()? as f64;
+    let n = if negative { -magnitude } else { magnitude };
+    Some(n.clamp(T::MIN.to_f64(), T::MAX.to_f64()))
+}
+
+/// Adjusts the hovered number with the mouse wheel, instead of scrolling the surrounding `ScrollArea`
+fn scroll_number<T: egui::emath::Numeric>(value: &mut T, ui: &egui::Ui, speed: f32) -> bool {
+    let (scroll_delta, modifiers) = ui.input(|input| (input.scroll_delta, input.modifiers));
+    // some platforms turn the vertical scrolling into horizontal scrolling while shift is held
+    let delta = match scroll_delta.y {
+        0.0 => scroll_delta.x,
+        y => y,
+    };
+    let adjustment = scroll_adjustment(delta, speed.into(), modifiers, T::INTEGRAL);
+    if adjustment == 0.0 {
+        return false;
+    }
+
+    ui.scroll_with_delta(-scroll_delta);
+    *value = T::from_f64(value.to_f64() + adjustment);
+    true
+}
+
+/// Change of a number when scrolling over it: one `speed` per scroll event in its direction,
+/// times 10 while holding shift and times 0.1 while holding ctrl. Integers change by at least one.
+fn scroll_adjustment(
+    scroll_delta: f32,
+    speed: f64,
+    modifiers: egui::Modifiers,
+    integral: bool,
+) -> f64 {
+    if scroll_delta == 0.0 {
+        return 0.0;
+    }
+    let factor = match (modifiers.shift, modifiers.ctrl) {
+        (true, _) => 10.0,
+        (false, true) => 0.1,
+        (false, false) => 1.0,
+    };
+    let step = speed * factor;
+    let step = match integral {
+        true => step.round().max(1.0),
+        false => step,
+    };
+    step * f64::from(scroll_delta.signum())
+}
+
+/// Clamps `value` to the `min` and `max` of the options, returning whether it was out of range
+fn clamp_number<T: egui::emath::Numeric>(value: &mut T, options: &NumberOptions<T>) -> bool {
+    let as_f64 = value.to_f64();
+    let clamped = match (options.min, options.max) {
+        (Some(min), _) if as_f64 < min.to_f64() => min,
+        (_, Some(max)) if as_f64 > max.to_f64() => max,
+        _ => return false,
+    };
+    *value = clamped;
+    true
+}
+
 pub fn number_ui_many<T>(
     ui: &mut egui::Ui,
     _: &dyn Any,
@@ -139,6 +290,268 @@ where
     })
 }
 
+pub fn range_ui<T: egui::emath::Numeric>(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) -> bool {
+    let range = value.downcast_mut::<Range<T>>().unwrap();
+    let options = range_options::<T>(options);
+    let (mut start, mut end) = (range.start, range.end);
+    let changed = range_bounds_ui(&mut start, &mut end, "..", &options, ui);
+    if changed {
+        *range = start..end;
+    }
+    changed
+}
+pub fn range_ui_readonly<T: egui::emath::Numeric>(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let range = value.downcast_ref::<Range<T>>().unwrap();
+    let options = range_options::<T>(options);
+    let (mut start, mut end) = (range.start, range.end);
+    ui.add_enabled_ui(false, |ui| {
+        range_bounds_ui(&mut start, &mut end, "..", &options, ui)
+    });
+}
+
+pub fn range_inclusive_ui<T: egui::emath::Numeric>(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) -> bool {
+    let range = value.downcast_mut::<RangeInclusive<T>>().unwrap();
+    let options = range_options::<T>(options);
+    let (mut start, mut end) = (*range.start(), *range.end());
+    let changed = range_bounds_ui(&mut start, &mut end, "..=", &options, ui);
+    if changed {
+        *range = start..=end;
+    }
+    changed
+}
+pub fn range_inclusive_ui_readonly<T: egui::emath::Numeric>(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let range = value.downcast_ref::<RangeInclusive<T>>().unwrap();
+    let options = range_options::<T>(options);
+    let (mut start, mut end) = (*range.start(), *range.end());
+    ui.add_enabled_ui(false, |ui| {
+        range_bounds_ui(&mut start, &mut end, "..=", &options, ui)
+    });
+}
+
+fn range_options<T: egui::emath::Numeric>(options: &dyn Any) -> NumberOptions<T> {
+    options
+        .downcast_ref::<NumberOptions<T>>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Drag fields for the bounds of a range, which are clamped to each other when the edit is committed.
+/// If the options have both a `min` and a `max`, they are preceded by a slider with a thumb for each bound.
+fn range_bounds_ui<T: egui::emath::Numeric>(
+    start: &mut T,
+    end: &mut T,
+    separator: &str,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+) -> bool {
+    ui.horizontal(|ui| {
+        let mut changed = false;
+        if let (Some(min), Some(max)) = (options.min, options.max) {
+            changed |= range_slider(start, end, min..=max, ui);
+        }
+
+        let start_options = NumberOptions {
+            max: Some(match options.max {
+                Some(max) if max < *end => max,
+                _ => *end,
+            }),
+            display: NumberDisplay::Drag,
+            ..options.clone()
+        };
+        changed |= display_number(start, &start_options, ui, 0.1);
+        ui.label(separator);
+        let end_options = NumberOptions {
+            min: Some(match options.min {
+                Some(min) if min > *start => min,
+                _ => *start,
+            }),
+            display: NumberDisplay::Drag,
+            ..options.clone()
+        };
+        changed |= display_number(end, &end_options, ui, 0.1);
+        changed
+    })
+    .inner
+}
+
+/// The bound of a range which is dragged in a [`range_slider`]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RangeThumb {
+    Start,
+    End,
+}
+
+/// A slider like [`egui::Slider`], but with one thumb for the `start` and one for the `end`, which can't pass each other
+fn range_slider<T: egui::emath::Numeric>(
+    start: &mut T,
+    end: &mut T,
+    bounds: RangeInclusive<T>,
+    ui: &mut egui::Ui,
+) -> bool {
+    let size = egui::vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click_and_drag());
+    let thumb_radius = rect.height() / 2.5;
+    let x_range = (rect.left() + thumb_radius) as f64..=(rect.right() - thumb_radius) as f64;
+    let value_range = bounds.start().to_f64()..=bounds.end().to_f64();
+    let to_x = |value: T| {
+        egui::emath::remap_clamp(value.to_f64(), value_range.clone(), x_range.clone()) as f32
+    };
+
+    let mut changed = false;
+    let thumb_id = response.id.with("thumb");
+    match response.interact_pointer_pos() {
+        Some(pointer) => {
+            let (start_x, end_x) = (to_x(*start), to_x(*end));
+            let drag_x = response.drag_delta().x;
+            let dragged_thumb = match response.drag_started() {
+                true => None,
+                false => ui.memory(|mem| mem.data.get_temp::<RangeThumb>(thumb_id)),
+            };
+            let thumb = dragged_thumb.or(
+                // overlapping thumbs are told apart by the direction they are dragged in
+                match (start_x == end_x, drag_x) {
+                    (true, x) if x < 0.0 => Some(RangeThumb::Start),
+                    (true, x) if x > 0.0 => Some(RangeThumb::End),
+                    (true, _) => None,
+                    (false, _) if (pointer.x - start_x).abs() < (pointer.x - end_x).abs() => {
+                        Some(RangeThumb::Start)
+                    }
+                    (false, _) => Some(RangeThumb::End),
+                },
+            );
+
+            if let Some(thumb) = thumb {
+                ui.memory_mut(|mem| mem.data.insert_temp(thumb_id, thumb));
+                let mut value = egui::emath::remap_clamp(
+                    pointer.x as f64,
+                    x_range.clone(),
+                    value_range.clone(),
+                );
+                if T::INTEGRAL {
+                    value = value.round();
+                }
+                let (bound, new) = match thumb {
+                    RangeThumb::Start => (&mut *start, T::from_f64(value.min(end.to_f64()))),
+                    RangeThumb::End => (&mut *end, T::from_f64(value.max(start.to_f64()))),
+                };
+                if *bound != new {
+                    *bound = new;
+                    changed = true;
+                }
+            }
+        }
+        None => ui.memory_mut(|mem| mem.data.remove::<RangeThumb>(thumb_id)),
+    }
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let widget_visuals = &ui.visuals().widgets;
+        let rail_radius = (rect.height() / 4.0).max(2.0);
+        let rail = egui::Rect::from_x_y_ranges(
+            rect.x_range(),
+            rect.center().y - rail_radius..=rect.center().y + rail_radius,
+        );
+        ui.painter().rect_filled(
+            rail,
+            widget_visuals.inactive.rounding,
+            widget_visuals.inactive.bg_fill,
+        );
+        let (start_x, end_x) = (to_x(*start), to_x(*end));
+        ui.painter().rect_filled(
+            egui::Rect::from_x_y_ranges(start_x..=end_x, rail.y_range()),
+            widget_visuals.inactive.rounding,
+            ui.visuals().selection.bg_fill,
+        );
+        for x in [start_x, end_x] {
+            ui.painter().circle(
+                egui::pos2(x, rect.center().y),
+                thumb_radius + visuals.expansion,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+        }
+    }
+
+    changed
+}
+
+macro_rules! nonzero_ui {
+    ($($name:ident $name_readonly:ident $nonzero:ty => $ty:ty),* $(,)?) => {$(
+        pub fn $name(
+            value: &mut dyn Any,
+            ui: &mut egui::Ui,
+            _: &dyn Any,
+            _: egui::Id,
+            _: InspectorUi<'_, '_>,
+        ) -> bool {
+            let value = value.downcast_mut::<$nonzero>().unwrap();
+            let previous = value.get();
+            let mut new = previous;
+            if !display_number(&mut new, &NumberOptions::default(), ui, 0.1) {
+                return false;
+            }
+            *value = <$nonzero>::new(snap_non_zero(new, previous)).unwrap();
+            value.get() != previous
+        }
+
+        pub fn $name_readonly(
+            value: &dyn Any,
+            ui: &mut egui::Ui,
+            _: &dyn Any,
+            id: egui::Id,
+            env: InspectorUi<'_, '_>,
+        ) {
+            let value = value.downcast_ref::<$nonzero>().unwrap().get();
+            number_ui_readonly::<$ty>(&value, ui, &(), id, env);
+        }
+    )*};
+}
+nonzero_ui!(
+    nonzero_u8_ui nonzero_u8_ui_readonly std::num::NonZeroU8 => u8,
+    nonzero_u16_ui nonzero_u16_ui_readonly std::num::NonZeroU16 => u16,
+    nonzero_u32_ui nonzero_u32_ui_readonly std::num::NonZeroU32 => u32,
+    nonzero_u64_ui nonzero_u64_ui_readonly std::num::NonZeroU64 => u64,
+    nonzero_usize_ui nonzero_usize_ui_readonly std::num::NonZeroUsize => usize,
+    nonzero_i8_ui nonzero_i8_ui_readonly std::num::NonZeroI8 => i8,
+    nonzero_i16_ui nonzero_i16_ui_readonly std::num::NonZeroI16 => i16,
+    nonzero_i32_ui nonzero_i32_ui_readonly std::num::NonZeroI32 => i32,
+    nonzero_i64_ui nonzero_i64_ui_readonly std::num::NonZeroI64 => i64,
+    nonzero_isize_ui nonzero_isize_ui_readonly std::num::NonZeroIsize => isize,
+);
+
+/// Replaces zero with the number of magnitude one which has the sign of the non-zero `previous` value
+fn snap_non_zero<T: egui::emath::Numeric>(value: T, previous: T) -> T {
+    if value.to_f64() == 0.0 {
+        T::from_f64(previous.to_f64().signum())
+    } else {
+        value
+    }
+}
+
 pub fn bool_ui(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
@@ -167,16 +580,16 @@ many_ui!(bool_ui_many bool_ui bool);
 pub fn string_ui(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
-    _: &dyn Any,
-    _: egui::Id,
+    options: &dyn Any,
+    id: egui::Id,
     _: InspectorUi<'_, '_>,
 ) -> bool {
     let value = value.downcast_mut::<String>().unwrap();
-    if value.contains('\n') {
-        ui.text_edit_multiline(value).changed()
-    } else {
-        ui.text_edit_singleline(value).changed()
-    }
+    let options = options
+        .downcast_ref::<StringOptions>()
+        .cloned()
+        .unwrap_or_default();
+    string_edit(value, ui, id, &options)
 }
 
 pub fn string_ui_readonly(
@@ -196,20 +609,91 @@ pub fn string_ui_readonly(
 
 many_ui!(string_ui_many string_ui String);
 
-pub fn cow_str_ui(
+/// `HashSet`s are opaque to `bevy_reflect`, so their elements are shown with a button to remove each of them.
+///
+/// Elements can't be edited in place since that would change their hash, instead new ones are entered in a separate
+/// row and added with the `+` button.
+pub fn hash_set_ui<T>(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
     _: &dyn Any,
-    _: egui::Id,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) -> bool
+where
+    T: Reflect + Default + Clone + Eq + Hash,
+{
+    let set = value.downcast_mut::<HashSet<T>>().unwrap();
+    let mut changed = false;
+
+    ui.vertical(|ui| {
+        let mut removed = None;
+        let visible = env.visible_collection_items(ui, id, set.len());
+        for (i, element) in set.iter().take(visible).enumerate() {
+            ui.horizontal(|ui| {
+                if egui_utils::label_button(ui, "✖", egui::Color32::RED) {
+                    removed = Some(element.clone());
+                }
+                env.ui_for_reflect_readonly_with_options(element, ui, id.with(i), &());
+            });
+        }
+        env.show_more_items(ui, id, visible, set.len());
+        if let Some(removed) = removed {
+            set.remove(&removed);
+            changed = true;
+        }
+
+        let new_element_id = id.with("new element");
+        let mut new_element = ui
+            .memory_mut(|mem| mem.data.get_temp::<T>(new_element_id))
+            .unwrap_or_default();
+        ui.horizontal(|ui| {
+            env.ui_for_reflect_with_options(&mut new_element, ui, new_element_id, &());
+            let add = ui.add_enabled(!set.contains(&new_element), egui::Button::new("+"));
+            if add.clicked() {
+                set.insert(std::mem::take(&mut new_element));
+                changed = true;
+            }
+        });
+        ui.memory_mut(|mem| mem.data.insert_temp(new_element_id, new_element));
+    });
+
+    changed
+}
+
+pub fn hash_set_ui_readonly<T>(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    mut env: InspectorUi<'_, '_>,
+) where
+    T: Reflect + Eq + Hash,
+{
+    let set = value.downcast_ref::<HashSet<T>>().unwrap();
+    ui.vertical(|ui| {
+        let visible = env.visible_collection_items(ui, id, set.len());
+        for (i, element) in set.iter().take(visible).enumerate() {
+            env.ui_for_reflect_readonly_with_options(element, ui, id.with(i), &());
+        }
+        env.show_more_items(ui, id, visible, set.len());
+    });
+}
+
+pub fn cow_str_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
     _: InspectorUi<'_, '_>,
 ) -> bool {
     let value = value.downcast_mut::<Cow<str>>().unwrap();
+    let options = options
+        .downcast_ref::<StringOptions>()
+        .cloned()
+        .unwrap_or_default();
     let mut clone = value.to_string();
-    let changed = if value.contains('\n') {
-        ui.text_edit_multiline(&mut clone).changed()
-    } else {
-        ui.text_edit_singleline(&mut clone).changed()
-    };
+    let changed = string_edit(&mut clone, ui, id, &options);
 
     if changed {
         *value = Cow::Owned(clone);
@@ -235,24 +719,259 @@ pub fn cow_str_ui_readonly(
 
 many_ui!(cow_str_ui_many cow_str_ui Cow<str>);
 
+pub fn path_buf_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    id: egui::Id,
+    _: InspectorUi<'_, '_>,
+) -> bool {
+    let value = value.downcast_mut::<PathBuf>().unwrap();
+    let Some(path) = value.to_str() else {
+        // editing the lossy conversion would replace the invalid parts of the path
+        ui.add_enabled_ui(false, |ui| {
+            ui.text_edit_singleline(&mut value.to_string_lossy().as_ref());
+        })
+        .response
+        .on_disabled_hover_text("Paths which are not valid UTF-8 can't be edited");
+        return false;
+    };
+
+    let mut path = path.to_owned();
+    let changed = debounced_text_edit(&mut path, ui, id, |ui, path| ui.text_edit_singleline(path));
+    if changed {
+        *value = PathBuf::from(path);
+    }
+    changed
+}
+
+pub fn path_buf_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _: &dyn Any,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
+) {
+    let value = value.downcast_ref::<PathBuf>().unwrap();
+    ui.text_edit_singleline(&mut value.to_string_lossy().as_ref());
+}
+
+many_ui!(path_buf_ui_many path_buf_ui PathBuf);
+
+/// Strings with at least this many characters show their length next to the text field
+const LONG_STRING_CHARS: usize = 100;
+
+fn string_edit(
+    value: &mut String,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    options: &StringOptions,
+) -> bool {
+    ui.horizontal(|ui| {
+        debounced_text_edit(value, ui, id, |ui, text| {
+            let response = if text.contains('\n') {
+                ui.text_edit_multiline(text)
+            } else {
+                ui.text_edit_singleline(text)
+            };
+            if response.changed() {
+                if let Some(max_len) = options.max_len {
+                    truncate_chars(text, max_len);
+                }
+            }
+
+            let len = text.chars().count();
+            match options.max_len {
+                Some(max_len) => {
+                    ui.weak(format!("{len}/{max_len}"));
+                }
+                None if len >= LONG_STRING_CHARS => {
+                    ui.weak(format!("{len} chars"));
+                }
+                None => {}
+            }
+
+            response
+        })
+    })
+    .inner
+}
+
+/// Seconds without typing after which the text of a focused field is written to the value
+const TEXT_COMMIT_DELAY: f64 = 0.5;
+
+/// Text which was typed, but not yet written to the value
+#[derive(Clone)]
+struct PendingText {
+    text: String,
+    last_edit: f64,
+}
+
+/// Shows the text field added by `add_field`, which edits a copy of the `value` kept in the egui memory.
+///
+/// The text is only written to the `value` once the field loses focus or after [`TEXT_COMMIT_DELAY`] seconds
+/// without typing, so that change detection doesn't trigger on every keystroke. Returns whether the value changed.
+fn debounced_text_edit(
+    value: &mut String,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    add_field: impl FnOnce(&mut egui::Ui, &mut String) -> egui::Response,
+) -> bool {
+    let pending_id = id.with("pending text");
+    let pending = ui.memory_mut(|mem| mem.data.get_temp::<PendingText>(pending_id));
+    let (mut text, mut last_edit) = match pending {
+        Some(pending) => (pending.text, Some(pending.last_edit)),
+        None => (value.clone(), None),
+    };
+
+    let response = add_field(ui, &mut text);
+    let now = ui.input(|input| input.time);
+    if response.changed() {
+        last_edit = Some(now);
+    }
+    let Some(last_edit) = last_edit else {
+        return false;
+    };
+
+    let idle = now - last_edit;
+    if response.has_focus() && idle < TEXT_COMMIT_DELAY {
+        ui.memory_mut(|mem| {
+            mem.data
+                .insert_temp(pending_id, PendingText { text, last_edit })
+        });
+        ui.ctx()
+            .request_repaint_after(Duration::from_secs_f64(TEXT_COMMIT_DELAY - idle));
+        return false;
+    }
+
+    ui.memory_mut(|mem| mem.data.remove::<PendingText>(pending_id));
+    let changed = *value != text;
+    *value = text;
+    changed
+}
+
+/// Truncates the string to at most `max_chars` characters, cutting at a char boundary instead of a byte offset
+fn truncate_chars(value: &mut String, max_chars: usize) {
+    if let Some((byte_index, _)) = value.char_indices().nth(max_chars) {
+        value.truncate(byte_index);
+    }
+}
+
+/// Unit in which a [`Duration`] is edited
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DurationUnit {
+    Nanos,
+    Micros,
+    Millis,
+    Secs,
+}
+
+impl DurationUnit {
+    const ALL: [DurationUnit; 4] = [
+        DurationUnit::Nanos,
+        DurationUnit::Micros,
+        DurationUnit::Millis,
+        DurationUnit::Secs,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            DurationUnit::Nanos => "ns",
+            DurationUnit::Micros => "µs",
+            DurationUnit::Millis => "ms",
+            DurationUnit::Secs => "s",
+        }
+    }
+
+    fn nanos(self) -> u128 {
+        match self {
+            DurationUnit::Nanos => 1,
+            DurationUnit::Micros => 1_000,
+            DurationUnit::Millis => 1_000_000,
+            DurationUnit::Secs => 1_000_000_000,
+        }
+    }
+
+    /// The largest unit in which the duration is at least `1`
+    fn for_duration(duration: Duration) -> DurationUnit {
+        DurationUnit::ALL
+            .into_iter()
+            .rev()
+            .find(|unit| duration.as_nanos() >= unit.nanos())
+            .unwrap_or(DurationUnit::Secs)
+    }
+
+    fn amount(self, duration: Duration) -> f64 {
+        let unit_nanos = self.nanos();
+        let nanos = duration.as_nanos();
+        // split the value so that large durations keep their sub-unit precision
+        (nanos / unit_nanos) as f64 + (nanos % unit_nanos) as f64 / unit_nanos as f64
+    }
+
+    /// Converts `amount` of this unit to a [`Duration`], clamping negative values to zero
+    fn duration(self, amount: f64) -> Duration {
+        if amount.is_nan() || amount <= 0.0 {
+            return Duration::ZERO;
+        }
+        let unit_nanos = self.nanos();
+        let whole = amount.trunc();
+        let fraction_nanos = ((amount - whole) * unit_nanos as f64).round() as u128;
+        let nanos = (whole as u128)
+            .saturating_mul(unit_nanos)
+            .saturating_add(fraction_nanos);
+
+        let secs = nanos / 1_000_000_000;
+        match u64::try_from(secs) {
+            Ok(secs) => Duration::new(secs, (nanos % 1_000_000_000) as u32),
+            Err(_) => Duration::MAX,
+        }
+    }
+}
+
+/// Formats the duration in its most fitting unit, like `1.25 s`
+pub(crate) fn humanize_duration(duration: Duration) -> String {
+    let unit = DurationUnit::for_duration(duration);
+    let amount = (unit.amount(duration) * 1000.0).round() / 1000.0;
+    format!("{amount} {}", unit.label())
+}
+
 pub fn duration_ui(
     value: &mut dyn Any,
     ui: &mut egui::Ui,
     _: &dyn Any,
     id: egui::Id,
-    mut env: InspectorUi<'_, '_>,
+    _: InspectorUi<'_, '_>,
 ) -> bool {
     let value = value.downcast_mut::<Duration>().unwrap();
-    let mut seconds = value.as_secs_f64();
-    let options = NumberOptions {
-        min: Some(0.0f64),
-        suffix: "s".to_string(),
-        ..Default::default()
-    };
 
-    let changed = env.ui_for_reflect_with_options(&mut seconds, ui, id, &options);
+    let unit_id = id.with("duration unit");
+    let mut unit = ui
+        .memory_mut(|mem| mem.data.get_temp::<DurationUnit>(unit_id))
+        .unwrap_or_else(|| DurationUnit::for_duration(*value));
+
+    let mut amount = unit.amount(*value);
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        changed = ui
+            .add(
+                DragValue::new(&mut amount)
+                    .clamp_range(0.0..=f64::MAX)
+                    .speed(0.1),
+            )
+            .changed();
+        egui::ComboBox::from_id_source(unit_id)
+            .selected_text(unit.label())
+            .width(0.0)
+            .show_ui(ui, |ui| {
+                for option in DurationUnit::ALL {
+                    ui.selectable_value(&mut unit, option, option.label());
+                }
+            });
+    });
+    ui.memory_mut(|mem| mem.data.insert_temp(unit_id, unit));
+
     if changed {
-        *value = Duration::from_secs_f64(seconds);
+        *value = unit.duration(amount);
     }
     changed
 }
@@ -260,17 +979,11 @@ pub fn duration_ui_readonly(
     value: &dyn Any,
     ui: &mut egui::Ui,
     _: &dyn Any,
-    id: egui::Id,
-    mut env: InspectorUi<'_, '_>,
+    _: egui::Id,
+    _: InspectorUi<'_, '_>,
 ) {
     let value = value.downcast_ref::<Duration>().unwrap();
-    let seconds = value.as_secs_f64();
-    let options = NumberOptions {
-        min: Some(0.0f64),
-        suffix: "s".to_string(),
-        ..Default::default()
-    };
-    env.ui_for_reflect_readonly_with_options(&seconds, ui, id, &options);
+    ui.label(humanize_duration(*value));
 }
 
 pub fn instant_ui(
@@ -298,3 +1011,436 @@ pub fn instant_ui_readonly(
         ui.label("seconds ago");
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::cell::RefCell;
+    use std::num::{NonZeroI32, NonZeroU32};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use bevy_reflect::Reflect;
+
+    use crate::inspector_options::std_options::{
+        BitflagsOptions, NumberDisplay, NumberOptions, StringOptions,
+    };
+    use crate::reflect_inspector::{Context, InspectorUi};
+    use crate::utils::tests::{std_type_registry, TestUi};
+
+    use super::{humanize_duration, DurationUnit};
+
+    #[test]
+    fn pasted_number_is_clamped_on_commit() {
+        let type_registry = std_type_registry();
+        let options = NumberOptions::between(0.0f32, 10.0);
+
+        let mut value = 5.0f32;
+        let mut test_ui = TestUi::new();
+        let mut ui =
+            |ui: &mut egui::Ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_options(&mut value, ui, egui::Id::new("number"), &options);
+            };
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Tab);
+        test_ui.run(&mut ui);
+        for _ in 0.."5.0".len() {
+            test_ui.key(egui::Key::Backspace);
+        }
+        test_ui.paste("123");
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut ui);
+
+        assert_eq!(value, 10.0);
+    }
+
+    #[test]
+    fn number_is_not_clamped_mid_edit() {
+        let type_registry = std_type_registry();
+        let options = NumberOptions::between(0.0f32, 10.0);
+
+        let mut value = 10.0f32;
+        let mut test_ui = TestUi::new();
+        let mut ui =
+            |ui: &mut egui::Ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_options(&mut value, ui, egui::Id::new("number"), &options);
+            };
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Tab);
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::ArrowUp);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("10.1").is_some());
+
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert_eq!(value, 10.0);
+    }
+
+    #[test]
+    fn slider_number_is_clamped() {
+        let type_registry = std_type_registry();
+        let options = NumberOptions {
+            display: NumberDisplay::Slider,
+            ..NumberOptions::between(0.0f32, 1.0)
+        };
+
+        let mut value = 5.0f32;
+        TestUi::new().run(&mut |ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut value,
+                ui,
+                egui::Id::new("number"),
+                &options,
+            );
+        });
+        assert_eq!(value, 1.0);
+    }
+
+    #[test]
+    fn hex_number_round_trip() {
+        let type_registry = std_type_registry();
+        let options = NumberOptions::<u32>::default().with_display_hex(true);
+
+        let mut value = 0xFFu32;
+        let mut test_ui = TestUi::new();
+        let mut ui =
+            |ui: &mut egui::Ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_options(&mut value, ui, egui::Id::new("number"), &options);
+            };
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        let number = test_ui.text_position("0xFF").unwrap();
+        test_ui.replace_text(number, "0xFF", "0x1a2B", &mut ui);
+        test_ui.run(&mut ui);
+        assert_eq!(value, 0x1A2B);
+        assert!(test_ui.text_position("0x1A2B").is_some());
+
+        assert_eq!(
+            super::parse_hex::<u32>("1FFFFFFFF"),
+            Some(f64::from(u32::MAX))
+        );
+        assert_eq!(super::parse_hex::<u32>("-0x10"), Some(0.0));
+        assert_eq!(super::parse_hex::<u32>("0xG"), None);
+    }
+
+    #[test]
+    fn scroll_adjustment() {
+        let shift = egui::Modifiers::SHIFT;
+        let ctrl = egui::Modifiers::CTRL;
+        let none = egui::Modifiers::NONE;
+
+        assert_eq!(super::scroll_adjustment(50.0, 0.5, none, false), 0.5);
+        assert_eq!(super::scroll_adjustment(-50.0, 0.5, none, false), -0.5);
+        assert_eq!(super::scroll_adjustment(1.0, 0.5, shift, false), 5.0);
+        assert!((super::scroll_adjustment(1.0, 0.5, ctrl, false) - 0.05).abs() < 1e-9);
+        assert_eq!(super::scroll_adjustment(0.0, 0.5, shift, false), 0.0);
+
+        assert_eq!(super::scroll_adjustment(1.0, 0.1, none, true), 1.0);
+        assert_eq!(super::scroll_adjustment(-1.0, 0.5, shift, true), -5.0);
+    }
+
+    #[test]
+    fn scroll_number_is_clamped() {
+        let type_registry = std_type_registry();
+        let options = NumberOptions::between(0.0f32, 1.0).with_speed(0.25);
+
+        let value = std::cell::Cell::new(0.5f32);
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut number = value.get();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut number,
+                ui,
+                egui::Id::new("number"),
+                &options,
+            );
+            value.set(number);
+        };
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position("0.5").unwrap();
+        test_ui.scroll(pos, egui::vec2(0.0, 50.0));
+        test_ui.run(&mut ui);
+        assert_eq!(value.get(), 0.75);
+
+        for _ in 0..3 {
+            test_ui.scroll(pos, egui::vec2(0.0, 50.0));
+            test_ui.run(&mut ui);
+        }
+        assert_eq!(value.get(), 1.0);
+    }
+
+    /// Replaces the text `old` of the number field by `text` and presses `Enter`
+    fn type_number<T: Reflect>(value: &mut T, old: &str, text: &str) {
+        let type_registry = std_type_registry();
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect(value, ui);
+        };
+        test_ui.run(&mut ui);
+        let number = test_ui.text_position(old).unwrap();
+        test_ui.replace_text(number, old, text, &mut ui);
+    }
+
+    #[test]
+    fn non_zero_numbers_snap_to_one() {
+        let mut unsigned = NonZeroU32::new(5).unwrap();
+        type_number(&mut unsigned, "5", "0");
+        assert_eq!(unsigned.get(), 1);
+
+        let mut signed = NonZeroI32::new(-5).unwrap();
+        type_number(&mut signed, "-5", "0");
+        assert_eq!(signed.get(), -1);
+
+        type_number(&mut signed, "-1", "7");
+        assert_eq!(signed.get(), 7);
+    }
+
+    #[test]
+    fn range_slider_thumbs_do_not_cross() {
+        let type_registry = std_type_registry();
+        let options = NumberOptions::between(0.0f32, 10.0);
+
+        let value = RefCell::new(2.0f32..5.0);
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut *value.borrow_mut(),
+                ui,
+                egui::Id::new("range"),
+                &options,
+            );
+        };
+        test_ui.run(&mut ui);
+        // the slider starts at the left of the panel, and the end thumb is at half its width
+        let y = test_ui.text_position("2.0").unwrap().y;
+        let slider_left = test_ui.ctx.style().spacing.window_margin.left;
+        let slider_width = test_ui.ctx.style().spacing.slider_width;
+        let end_thumb = egui::pos2(slider_left + slider_width * 0.5, y);
+        test_ui.drag(end_thumb, egui::pos2(0.0, y), &mut ui);
+        assert_eq!(*value.borrow(), 2.0..2.0);
+
+        // the overlapping thumbs are separated by dragging to the right, which moves the end
+        let thumbs = egui::pos2(slider_left + slider_width * 0.2, y);
+        let slider_right = egui::pos2(slider_left + slider_width, y);
+        test_ui.drag(thumbs, slider_right, &mut ui);
+        assert_eq!(*value.borrow(), 2.0..10.0);
+
+        test_ui.drag(thumbs, egui::pos2(800.0, y), &mut ui);
+        assert_eq!(*value.borrow(), 10.0..10.0);
+    }
+
+    #[test]
+    fn range_inclusive_start_is_clamped_to_end() {
+        let mut range = 3u32..=7;
+        type_number(&mut range, "3", "9");
+        assert_eq!(range, 7..=7);
+
+        let mut range = 3u32..=7;
+        type_number(&mut range, "3", "5");
+        assert_eq!(range, 5..=7);
+    }
+
+    #[test]
+    fn string_max_len() {
+        let type_registry = std_type_registry();
+        let options = StringOptions { max_len: Some(3) };
+
+        let mut value = "ab".to_string();
+        let mut test_ui = TestUi::new();
+        let mut ui =
+            |ui: &mut egui::Ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_options(&mut value, ui, egui::Id::new("string"), &options);
+            };
+        test_ui.run(&mut ui);
+        let text_field = test_ui.text_position("ab").unwrap();
+        test_ui.click(text_field, &mut ui);
+        test_ui.key(egui::Key::End);
+        test_ui.type_text("çé");
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut ui);
+
+        assert_eq!(value, "abç");
+    }
+
+    #[test]
+    fn text_is_written_once_typing_stops() {
+        let type_registry = std_type_registry();
+        let value = RefCell::new("ab".to_string());
+        let writes = std::cell::Cell::new(0);
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let changed = InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut *value.borrow_mut(), ui);
+            writes.set(writes.get() + usize::from(changed));
+        };
+        test_ui.run(&mut ui);
+        let text_field = test_ui.text_position("ab").unwrap();
+        test_ui.click(text_field, &mut ui);
+        test_ui.key(egui::Key::End);
+        for c in ["c", "d", "e"] {
+            test_ui.type_text(c);
+            test_ui.run(&mut ui);
+        }
+        assert_eq!(*value.borrow(), "ab");
+        assert_eq!(writes.get(), 0);
+
+        // a frame is a 60th of a second in the test
+        for _ in 0..=(super::TEXT_COMMIT_DELAY * 60.0) as usize {
+            test_ui.run(&mut ui);
+        }
+        assert_eq!(*value.borrow(), "abcde");
+        assert_eq!(writes.get(), 1);
+
+        // losing focus writes right away
+        test_ui.type_text("f");
+        test_ui.run(&mut ui);
+        assert_eq!(*value.borrow(), "abcde");
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut ui);
+        assert_eq!(*value.borrow(), "abcdef");
+        assert_eq!(writes.get(), 2);
+    }
+
+    /// Appends `text` to the text field showing `current`, which should be the displayed `value`
+    fn append_text<T: Reflect>(value: &mut T, current: &str, text: &str) {
+        let type_registry = std_type_registry();
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect(value, ui);
+        };
+        test_ui.run(&mut ui);
+        let text_field = test_ui.text_position(current).unwrap();
+        test_ui.click(text_field, &mut ui);
+        test_ui.key(egui::Key::End);
+        test_ui.type_text(text);
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut ui);
+    }
+
+    #[test]
+    fn edit_string_types() {
+        let mut cow: Cow<'static, str> = Cow::Borrowed("borrowed");
+        append_text(&mut cow, "borrowed", " and owned");
+        assert!(matches!(&cow, Cow::Owned(text) if text == "borrowed and owned"));
+
+        let mut path = PathBuf::from("assets/textures");
+        append_text(&mut path, "assets/textures", "/grass.png");
+        assert_eq!(path, PathBuf::from("assets/textures/grass.png"));
+    }
+
+    #[test]
+    fn hash_set_add_and_remove() {
+        let type_registry = std_type_registry();
+        let mut set = bevy_utils::HashSet::from_iter(["shield".to_string()]);
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect(&mut set, ui);
+        };
+        test_ui.run(&mut ui);
+        let remove = test_ui.text_position("✖").unwrap();
+        test_ui.click(remove, &mut ui);
+
+        // with the only element removed, the field for the new element is the first one to be focused
+        test_ui.key(egui::Key::Tab);
+        test_ui.run(&mut ui);
+        test_ui.type_text("sword");
+        test_ui.run(&mut ui);
+        let add = test_ui.text_position("+").unwrap();
+        test_ui.click(add, &mut ui);
+
+        assert_eq!(set, bevy_utils::HashSet::from_iter(["sword".to_string()]));
+    }
+
+    #[test]
+    fn bitflags_checkboxes() {
+        let type_registry = std_type_registry();
+        let options = BitflagsOptions::new([("SHADOWS", 1 << 0), ("REFLECTIONS", 1 << 2)]);
+
+        let value = RefCell::new(0b1u32);
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx).ui_for_reflect_with_options(
+                &mut *value.borrow_mut(),
+                ui,
+                egui::Id::new("flags"),
+                &options,
+            );
+        };
+        test_ui.run(&mut ui);
+        let checkbox = test_ui.text_position("REFLECTIONS").unwrap();
+        test_ui.click(checkbox, &mut ui);
+        assert_eq!(*value.borrow(), 0b101);
+
+        let checkbox = test_ui.text_position("SHADOWS").unwrap();
+        test_ui.click(checkbox, &mut ui);
+        assert_eq!(*value.borrow(), 0b100);
+    }
+
+    #[test]
+    fn truncate_at_char_boundary() {
+        let mut value = "héllo wörld".to_string();
+        super::truncate_chars(&mut value, 2);
+        assert_eq!(value, "hé");
+
+        let mut value = "日本語".to_string();
+        super::truncate_chars(&mut value, 5);
+        assert_eq!(value, "日本語");
+        super::truncate_chars(&mut value, 1);
+        assert_eq!(value, "日");
+    }
+
+    #[test]
+    fn duration_units_round_trip() {
+        let durations = [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::from_nanos(999_999),
+            Duration::from_millis(1250),
+            Duration::new(123_456, 789_012_345),
+        ];
+        for duration in durations {
+            for unit in DurationUnit::ALL {
+                assert_eq!(unit.duration(unit.amount(duration)), duration, "{unit:?}");
+            }
+        }
+
+        assert_eq!(DurationUnit::Millis.amount(Duration::from_secs(2)), 2000.0);
+        assert_eq!(
+            DurationUnit::Micros.duration(1.5),
+            Duration::from_nanos(1500)
+        );
+        assert_eq!(DurationUnit::Secs.duration(-3.0), Duration::ZERO);
+        assert_eq!(DurationUnit::Secs.duration(f64::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn humanized_duration() {
+        assert_eq!(humanize_duration(Duration::from_millis(1250)), "1.25 s");
+        assert_eq!(humanize_duration(Duration::from_micros(1500)), "1.5 ms");
+        assert_eq!(humanize_duration(Duration::from_nanos(42)), "42 ns");
+        assert_eq!(humanize_duration(Duration::ZERO), "0 s");
+    }
+}
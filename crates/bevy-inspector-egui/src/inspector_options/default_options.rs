@@ -1,7 +1,7 @@
 use bevy_reflect::{TypeData, TypeInfo, TypeRegistry};
 
 use crate::{
-    inspector_options::{std_options::NumberOptions, Target},
+    inspector_options::{std_options::NumberOptions, ContainerOptions, Target},
     prelude::ReflectInspectorOptions,
     InspectorOptions,
 };
@@ -62,6 +62,22 @@ fn insert_options_enum<T: 'static>(
     }
 }
 
+#[allow(dead_code)]
+fn insert_options_container<T: 'static>(
+    type_registry: &mut TypeRegistry,
+    container: impl FnOnce(&mut ContainerOptions),
+) {
+    let Some(registration) = type_registry.get_mut(std::any::TypeId::of::<T>()) else {
+        bevy_log::warn!("Attempting to set default inspector options for {}, but it wasn't registered in the type registry.", std::any::type_name::<T>());
+        return;
+    };
+    if registration.data::<ReflectInspectorOptions>().is_none() {
+        let mut options = InspectorOptions::new();
+        container(options.container_mut());
+        registration.insert(ReflectInspectorOptions(options));
+    }
+}
+
 pub fn register_default_options(type_registry: &mut TypeRegistry) {
     insert_options_enum::<bevy_render::color::Color>(
         type_registry,
@@ -143,6 +159,11 @@ pub fn register_default_options(type_registry: &mut TypeRegistry) {
         );
     }
 
+    #[cfg(feature = "bevy_ui")]
+    insert_options_container::<bevy_ui::Val>(type_registry, |container| {
+        container.number_with_unit = true;
+    });
+
     insert_options_enum::<bevy_core_pipeline::core_3d::Camera3dDepthLoadOp>(
         type_registry,
         &[("Clear", "0", &NumberOptions::<f32>::normalized())],
@@ -49,6 +49,66 @@ pub use bevy_inspector_egui_derive::InspectorOptions;
 #[derive(Default)]
 pub struct InspectorOptions {
     options: HashMap<Target, Box<dyn TypeData>>,
+    fields: HashMap<Target, FieldOptions>,
+    container: ContainerOptions,
+}
+
+/// Options which can be set on a field of any type, independent of the type-specific options.
+///
+/// ```rust
+/// # use bevy_inspector_egui::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Light {
+///     #[inspector(min = 0.0, help = "Luminous power in lumens", reset)]
+///     intensity: f32,
+///     #[inspector(read_only)]
+///     illuminance: f32,
+/// }
+/// ```
+#[derive(Default, Clone, Debug)]
+#[non_exhaustive]
+pub struct FieldOptions {
+    /// Shown in a tooltip when hovering the field label.
+    ///
+    /// With the `documentation` feature, the doc comment of the field is used if this is not set.
+    pub help: Option<&'static str>,
+    /// Show a button next to the field label which resets it to its default value.
+    ///
+    /// The default is taken from the `ReflectDefault` of the struct if it is registered, otherwise from the field type.
+    pub reset: bool,
+    /// Always show the field like in a readonly inspector, even if the rest of the value is editable.
+    ///
+    /// Useful for derived or cached values which are overwritten anyway.
+    pub read_only: bool,
+}
+
+/// Options which apply to a struct or enum as a whole instead of one of its fields.
+///
+/// These are set by attributes on the type itself:
+/// ```rust
+/// # use bevy_inspector_egui::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// #[inspector(grid)]
+/// struct Stats {
+///     health: f32,
+///     mana: f32,
+/// }
+/// ```
+#[derive(Default, Clone, Debug)]
+#[non_exhaustive]
+pub struct ContainerOptions {
+    /// Lay out primitive fields in a multi-column grid of label/value pairs.
+    /// Nested non-primitive fields are shown in collapsing headers below the grid.
+    pub grid: bool,
+    /// Show an enum whose variants hold a single `f32` or nothing, like `Val` of `bevy_ui`, as the number
+    /// followed by a dropdown for the unit. Switching between units keeps the number.
+    ///
+    /// Enums of other shapes are shown as usual. This is set for `Val` by default with the `bevy_ui` feature.
+    pub number_with_unit: bool,
 }
 
 impl std::fmt::Debug for InspectorOptions {
@@ -57,6 +117,10 @@ impl std::fmt::Debug for InspectorOptions {
         for entry in self.options.keys() {
             options.field(&format!("{entry:?}"), &"..");
         }
+        for (target, field) in &self.fields {
+            options.field(&format!("{target:?}"), field);
+        }
+        options.field("container", &self.container);
         options.finish()
     }
 }
@@ -69,6 +133,8 @@ impl Clone for InspectorOptions {
                 .iter()
                 .map(|(target, data)| (*target, TypeData::clone_type_data(&**data)))
                 .collect(),
+            fields: self.fields.clone(),
+            container: self.container.clone(),
         }
     }
 }
@@ -90,6 +156,20 @@ impl InspectorOptions {
     pub fn iter(&self) -> impl Iterator<Item = (Target, &dyn TypeData)> + '_ {
         self.options.iter().map(|(target, data)| (*target, &**data))
     }
+
+    pub fn field(&self, target: Target) -> Option<&FieldOptions> {
+        self.fields.get(&target)
+    }
+    pub fn field_mut(&mut self, target: Target) -> &mut FieldOptions {
+        self.fields.entry(target).or_default()
+    }
+
+    pub fn container(&self) -> &ContainerOptions {
+        &self.container
+    }
+    pub fn container_mut(&mut self) -> &mut ContainerOptions {
+        &mut self.container
+    }
 }
 
 /// Wrapper of [`struct@InspectorOptions`] to be stored in the [`TypeRegistry`](bevy_reflect::TypeRegistry)
@@ -1,5 +1,5 @@
 use bevy_ecs::entity::Entity;
-use std::collections::VecDeque;
+use std::{borrow::Cow, collections::VecDeque};
 
 use crate::InspectorOptions;
 
@@ -27,6 +27,10 @@ pub struct NumberOptions<T> {
     pub prefix: String,
     pub suffix: String,
     pub display: NumberDisplay,
+    /// Show the components of vectors below each other with their names, instead of on a single line
+    pub expanded: bool,
+    /// Show and parse integers in hexadecimal like `0x1F`. Ignored for floats
+    pub display_hex: bool,
 }
 
 impl<T> Default for NumberOptions<T> {
@@ -38,6 +42,8 @@ impl<T> Default for NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            expanded: false,
+            display_hex: false,
         }
     }
 }
@@ -59,6 +65,8 @@ impl<T> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            expanded: false,
+            display_hex: false,
         }
     }
     pub fn at_least(min: T) -> NumberOptions<T> {
@@ -69,6 +77,8 @@ impl<T> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            expanded: false,
+            display_hex: false,
         }
     }
 
@@ -76,6 +86,13 @@ impl<T> NumberOptions<T> {
         NumberOptions { speed, ..self }
     }
 
+    pub fn with_display_hex(self, display_hex: bool) -> NumberOptions<T> {
+        NumberOptions {
+            display_hex,
+            ..self
+        }
+    }
+
     pub fn map<U>(&self, f: impl Fn(&T) -> U) -> NumberOptions<U> {
         NumberOptions {
             #[allow(clippy::redundant_closure)] // false positive
@@ -85,6 +102,8 @@ impl<T> NumberOptions<T> {
             prefix: self.prefix.clone(),
             suffix: self.suffix.clone(),
             display: NumberDisplay::default(),
+            expanded: false,
+            display_hex: self.display_hex,
         }
     }
 }
@@ -97,6 +116,8 @@ impl<T: egui::emath::Numeric> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            expanded: false,
+            display_hex: false,
         }
     }
 
@@ -108,6 +129,8 @@ impl<T: egui::emath::Numeric> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            expanded: false,
+            display_hex: false,
         }
     }
 }
@@ -126,6 +149,197 @@ impl_options!(u32 => NumberOptions<u32>);
 impl_options!(u64 => NumberOptions<u64>);
 impl_options!(u128 => NumberOptions<u128>);
 impl_options!(usize => NumberOptions<usize>);
+impl_options!(std::ops::Range<f32> => NumberOptions<f32>);
+impl_options!(std::ops::RangeInclusive<f32> => NumberOptions<f32>);
+impl_options!(std::ops::Range<f64> => NumberOptions<f64>);
+impl_options!(std::ops::RangeInclusive<f64> => NumberOptions<f64>);
+impl_options!(std::ops::Range<i32> => NumberOptions<i32>);
+impl_options!(std::ops::RangeInclusive<i32> => NumberOptions<i32>);
+impl_options!(std::ops::Range<i64> => NumberOptions<i64>);
+impl_options!(std::ops::RangeInclusive<i64> => NumberOptions<i64>);
+impl_options!(std::ops::Range<u32> => NumberOptions<u32>);
+impl_options!(std::ops::RangeInclusive<u32> => NumberOptions<u32>);
+impl_options!(std::ops::Range<u64> => NumberOptions<u64>);
+impl_options!(std::ops::RangeInclusive<u64> => NumberOptions<u64>);
+impl_options!(std::ops::Range<usize> => NumberOptions<usize>);
+impl_options!(std::ops::RangeInclusive<usize> => NumberOptions<usize>);
+impl_options!(bevy_math::Vec2 => Vec2Options<bevy_math::Vec2>);
+impl_options!(bevy_math::Vec3 => Vec3Options<bevy_math::Vec3>);
+impl_options!(bevy_math::Vec3A => Vec3Options<bevy_math::Vec3A>);
+impl_options!(bevy_math::Vec4 => Vec4Options<bevy_math::Vec4>);
+impl_options!(bevy_math::UVec2 => Vec2Options<bevy_math::UVec2>);
+impl_options!(bevy_math::UVec3 => Vec3Options<bevy_math::UVec3>);
+impl_options!(bevy_math::UVec4 => Vec4Options<bevy_math::UVec4>);
+impl_options!(bevy_math::IVec2 => Vec2Options<bevy_math::IVec2>);
+impl_options!(bevy_math::IVec3 => Vec3Options<bevy_math::IVec3>);
+impl_options!(bevy_math::IVec4 => Vec4Options<bevy_math::IVec4>);
+impl_options!(bevy_math::DVec2 => Vec2Options<bevy_math::DVec2>);
+impl_options!(bevy_math::DVec3 => Vec3Options<bevy_math::DVec3>);
+impl_options!(bevy_math::DVec4 => Vec4Options<bevy_math::DVec4>);
+
+/// Vector types like [`Vec3`](bevy_math::Vec3) whose components can be accessed by index
+pub trait VectorComponents: Copy {
+    type Component: Copy;
+
+    fn component(self, index: usize) -> Self::Component;
+}
+
+macro_rules! impl_vector_components {
+    ($($ty:ident: $component:ty),*) => {
+        $(impl VectorComponents for bevy_math::$ty {
+            type Component = $component;
+
+            fn component(self, index: usize) -> $component {
+                self[index]
+            }
+        })*
+    };
+}
+impl_vector_components!(Vec2: f32, Vec3: f32, Vec3A: f32, Vec4: f32);
+impl_vector_components!(UVec2: u32, UVec3: u32, UVec4: u32);
+impl_vector_components!(IVec2: i32, IVec3: i32, IVec4: i32);
+impl_vector_components!(DVec2: f64, DVec3: f64, DVec4: f64);
+
+macro_rules! impl_bvec_components {
+    ($($ty:ident: $($component:ident)*),*) => {
+        $(impl VectorComponents for bevy_math::$ty {
+            type Component = bool;
+
+            fn component(self, index: usize) -> bool {
+                [$(self.$component),*][index]
+            }
+        })*
+    };
+}
+impl_bvec_components!(BVec2: x y, BVec3: x y z, BVec4: x y z w);
+
+macro_rules! vec_options {
+    ($(#[$attr:meta])* $name:ident: $count:literal $($index:literal $min:ident $max:ident $speed:ident)*) => {
+        $(#[$attr])*
+        #[derive(Clone)]
+        #[non_exhaustive]
+        pub struct $name<V: VectorComponents> {
+            /// Minimum of all components, unless overridden for a single one
+            pub min: Option<V>,
+            /// Maximum of all components, unless overridden for a single one
+            pub max: Option<V>,
+            pub speed: f32,
+            pub prefix: String,
+            pub suffix: String,
+            pub display: NumberDisplay,
+            /// Show the components below each other with their names, instead of on a single line
+            pub expanded: bool,
+            $(
+                pub $min: Option<V::Component>,
+                pub $max: Option<V::Component>,
+                pub $speed: Option<f32>,
+            )*
+        }
+
+        impl<V: VectorComponents> Default for $name<V> {
+            fn default() -> Self {
+                NumberOptions::default().into()
+            }
+        }
+
+        impl<V: VectorComponents> From<NumberOptions<V>> for $name<V> {
+            fn from(options: NumberOptions<V>) -> Self {
+                Self {
+                    min: options.min,
+                    max: options.max,
+                    speed: options.speed,
+                    prefix: options.prefix,
+                    suffix: options.suffix,
+                    display: options.display,
+                    expanded: options.expanded,
+                    $($min: None, $max: None, $speed: None,)*
+                }
+            }
+        }
+
+        impl<V: VectorComponents> $name<V> {
+            /// The options of each component, where the per-component settings take precedence over the ones for the whole vector
+            pub fn components(&self) -> [NumberOptions<V::Component>; $count] {
+                [$(NumberOptions {
+                    min: self.$min.or(self.min.map(|min| min.component($index))),
+                    max: self.$max.or(self.max.map(|max| max.component($index))),
+                    speed: self.$speed.unwrap_or(self.speed),
+                    prefix: self.prefix.clone(),
+                    suffix: self.suffix.clone(),
+                    display: NumberDisplay::default(),
+                    expanded: false,
+                    display_hex: false,
+                }),*]
+            }
+        }
+    };
+}
+
+vec_options!(
+    /// Options for vectors with two components like [`Vec2`](bevy_math::Vec2).
+    ///
+    /// The `min`, `max` and `speed` apply to every component, but can be overridden for a single one using e.g. `min_y`.
+    Vec2Options: 2 0 min_x max_x speed_x 1 min_y max_y speed_y
+);
+vec_options!(
+    /// Options for vectors with three components like [`Vec3`](bevy_math::Vec3).
+    ///
+    /// The `min`, `max` and `speed` apply to every component, but can be overridden for a single one:
+    /// ```rust
+    /// # use bevy_inspector_egui::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// # use bevy_math::Vec3;
+    /// #[derive(Reflect, Default, InspectorOptions)]
+    /// #[reflect(InspectorOptions)]
+    /// struct Placement {
+    ///     // keep the object above the ground
+    ///     #[inspector(min_y = 0.0, speed = 0.1)]
+    ///     position: Vec3,
+    /// }
+    /// ```
+    Vec3Options: 3 0 min_x max_x speed_x 1 min_y max_y speed_y 2 min_z max_z speed_z
+);
+vec_options!(
+    /// Options for vectors with four components like [`Vec4`](bevy_math::Vec4).
+    ///
+    /// The `min`, `max` and `speed` apply to every component, but can be overridden for a single one using e.g. `min_w`.
+    Vec4Options: 4 0 min_x max_x speed_x 1 min_y max_y speed_y 2 min_z max_z speed_z 3 min_w max_w speed_w
+);
+
+/// Displays an unsigned integer as one checkbox per named flag instead of a number.
+///
+/// ```rust
+/// # use bevy_inspector_egui::inspector_options::{InspectorOptions, Target, std_options::BitflagsOptions};
+/// let mut options = InspectorOptions::new();
+/// options.insert(
+///     Target::Field(0),
+///     BitflagsOptions::new([("SHADOWS", 1 << 0), ("REFLECTIONS", 1 << 1)]),
+/// );
+/// ```
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct BitflagsOptions {
+    /// Name and bit mask of each flag
+    pub flags: Vec<(&'static str, u64)>,
+}
+
+impl BitflagsOptions {
+    pub fn new(flags: impl IntoIterator<Item = (&'static str, u64)>) -> Self {
+        BitflagsOptions {
+            flags: flags.into_iter().collect(),
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct StringOptions {
+    /// Maximum number of characters, longer input is truncated when editing
+    pub max_len: Option<usize>,
+}
+
+impl_options!(String => StringOptions);
+impl_options!(Cow<'static, str> => StringOptions);
 
 #[derive(Default, Clone)]
 #[non_exhaustive]
@@ -133,7 +347,7 @@ pub struct QuatOptions {
     pub display: QuatDisplay,
 }
 
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
 pub enum QuatDisplay {
     Raw,
     #[default]
@@ -143,6 +357,7 @@ pub enum QuatDisplay {
 }
 
 impl_options!(bevy_math::Quat => QuatOptions);
+impl_options!(bevy_math::DQuat => QuatOptions);
 
 #[derive(Clone)]
 #[non_exhaustive]
@@ -166,6 +381,8 @@ pub enum EntityDisplay {
     Id,
     #[default]
     Components,
+    /// Dropdown of the entities with a [`Name`](bevy_core::Name) to pick the one the field refers to
+    NamePicker,
 }
 
 impl_options!(Entity => EntityOptions);
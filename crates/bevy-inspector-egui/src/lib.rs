@@ -69,7 +69,7 @@
 //!
 //! <hr>
 //!
-//! There is also the [`StateInspectorPlugin`](quick::StateInspectorPlugin) and the [`AssetInspectorPlugin`](quick::AssetInspectorPlugin).
+//! There is also the [`StateInspectorPlugin`](quick::StateInspectorPlugin), the [`AssetInspectorPlugin`](quick::AssetInspectorPlugin), the [`EventInspectorPlugin`](quick::EventInspectorPlugin) and the [`NonSendResourceInspectorPlugin`](quick::NonSendResourceInspectorPlugin).
 //!
 //! # Use case 2: Manual UI
 //! The [`quick`] plugins don't allow customization of the egui window or its content, but you can easily build your own UI:
@@ -129,13 +129,28 @@
 //! **Q: What if I just want to display a single value without passing in the whole `&mut World`?**
 //!
 //! **A:** You can use [`ui_for_value`](crate::reflect_inspector::ui_for_value). Note that displaying things like `Handle<StandardMaterial>` won't be able to display the asset's value.
+//!
+//! **Q: Can I display values outside of a bevy app?**
+//!
+//! **A:** Yes, fill a `TypeRegistry` of your own with [`register_default_config`] and pass it to the functions of the [`reflect_inspector`],
+//! which only need a [`egui::Ui`]. Only the values which live in the `World`, like assets behind a `Handle`, can't be shown without one.
+//!
+//! **Q: Can I inspect collections like `SmallVec` or `ArrayVec`?**
+//!
+//! **A:** `SmallVec` implements [`List`](bevy_reflect::List) through the `smallvec` feature of `bevy_reflect`, so once registered it
+//! is shown and edited like a `Vec`. `ArrayVec` doesn't implement `Reflect`, and this crate can't implement it for a foreign type,
+//! so it stays opaque unless it is wrapped in a type of your own that implements `List`.
 
 pub mod bevy_inspector;
+#[cfg(feature = "bevy_gizmos")]
+pub mod hover_highlight;
 pub mod inspector_egui_impls;
 pub mod inspector_options;
 pub mod quick;
 pub mod reflect_inspector;
 pub mod restricted_world_view;
+#[cfg(feature = "bevy_gizmos")]
+pub mod transform_gizmo;
 
 mod egui_utils;
 mod utils;
@@ -152,15 +167,39 @@ impl bevy_app::Plugin for DefaultInspectorConfigPlugin {
         }
 
         let type_registry = app.world.resource::<bevy_ecs::prelude::AppTypeRegistry>();
-        let mut type_registry = type_registry.write();
-
-        inspector_options::default_options::register_default_options(&mut type_registry);
-        inspector_egui_impls::register_std_impls(&mut type_registry);
-        inspector_egui_impls::register_glam_impls(&mut type_registry);
-        inspector_egui_impls::register_bevy_impls(&mut type_registry);
+        register_default_config(&mut type_registry.write());
     }
 }
 
+/// Registers the same default [`struct@InspectorOptions`] and [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl)s
+/// as the [`DefaultInspectorConfigPlugin`], but in a `type_registry` of your own instead of the `AppTypeRegistry`.
+///
+/// Together with [`reflect_inspector::ui_for_value`] this displays values without any bevy `App` or `World`:
+/// ```rust
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// #[derive(Reflect, Default)]
+/// struct Settings {
+///     volume: f32,
+///     name: String,
+/// }
+///
+/// let mut type_registry = TypeRegistry::default();
+/// bevy_inspector_egui::register_default_config(&mut type_registry);
+/// type_registry.register::<Settings>();
+///
+/// # let ctx = egui::Context::default();
+/// # let _ = ctx.run(Default::default(), |ctx| { egui::CentralPanel::default().show(ctx, |ui| {
+/// let mut settings = Settings::default();
+/// bevy_inspector_egui::reflect_inspector::ui_for_value(&mut settings, ui, &type_registry);
+/// # }); });
+/// ```
+pub fn register_default_config(type_registry: &mut bevy_reflect::TypeRegistry) {
+    inspector_options::default_options::register_default_options(type_registry);
+    inspector_egui_impls::register_std_impls(type_registry);
+    inspector_egui_impls::register_glam_impls(type_registry);
+    inspector_egui_impls::register_bevy_impls(type_registry);
+}
+
 #[doc(inline)]
 pub use inspector_options::InspectorOptions;
 
@@ -5,26 +5,292 @@
 //! **Cons:** not configurable
 //!
 //! When you want something more custom, you can use these plugins as a starting point.
+//! The content of their windows is also available as `*_panel` functions like [`world_inspector_panel`],
+//! which render into a [`egui::Ui`] of your own, for example a tab of `egui_dock`.
+//!
+//! The windows can show the frame rate at the top with the [`QuickWindowHeader`] resource,
+//! and can be docked to the side of the screen with the `window_mode` builder of each plugin, see [`WindowMode`].
+//! Edits made in the world, resource and filter query windows can be undone with `Ctrl+Z`, see [`history`].
+//! The other windows show non-send resources, states, assets and events, whose edits aren't recorded in the history.
+//! Types are looked up in the [`InspectorTypeRegistry`](crate::bevy_inspector::type_registry::InspectorTypeRegistry)
+//! instead of the `AppTypeRegistry` if you insert one.
 
-use std::{marker::PhantomData, sync::Mutex};
+use std::{
+    collections::{HashSet, VecDeque},
+    marker::PhantomData,
+    sync::Mutex,
+};
 
-use bevy_app::{Plugin, Update};
+use bevy_app::{Plugin, PostUpdate, Update};
 use bevy_asset::Asset;
+use bevy_diagnostic::{Diagnostic, DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use bevy_ecs::{
-    component::Tick, prelude::*, query::ReadOnlyWorldQuery, schedule::BoxedCondition,
-    system::ReadOnlySystem, world::unsafe_world_cell::UnsafeWorldCell,
+    component::Tick, event::ManualEventReader, prelude::*, query::ReadOnlyWorldQuery,
+    schedule::BoxedCondition, system::ReadOnlySystem, world::unsafe_world_cell::UnsafeWorldCell,
 };
 use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_input::{keyboard::KeyCode, Input};
 use bevy_reflect::Reflect;
 use bevy_window::PrimaryWindow;
 use pretty_type_name::pretty_type_name;
 
+use crate::bevy_inspector::command_queue::{apply_inspector_commands, InspectorCommandQueue};
+use crate::bevy_inspector::hierarchy::SelectedEntities;
+use crate::bevy_inspector::history::{self, InspectorHistory};
+use crate::bevy_inspector::pins::InspectorPins;
+use crate::bevy_inspector::type_registry::inspector_type_registry;
+use crate::bevy_inspector::WorldInspectorOptions;
+use crate::reflect_inspector::{Context, InspectorUi};
+use crate::restricted_world_view::RestrictedWorldView;
+use crate::utils::guess_entity_name::guess_entity_name;
 use crate::{bevy_inspector, DefaultInspectorConfigPlugin};
 
 const DEFAULT_SIZE: (f32, f32) = (320., 160.);
 
+/// What to show at the top of the windows of the quick plugins, above their content.
+///
+/// ```no_run
+/// use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{QuickWindowHeader, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(FrameTimeDiagnosticsPlugin)
+///         .insert_resource(QuickWindowHeader { frame_time: true })
+///         .add_plugins(WorldInspectorPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct QuickWindowHeader {
+    /// Show the frames per second and frame time, see [`frame_time_ui`].
+    pub frame_time: bool,
+}
+
+/// Where the quick plugins show their content, chosen with the `window_mode` builder of each plugin.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{WindowMode, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(WorldInspectorPlugin::new().window_mode(WindowMode::RightPanel))
+///         .run();
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowMode {
+    /// A floating [`egui::Window`]
+    #[default]
+    Window,
+    /// A [`egui::SidePanel`] docked to the left edge of the screen
+    LeftPanel,
+    /// A [`egui::SidePanel`] docked to the right edge of the screen
+    RightPanel,
+}
+
+/// Whether the windows of the quick plugins with a `toggle_key` are shown.
+///
+/// Pressing any of their toggle keys shows or hides all of them at once. Plugins without a toggle key aren't affected.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{InspectorVisibility, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         // start out hidden until F12 is pressed
+///         .insert_resource(InspectorVisibility { visible: false })
+///         .add_plugins(WorldInspectorPlugin::new().toggle_key(KeyCode::F12))
+///         .run();
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InspectorVisibility {
+    pub visible: bool,
+}
+
+impl Default for InspectorVisibility {
+    fn default() -> Self {
+        InspectorVisibility { visible: true }
+    }
+}
+
+/// The keys passed to the `toggle_key` builders, handled by a single system so that sharing a key doesn't toggle twice
+#[derive(Resource, Default)]
+struct ToggleKeys(HashSet<KeyCode>);
+
+fn add_toggle_key(app: &mut bevy_app::App, key: KeyCode) {
+    app.init_resource::<InspectorVisibility>();
+    if !app.world.contains_resource::<ToggleKeys>() {
+        app.init_resource::<ToggleKeys>();
+        app.add_systems(Update, toggle_visibility);
+    }
+    app.world.resource_mut::<ToggleKeys>().0.insert(key);
+}
+
+/// Defers the structural edits of the quick plugins until all of them were drawn, see [`command_queue`](bevy_inspector::command_queue)
+fn add_command_queue(app: &mut bevy_app::App) {
+    if !app.world.contains_resource::<InspectorCommandQueue>() {
+        app.init_resource::<InspectorCommandQueue>();
+        app.add_systems(PostUpdate, apply_inspector_commands);
+    }
+}
+
+/// The `run_if`, `toggle_key` and `window_mode` builder settings which all quick plugins have
+#[derive(Default)]
+struct QuickPluginSettings {
+    condition: Mutex<Option<BoxedCondition>>,
+    toggle_key: Option<KeyCode>,
+    window_mode: WindowMode,
+}
+
+/// Implements the `run_if`, `toggle_key` and `window_mode` builders for a plugin with a `settings: QuickPluginSettings` field
+macro_rules! quick_plugin_builders {
+    ($plugin:ident $(<$param:ident>)?, $content:literal) => {
+        impl$(<$param>)? $plugin$(<$param>)? {
+            /// Only show the UI of the specified condition is active
+            pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+                let condition_system = IntoSystem::into_system(condition);
+                self.settings.condition =
+                    Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+                self
+            }
+
+            /// Show and hide the window by pressing `key`, see [`InspectorVisibility`]
+            pub fn toggle_key(mut self, key: KeyCode) -> Self {
+                self.settings.toggle_key = Some(key);
+                self
+            }
+
+            #[doc = concat!("Show ", $content, " in a window or a side panel, see [`WindowMode`]")]
+            pub fn window_mode(mut self, window_mode: WindowMode) -> Self {
+                self.settings.window_mode = window_mode;
+                self
+            }
+        }
+    };
+}
+
+/// Adds the plugins every quick window needs and the `system` showing the window to `Update`.
+///
+/// The system only runs while the `run_if` condition of the `settings` holds and the window isn't hidden by its `toggle_key`.
+/// Structural edits made in the window are deferred to the [`InspectorCommandQueue`].
+fn build_quick_window<M>(
+    app: &mut bevy_app::App,
+    settings: &QuickPluginSettings,
+    system: impl IntoSystemConfigs<M>,
+) {
+    if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+        app.add_plugins(DefaultInspectorConfigPlugin);
+    }
+    if !app.is_plugin_added::<EguiPlugin>() {
+        app.add_plugins(EguiPlugin);
+    }
+
+    let mut system = system.into_configs();
+    if let Some(condition) = settings.condition.lock().unwrap().take() {
+        system = system.run_if(BoxedConditionHelper(condition));
+    }
+    if let Some(key) = settings.toggle_key {
+        add_toggle_key(app, key);
+        system = system.run_if(inspector_visible);
+    }
+    add_command_queue(app);
+    app.add_systems(Update, system);
+}
+
+fn toggle_visibility(
+    keys: Res<ToggleKeys>,
+    input: Option<Res<Input<KeyCode>>>,
+    mut egui_contexts: Query<&mut EguiContext>,
+    mut visibility: ResMut<InspectorVisibility>,
+) {
+    // the key is typed into a text field instead
+    if egui_contexts
+        .iter_mut()
+        .any(|mut egui_context| egui_context.get_mut().wants_keyboard_input())
+    {
+        return;
+    }
+    if input.is_some_and(|input| input.any_just_pressed(keys.0.iter().copied())) {
+        visibility.visible = !visibility.visible;
+    }
+}
+
+fn inspector_visible(visibility: Res<InspectorVisibility>) -> bool {
+    visibility.visible
+}
+
+/// Shows `add_contents` in a window or a side panel, depending on the `mode`.
+///
+/// `window` is only called for [`WindowMode::Window`] to configure the window beyond its `title` and `id`.
+/// Side panels have no title bar, so they show the `title` as a heading instead.
+fn show_window(
+    ctx: &egui::Context,
+    mode: WindowMode,
+    title: &str,
+    id: egui::Id,
+    window: impl FnOnce(egui::Window<'_>) -> egui::Window<'_>,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
+    let panel = match mode {
+        WindowMode::Window => {
+            window(egui::Window::new(title).id(id)).show(ctx, add_contents);
+            return;
+        }
+        WindowMode::LeftPanel => egui::SidePanel::left(id),
+        WindowMode::RightPanel => egui::SidePanel::right(id),
+    };
+    panel.default_width(DEFAULT_SIZE.0).show(ctx, |ui| {
+        ui.heading(title);
+        add_contents(ui);
+    });
+}
+
+fn window_header(world: &World, ui: &mut egui::Ui) {
+    let header = world
+        .get_resource::<QuickWindowHeader>()
+        .copied()
+        .unwrap_or_default();
+    if header.frame_time && frame_time_ui(world, ui) {
+        ui.separator();
+    }
+}
+
+/// Shows the frames per second and frame time measured by the [`FrameTimeDiagnosticsPlugin`].
+///
+/// Nothing is shown if the plugin wasn't added or hasn't measured anything yet, in which case `false` is returned.
+pub fn frame_time_ui(world: &World, ui: &mut egui::Ui) -> bool {
+    let Some(diagnostics) = world.get_resource::<DiagnosticsStore>() else {
+        return false;
+    };
+    let smoothed = |id| diagnostics.get(id).and_then(Diagnostic::smoothed);
+    let (Some(fps), Some(frame_time)) = (
+        smoothed(FrameTimeDiagnosticsPlugin::FPS),
+        smoothed(FrameTimeDiagnosticsPlugin::FRAME_TIME),
+    ) else {
+        return false;
+    };
+
+    ui.horizontal(|ui| {
+        ui.monospace(format!("{fps:.0} fps"));
+        ui.weak(format!("{frame_time:.2} ms"));
+    });
+    true
+}
+
 /// Plugin displaying a egui window with an entity list, resources and assets
 ///
+/// Edits can be undone with `Ctrl+Z` and redone with `Ctrl+Y`, see [`history`](crate::bevy_inspector::history).
+/// Entities and resources can be pinned to the top of the window from their context menu, see [`pins`](crate::bevy_inspector::pins).
+/// With [`WorldInspectorPlugin::selected_only`], only the entities in the [`SelectedEntities`] are shown, e.g. for click-to-select.
+///
 /// You can use [`WorldInspectorPlugin::run_if`] to control when the window is shown, for example
 /// in combination with `input_toggle_active`.
 ///
@@ -42,41 +308,103 @@ const DEFAULT_SIZE: (f32, f32) = (320., 160.);
 /// ```
 #[derive(Default)]
 pub struct WorldInspectorPlugin {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
+    options: WorldInspectorOptions,
+    entities_ui: Option<EntitiesUi>,
+    selected_only: bool,
 }
 
+type EntitiesUi = fn(&mut World, &mut egui::Ui, &WorldInspectorOptions);
+
 impl WorldInspectorPlugin {
     pub fn new() -> Self {
         Self::default()
     }
 
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+    /// Only show the entities matching the query `Q` and filter `F` as a flat list, without resources and assets.
+    ///
+    /// Unlike the [`FilterQueryInspectorPlugin`] the other options of the world inspector still apply.
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_inspector_egui::quick::WorldInspectorPlugin;
+    /// #[derive(Component)]
+    /// struct Selected;
+    ///
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .add_plugins(WorldInspectorPlugin::filtered::<(), With<Selected>>());
+    /// ```
+    pub fn filtered<Q: ReadOnlyWorldQuery + 'static, F: ReadOnlyWorldQuery + 'static>() -> Self {
+        WorldInspectorPlugin {
+            entities_ui: Some(bevy_inspector::ui_for_world_entities_query::<Q, F>),
+            ..Default::default()
+        }
+    }
+
+    /// Don't show the component `T` on entities
+    pub fn hide_component<T: Component>(mut self) -> Self {
+        self.options.hide_component::<T>();
+        self
+    }
+
+    /// Don't show components defined in the `module` or its submodules, see [`WorldInspectorOptions::hide_module`]
+    pub fn hide_module(mut self, module: impl Into<String>) -> Self {
+        self.options.hide_module(module);
+        self
+    }
+
+    /// Allow adding and removing components of entities, see [`WorldInspectorOptions::edit_components`]
+    pub fn edit_components(mut self, edit_components: bool) -> Self {
+        self.options.edit_components(edit_components);
+        self
+    }
+
+    /// Start out only showing what changed since the last frame, see [`WorldInspectorOptions::only_changed`]
+    pub fn only_changed(mut self, only_changed: bool) -> Self {
+        self.options.only_changed(only_changed);
+        self
+    }
+
+    /// While an entity is in the [`SelectedEntities`] resource, show all components of just that entity
+    /// instead of the whole world, with a `World` link above it which clears the selection again.
+    /// If several entities are selected, the components they share are shown and edited for all of them at once,
+    /// see [`ui_for_entities_shared_components`](bevy_inspector::ui_for_entities_shared_components).
+    ///
+    /// Selecting the entities is up to you, for example from a system reacting to mouse clicks in the viewport
+    /// or with the Ctrl/Shift-click selection of the [`hierarchy_ui`](bevy_inspector::hierarchy::hierarchy_ui).
+    pub fn selected_only(mut self, selected_only: bool) -> Self {
+        self.selected_only = selected_only;
         self
     }
 }
 
+quick_plugin_builders!(WorldInspectorPlugin, "the inspector");
+
 impl Plugin for WorldInspectorPlugin {
     fn build(&self, app: &mut bevy_app::App) {
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
+        app.init_resource::<InspectorHistory>();
+        app.init_resource::<InspectorPins>();
+        if self.selected_only {
+            app.init_resource::<SelectedEntities>();
         }
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = world_inspector_ui.into_configs();
-        if let Some(condition) = condition {
-            system = system.run_if(BoxedConditionHelper(condition));
-        }
-        app.add_systems(Update, system);
+        let options = self.options.clone();
+        let entities_ui = self.entities_ui;
+        let window_mode = self.settings.window_mode;
+        let selected_only = self.selected_only;
+        build_quick_window(app, &self.settings, move |world: &mut World| {
+            world_inspector_ui(world, &options, entities_ui, window_mode, selected_only)
+        });
     }
 }
 
-fn world_inspector_ui(world: &mut World) {
+fn world_inspector_ui(
+    world: &mut World,
+    options: &WorldInspectorOptions,
+    entities_ui: Option<EntitiesUi>,
+    window_mode: WindowMode,
+    selected_only: bool,
+) {
     let egui_context = world
         .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
         .get_single(world);
@@ -86,14 +414,96 @@ fn world_inspector_ui(world: &mut World) {
     };
     let mut egui_context = egui_context.clone();
 
-    egui::Window::new("World Inspector")
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
+    let title = "World Inspector";
+    show_window(
+        egui_context.get_mut(),
+        window_mode,
+        title,
+        egui::Id::new(title),
+        |window| window.default_size(DEFAULT_SIZE),
+        |ui| {
+            window_header(world, ui);
             egui::ScrollArea::vertical().show(ui, |ui| {
-                bevy_inspector::ui_for_world(world, ui);
+                let selected = match selected_only {
+                    true => existing_selected(world),
+                    false => Vec::new(),
+                };
+                match selected.is_empty() {
+                    true => world_inspector_content(world, ui, options, entities_ui),
+                    false => selected_entities_ui(world, ui, &selected, options),
+                }
                 ui.allocate_space(ui.available_size());
             });
-        });
+        },
+    );
+}
+
+/// Content of the [`WorldInspectorPlugin`] window, for showing it in a `ui` of your own like an `egui_dock` tab.
+///
+/// Like the window, this handles the undo and redo shortcuts of the [`history`](crate::bevy_inspector::history).
+/// Unlike it, the content isn't wrapped in a [`egui::ScrollArea`].
+pub fn world_inspector_panel(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    options: &WorldInspectorOptions,
+) {
+    world_inspector_content(world, ui, options, None);
+}
+
+fn world_inspector_content(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    options: &WorldInspectorOptions,
+    entities_ui: Option<EntitiesUi>,
+) {
+    history::handle_shortcuts(world, ui.ctx());
+    match entities_ui {
+        Some(entities_ui) => entities_ui(world, ui, options),
+        None => bevy_inspector::ui_for_world_with_options(world, ui, options),
+    }
+}
+
+/// The selected entities which still exist
+fn existing_selected(world: &World) -> Vec<Entity> {
+    world
+        .get_resource::<SelectedEntities>()
+        .map(|selected| {
+            selected
+                .iter()
+                .filter(|&entity| world.get_entity(entity).is_some())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Breadcrumb leading back to the whole world, followed by the components of the selected entity,
+/// or the components shared by all of them if there are several
+fn selected_entities_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    entities: &[Entity],
+    options: &WorldInspectorOptions,
+) {
+    history::handle_shortcuts(world, ui.ctx());
+    ui.horizontal(|ui| {
+        if ui
+            .link("World")
+            .on_hover_text("Show all entities")
+            .clicked()
+        {
+            world.resource_mut::<SelectedEntities>().clear();
+        }
+        ui.weak("›");
+        match entities {
+            &[entity] => ui.label(guess_entity_name(world, entity)),
+            entities => ui.label(format!("{} entities", entities.len())),
+        };
+    });
+    ui.separator();
+    match entities {
+        &[entity] => bevy_inspector::ui_for_entity_with_options(world, entity, ui, options),
+        entities => bevy_inspector::ui_for_entities_shared_components(world, entities, ui),
+    }
 }
 
 /// Plugin displaying an egui window for a single resource.
@@ -101,6 +511,9 @@ fn world_inspector_ui(world: &mut World) {
 ///
 /// You can use [`ResourceInspectorPlugin::run_if`] to control when the window is shown, for example
 /// in combination with `input_toggle_active`.
+/// The window itself can be configured with [`window_title`](ResourceInspectorPlugin::window_title),
+/// [`default_size`](ResourceInspectorPlugin::default_size) and [`resizable`](ResourceInspectorPlugin::resizable),
+/// or replaced by a side panel with [`window_mode`](ResourceInspectorPlugin::window_mode).
 ///
 /// ```no_run
 /// use bevy::prelude::*;
@@ -128,15 +541,28 @@ fn world_inspector_ui(world: &mut World) {
 /// }
 /// ```
 pub struct ResourceInspectorPlugin<T> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
+    window: ResourceWindowOptions,
     marker: PhantomData<fn() -> T>,
 }
 
+#[derive(Clone)]
+struct ResourceWindowOptions {
+    title: Option<String>,
+    default_size: egui::Vec2,
+    resizable: bool,
+}
+
 impl<T> Default for ResourceInspectorPlugin<T> {
     fn default() -> Self {
         Self {
             marker: PhantomData,
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
+            window: ResourceWindowOptions {
+                title: None,
+                default_size: egui::Vec2::ZERO,
+                resizable: true,
+            },
         }
     }
 }
@@ -146,33 +572,52 @@ impl<T> ResourceInspectorPlugin<T> {
         Self::default()
     }
 
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+    /// Title of the window, instead of the name of the resource type
+    pub fn window_title(mut self, title: &str) -> Self {
+        self.window.title = Some(title.to_string());
+        self
+    }
+
+    /// Initial size of the window, which otherwise starts out as small as its content allows
+    pub fn default_size(mut self, size: egui::Vec2) -> Self {
+        self.window.default_size = size;
+        self
+    }
+
+    /// Whether the window can be resized by dragging its edges, `true` by default
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.window.resizable = resizable;
         self
     }
 }
 
+quick_plugin_builders!(ResourceInspectorPlugin<T>, "the resource");
+
 impl<T: Resource + Reflect> Plugin for ResourceInspectorPlugin<T> {
     fn build(&self, app: &mut bevy_app::App) {
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
+        app.init_resource::<InspectorHistory>();
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = inspector_ui::<T>.into_configs();
-        if let Some(condition) = condition {
-            system = system.run_if(BoxedConditionHelper(condition));
-        }
-        app.add_systems(Update, system);
+        let window = self.window.clone();
+        let window_mode = self.settings.window_mode;
+        build_quick_window(app, &self.settings, move |world: &mut World| {
+            inspector_ui(
+                world,
+                &window,
+                window_mode,
+                pretty_type_name::<T>(),
+                resource_inspector_panel::<T>,
+            )
+        });
     }
 }
 
-fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
+fn inspector_ui(
+    world: &mut World,
+    window: &ResourceWindowOptions,
+    window_mode: WindowMode,
+    type_name: String,
+    panel: fn(&mut World, &mut egui::Ui),
+) {
     let egui_context = world
         .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
         .get_single(world);
@@ -182,15 +627,99 @@ fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
     };
     let mut egui_context = egui_context.clone();
 
-    egui::Window::new(pretty_type_name::<T>())
-        .default_size((0., 0.))
-        .show(egui_context.get_mut(), |ui| {
+    let title = window.title.as_deref().unwrap_or(&type_name);
+    // the id stays the same when the title is customized, so that windows for different resources can share a title
+    show_window(
+        egui_context.get_mut(),
+        window_mode,
+        title,
+        egui::Id::new(&type_name),
+        |egui_window| {
+            egui_window
+                .default_size(window.default_size)
+                .resizable(window.resizable)
+        },
+        |ui| {
+            window_header(world, ui);
             egui::ScrollArea::vertical().show(ui, |ui| {
-                bevy_inspector::ui_for_resource::<T>(world, ui);
+                panel(world, ui);
 
                 ui.allocate_space(ui.available_size());
             });
+        },
+    );
+}
+
+/// Content of the [`ResourceInspectorPlugin`] window, see [`world_inspector_panel`]
+pub fn resource_inspector_panel<T: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    history::handle_shortcuts(world, ui.ctx());
+    bevy_inspector::ui_for_resource::<T>(world, ui);
+}
+
+/// Plugin displaying an egui window for a single non-send resource.
+/// Remember to call [`App::register_type`](bevy_app::App::register_type).
+///
+/// The resource is displayed with [`ui_for_non_send_resource`](bevy_inspector::ui_for_non_send_resource),
+/// from an exclusive system which runs on the main thread like non-send resources require.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::NonSendResourceInspectorPlugin;
+///
+/// #[derive(Reflect, Default)]
+/// struct MainThreadCache {
+///     entries: Vec<String>,
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .insert_non_send_resource(MainThreadCache::default())
+///         .register_type::<MainThreadCache>()
+///         .add_plugin(NonSendResourceInspectorPlugin::<MainThreadCache>::default())
+///         .run();
+/// }
+/// ```
+pub struct NonSendResourceInspectorPlugin<T> {
+    settings: QuickPluginSettings,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for NonSendResourceInspectorPlugin<T> {
+    fn default() -> Self {
+        Self {
+            settings: QuickPluginSettings::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> NonSendResourceInspectorPlugin<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+quick_plugin_builders!(NonSendResourceInspectorPlugin<T>, "the resource");
+
+impl<T: Reflect> Plugin for NonSendResourceInspectorPlugin<T> {
+    fn build(&self, app: &mut bevy_app::App) {
+        let window = ResourceWindowOptions {
+            title: None,
+            default_size: egui::Vec2::ZERO,
+            resizable: true,
+        };
+        let window_mode = self.settings.window_mode;
+        build_quick_window(app, &self.settings, move |world: &mut World| {
+            let panel = non_send_resource_inspector_panel::<T>;
+            inspector_ui(world, &window, window_mode, pretty_type_name::<T>(), panel)
         });
+    }
+}
+
+/// Content of the [`NonSendResourceInspectorPlugin`] window, see [`world_inspector_panel`]
+pub fn non_send_resource_inspector_panel<T: Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    bevy_inspector::ui_for_non_send_resource::<T>(world, ui);
 }
 
 /// Plugin displaying an egui window for an app state.
@@ -222,14 +751,14 @@ fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct StateInspectorPlugin<T> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
     marker: PhantomData<fn() -> T>,
 }
 
 impl<T> Default for StateInspectorPlugin<T> {
     fn default() -> Self {
         StateInspectorPlugin {
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
             marker: PhantomData,
         }
     }
@@ -238,34 +767,20 @@ impl<T> StateInspectorPlugin<T> {
     pub fn new() -> Self {
         Self::default()
     }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
 }
 
+quick_plugin_builders!(StateInspectorPlugin<T>, "the state");
+
 impl<T: States + Reflect> Plugin for StateInspectorPlugin<T> {
     fn build(&self, app: &mut bevy_app::App) {
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = state_ui::<T>.into_configs();
-        if let Some(condition) = condition {
-            system = system.run_if(BoxedConditionHelper(condition));
-        }
-        app.add_systems(Update, system);
+        let window_mode = self.settings.window_mode;
+        build_quick_window(app, &self.settings, move |world: &mut World| {
+            state_ui::<T>(world, window_mode)
+        });
     }
 }
 
-fn state_ui<T: States + Reflect>(world: &mut World) {
+fn state_ui<T: States + Reflect>(world: &mut World, window_mode: WindowMode) {
     let egui_context = world
         .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
         .get_single(world);
@@ -275,15 +790,28 @@ fn state_ui<T: States + Reflect>(world: &mut World) {
     };
     let mut egui_context = egui_context.clone();
 
-    egui::Window::new(std::any::type_name::<T>())
-        .resizable(false)
-        .title_bar(false)
-        .show(egui_context.get_mut(), |ui| {
+    // the window has no title bar, so it shows the title as a heading like the side panels do
+    show_window(
+        egui_context.get_mut(),
+        window_mode,
+        &pretty_type_name::<T>(),
+        egui::Id::new(std::any::type_name::<T>()),
+        |window| window.resizable(false).title_bar(false),
+        |ui| {
+            window_header(world, ui);
             egui::ScrollArea::vertical().show(ui, |ui| {
-                ui.heading(pretty_type_name::<T>());
-                bevy_inspector::ui_for_state::<T>(world, ui);
+                if window_mode == WindowMode::Window {
+                    ui.heading(pretty_type_name::<T>());
+                }
+                state_inspector_panel::<T>(world, ui);
             });
-        });
+        },
+    );
+}
+
+/// Content of the [`StateInspectorPlugin`] window, see [`world_inspector_panel`]
+pub fn state_inspector_panel<T: States + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    bevy_inspector::ui_for_state::<T>(world, ui);
 }
 
 /// Plugin displaying an egui window for all assets of type `A`.
@@ -304,14 +832,14 @@ fn state_ui<T: States + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct AssetInspectorPlugin<A> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
     marker: PhantomData<fn() -> A>,
 }
 
 impl<A> Default for AssetInspectorPlugin<A> {
     fn default() -> Self {
         Self {
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
             marker: PhantomData,
         }
     }
@@ -320,34 +848,20 @@ impl<A> AssetInspectorPlugin<A> {
     pub fn new() -> Self {
         Self::default()
     }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
 }
 
+quick_plugin_builders!(AssetInspectorPlugin<A>, "the assets");
+
 impl<A: Asset + Reflect> Plugin for AssetInspectorPlugin<A> {
     fn build(&self, app: &mut bevy_app::App) {
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = asset_inspector_ui::<A>.into_configs();
-        if let Some(condition) = condition {
-            system = system.run_if(BoxedConditionHelper(condition));
-        }
-        app.add_systems(Update, system);
+        let window_mode = self.settings.window_mode;
+        build_quick_window(app, &self.settings, move |world: &mut World| {
+            asset_inspector_ui::<A>(world, window_mode)
+        });
     }
 }
 
-fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World) {
+fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World, window_mode: WindowMode) {
     let egui_context = world
         .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
         .get_single(world);
@@ -357,15 +871,27 @@ fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World) {
     };
     let mut egui_context = egui_context.clone();
 
-    egui::Window::new(pretty_type_name::<A>())
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
+    let title = pretty_type_name::<A>();
+    show_window(
+        egui_context.get_mut(),
+        window_mode,
+        &title,
+        egui::Id::new(&title),
+        |window| window.default_size(DEFAULT_SIZE),
+        |ui| {
+            window_header(world, ui);
             egui::ScrollArea::vertical().show(ui, |ui| {
-                bevy_inspector::ui_for_assets::<A>(world, ui);
+                asset_inspector_panel::<A>(world, ui);
 
                 ui.allocate_space(ui.available_size());
             });
-        });
+        },
+    );
+}
+
+/// Content of the [`AssetInspectorPlugin`] window, see [`world_inspector_panel`]
+pub fn asset_inspector_panel<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    bevy_inspector::ui_for_assets::<A>(world, ui);
 }
 
 /// Plugin displaying an egui window for all entities matching the filter `F`.
@@ -381,14 +907,14 @@ fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct FilterQueryInspectorPlugin<F> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
     marker: PhantomData<fn() -> F>,
 }
 
 impl<F> Default for FilterQueryInspectorPlugin<F> {
     fn default() -> Self {
         Self {
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
             marker: PhantomData,
         }
     }
@@ -397,38 +923,25 @@ impl<A> FilterQueryInspectorPlugin<A> {
     pub fn new() -> Self {
         Self::default()
     }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
 }
 
+quick_plugin_builders!(FilterQueryInspectorPlugin<F>, "the entities");
+
 impl<F: 'static> Plugin for FilterQueryInspectorPlugin<F>
 where
     F: ReadOnlyWorldQuery,
 {
     fn build(&self, app: &mut bevy_app::App) {
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
+        app.init_resource::<InspectorHistory>();
 
-        let condition: Option<Box<dyn ReadOnlySystem<In = (), Out = bool>>> =
-            self.condition.lock().unwrap().take();
-        let mut system = entity_query_ui::<F>.into_configs();
-        if let Some(condition) = condition {
-            system = system.run_if(BoxedConditionHelper(condition));
-        }
-        app.add_systems(Update, system);
+        let window_mode = self.settings.window_mode;
+        build_quick_window(app, &self.settings, move |world: &mut World| {
+            entity_query_ui::<F>(world, window_mode)
+        });
     }
 }
 
-fn entity_query_ui<F: ReadOnlyWorldQuery>(world: &mut World) {
+fn entity_query_ui<F: ReadOnlyWorldQuery>(world: &mut World, window_mode: WindowMode) {
     let egui_context = world
         .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
         .get_single(world);
@@ -438,14 +951,187 @@ fn entity_query_ui<F: ReadOnlyWorldQuery>(world: &mut World) {
     };
     let mut egui_context = egui_context.clone();
 
-    egui::Window::new(pretty_type_name::<F>())
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
+    let title = pretty_type_name::<F>();
+    show_window(
+        egui_context.get_mut(),
+        window_mode,
+        &title,
+        egui::Id::new(&title),
+        |window| window.default_size(DEFAULT_SIZE),
+        |ui| {
+            window_header(world, ui);
             egui::ScrollArea::vertical().show(ui, |ui| {
-                bevy_inspector::ui_for_world_entities_filtered::<F>(world, ui, false);
+                filter_query_inspector_panel::<F>(world, ui);
                 ui.allocate_space(ui.available_size());
             });
+        },
+    );
+}
+
+/// Content of the [`FilterQueryInspectorPlugin`] window, see [`world_inspector_panel`]
+pub fn filter_query_inspector_panel<F: ReadOnlyWorldQuery>(world: &mut World, ui: &mut egui::Ui) {
+    history::handle_shortcuts(world, ui.ctx());
+    bevy_inspector::ui_for_world_entities_filtered::<F>(world, ui, false);
+}
+
+/// Default value of [`EventInspectorPlugin::max_events`]
+pub const DEFAULT_MAX_EVENTS: usize = 100;
+
+/// Plugin displaying an egui window with a log of the events of type `E` sent each frame.
+///
+/// The events are read using a separate [`ManualEventReader`](bevy_ecs::event::ManualEventReader), so other systems reading them are unaffected.
+/// Only the last [`max_events`](EventInspectorPlugin::max_events) events are kept.
+/// Events are still recorded while the window is hidden by [`run_if`](EventInspectorPlugin::run_if) or a toggle key.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::EventInspectorPlugin;
+///
+/// #[derive(Event, Reflect)]
+/// struct Explosion {
+///     position: Vec3,
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_event::<Explosion>() // `EventInspectorPlugin` won't add the event
+///         .add_plugins(EventInspectorPlugin::<Explosion>::default())
+///         .run();
+/// }
+/// ```
+pub struct EventInspectorPlugin<E> {
+    settings: QuickPluginSettings,
+    max_events: usize,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E> Default for EventInspectorPlugin<E> {
+    fn default() -> Self {
+        Self {
+            settings: QuickPluginSettings::default(),
+            max_events: DEFAULT_MAX_EVENTS,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E> EventInspectorPlugin<E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of events to keep, older ones are discarded
+    pub fn max_events(mut self, max_events: usize) -> Self {
+        self.max_events = max_events;
+        self
+    }
+}
+
+quick_plugin_builders!(EventInspectorPlugin<E>, "the events");
+
+impl<E: Event + Reflect> Plugin for EventInspectorPlugin<E> {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.insert_resource(EventLog::<E> {
+            reader: Default::default(),
+            events: VecDeque::new(),
+            received: 0,
+            max_events: self.max_events,
         });
+
+        // the events are recorded even while the window is hidden
+        app.add_systems(Update, record_events::<E>);
+        let window_mode = self.settings.window_mode;
+        build_quick_window(
+            app,
+            &self.settings,
+            (move |world: &mut World| event_inspector_ui::<E>(world, window_mode))
+                .after(record_events::<E>),
+        );
+    }
+}
+
+#[derive(Resource)]
+struct EventLog<E: Event> {
+    reader: ManualEventReader<E>,
+    events: VecDeque<Box<dyn Reflect>>,
+    /// Number of events received since the app started, including discarded and cleared ones
+    received: usize,
+    max_events: usize,
+}
+
+fn record_events<E: Event + Reflect>(mut log: ResMut<EventLog<E>>, events: Option<Res<Events<E>>>) {
+    let Some(events) = events else {
+        return;
+    };
+    let log = &mut *log;
+    for event in log.reader.iter(&events) {
+        log.events.push_back(event.clone_value());
+        log.received += 1;
+    }
+    while log.events.len() > log.max_events {
+        log.events.pop_front();
+    }
+}
+
+fn event_inspector_ui<E: Event + Reflect>(world: &mut World, window_mode: WindowMode) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .get_single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    let title = pretty_type_name::<E>();
+    let id = egui::Id::new(&title);
+    show_window(
+        egui_context.get_mut(),
+        window_mode,
+        &title,
+        id,
+        |window| window.default_size(DEFAULT_SIZE),
+        |ui| {
+            window_header(world, ui);
+            event_log_ui::<E>(world, ui, id);
+        },
+    );
+}
+
+/// The events in the [`EventLog`], with a button to clear it
+fn event_log_ui<E: Event + Reflect>(world: &mut World, ui: &mut egui::Ui, id: egui::Id) {
+    let type_registry = inspector_type_registry(world);
+    let type_registry = type_registry.read();
+
+    world.resource_scope(|world, mut log: Mut<EventLog<E>>| {
+        ui.horizontal(|ui| {
+            ui.label(format!("{} events received", log.received));
+            if ui.button("Clear").clicked() {
+                log.events.clear();
+            }
+        });
+        ui.separator();
+
+        let mut cx = Context {
+            world: Some(RestrictedWorldView::new(world)),
+            queue: None,
+        };
+        let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+
+        let first_index = log.received - log.events.len();
+        egui::ScrollArea::vertical()
+            .stick_to_bottom(true)
+            .show(ui, |ui| {
+                for (i, event) in log.events.iter().enumerate() {
+                    let index = first_index + i;
+                    ui.weak(format!("#{index}"));
+                    env.ui_for_reflect_readonly_with_options(&**event, ui, id.with(index), &());
+                    ui.separator();
+                }
+                ui.allocate_space(ui.available_size());
+            });
+    });
 }
 
 struct BoxedConditionHelper(BoxedCondition);
@@ -506,3 +1192,48 @@ impl System for BoxedConditionHelper {
         self.0.set_last_run(last_run)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use bevy_ecs::event::{Event, Events};
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_reflect::Reflect;
+
+    use super::EventLog;
+    use crate::utils::tests::TestUi;
+
+    #[derive(Event, Reflect, Clone)]
+    struct Damage {
+        amount: f32,
+    }
+
+    #[test]
+    fn event_log_lists_sent_events() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<Events<Damage>>();
+        world.insert_resource(EventLog::<Damage> {
+            reader: Default::default(),
+            events: VecDeque::new(),
+            received: 0,
+            max_events: 10,
+        });
+        world.send_event(Damage { amount: 12.5 });
+
+        let mut schedule = Schedule::new();
+        schedule.add_systems(super::record_events::<Damage>);
+        schedule.run(&mut world);
+
+        let mut add_contents =
+            |ui: &mut egui::Ui| super::event_log_ui::<Damage>(&mut world, ui, egui::Id::new("log"));
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("1 events received").is_some());
+        assert!(test_ui.text_position("#0").is_some());
+        assert!(test_ui.text_position("12.5").is_some());
+    }
+}
@@ -0,0 +1,126 @@
+//! Showing the path of the nested header which was expanded last, see [`InspectorUi::with_breadcrumb`](super::InspectorUi::with_breadcrumb).
+
+use egui::collapsing_header::CollapsingState;
+
+/// An expanded collapsing header on the way to the value the user is looking at
+#[derive(Clone)]
+struct Segment {
+    /// Field path from the previous segment to this one, like `inner` or `items[2].inner`
+    label: String,
+    header: egui::Id,
+}
+
+/// Keeps track of the headers which are open around the field currently being displayed
+pub(crate) struct Breadcrumb {
+    id: egui::Id,
+    pub(super) path: String,
+    /// Length of the `path` at each of the open headers containing the current field
+    open: Vec<usize>,
+    /// Segments from a header clicked in this frame outwards, with the nesting depth of the outermost one.
+    ///
+    /// The id of a header is only known once it has been shown, which is after the headers nested in it,
+    /// so the containing headers are added as they finish.
+    clicked: Option<(usize, Vec<Segment>)>,
+}
+
+impl Breadcrumb {
+    pub(super) fn new(id: egui::Id) -> Breadcrumb {
+        Breadcrumb {
+            id: breadcrumb_id(id),
+            path: String::new(),
+            open: Vec::new(),
+            clicked: None,
+        }
+    }
+
+    /// Called before showing the body of a header whose field is at the current `path`
+    pub(super) fn enter(&mut self) {
+        self.open.push(self.path.len());
+    }
+
+    pub(super) fn exit(&mut self) {
+        self.open.pop();
+    }
+
+    /// Called after a header for the field at the current `path` was shown.
+    ///
+    /// Clicking it moves the breadcrumb to the header if it was expanded, or to the header containing it if it was collapsed.
+    pub(super) fn header_shown(&mut self, ui: &egui::Ui, response: &egui::Response) {
+        let depth = self.open.len();
+        let start = self.open.last().copied().unwrap_or(0);
+        let segment = || Segment {
+            label: self.path[start..].trim_start_matches('.').to_owned(),
+            header: response.id,
+        };
+
+        if response.clicked() {
+            let open =
+                CollapsingState::load(ui.ctx(), response.id).is_some_and(|state| state.is_open());
+            self.clicked = Some((depth, open.then(segment).into_iter().collect()));
+        } else if let Some((clicked_depth, segments)) = &mut self.clicked {
+            if *clicked_depth == depth + 1 {
+                segments.insert(0, segment());
+                *clicked_depth = depth;
+            }
+        }
+
+        if let Some((0, _)) = self.clicked {
+            let (_, segments) = self.clicked.take().unwrap();
+            ui.data_mut(|data| data.insert_temp(self.id, segments));
+        }
+    }
+}
+
+fn breadcrumb_id(id: egui::Id) -> egui::Id {
+    id.with("breadcrumb")
+}
+
+/// Strip of links like `Transform > rotation > x` for the value displayed by an [`InspectorUi`](super::InspectorUi) created
+/// [`with_breadcrumb`](super::InspectorUi::with_breadcrumb) using the same `id`, starting with the `root` label.
+///
+/// It shows the path to the nested header which was expanded last. Clicking an earlier part of it collapses
+/// the headers below that part. Nothing is shown while no nested header is open.
+pub fn breadcrumb_ui(ui: &mut egui::Ui, id: egui::Id, root: &str) {
+    let id = breadcrumb_id(id);
+    let Some(mut segments) = ui.data_mut(|data| data.get_temp::<Vec<Segment>>(id)) else {
+        return;
+    };
+    // headers collapsed some other way end the breadcrumb
+    let open = segments
+        .iter()
+        .take_while(|segment| {
+            CollapsingState::load(ui.ctx(), segment.header).is_some_and(|state| state.is_open())
+        })
+        .count();
+    segments.truncate(open);
+    if segments.is_empty() {
+        return;
+    }
+
+    let mut collapse_below = None;
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+        if ui.link(root).clicked() {
+            collapse_below = Some(0);
+        }
+        for (i, segment) in segments.iter().enumerate() {
+            ui.weak(">");
+            if i + 1 == segments.len() {
+                ui.label(&segment.label);
+            } else if ui.link(&segment.label).clicked() {
+                collapse_below = Some(i + 1);
+            }
+        }
+    });
+
+    if let Some(len) = collapse_below {
+        for segment in &segments[len..] {
+            if let Some(mut state) = CollapsingState::load(ui.ctx(), segment.header) {
+                state.set_open(false);
+                state.store(ui.ctx());
+            }
+        }
+        segments.truncate(len);
+    }
+    ui.data_mut(|data| data.insert_temp(id, segments));
+}
@@ -0,0 +1,33 @@
+//! Finding the values which differ from the default value of their type, see [`InspectorUi::with_default_highlight`](super::InspectorUi::with_default_highlight).
+
+use std::sync::Arc;
+
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeRegistry};
+
+/// The [`ReflectDefault`] value of the type represented by `value`, which is only created once per [`egui::Context`]
+pub(crate) fn cached_default(
+    ui: &egui::Ui,
+    type_registry: &TypeRegistry,
+    value: &dyn Reflect,
+) -> Option<Arc<dyn Reflect>> {
+    let type_id = value.get_represented_type_info()?.type_id();
+    let reflect_default = type_registry.get_type_data::<ReflectDefault>(type_id)?;
+
+    let id = egui::Id::new(("default value", type_id));
+    if let Some(default) = ui.data(|data| data.get_temp::<Arc<dyn Reflect>>(id)) {
+        return Some(default);
+    }
+    let default: Arc<dyn Reflect> = Arc::from(reflect_default.default());
+    ui.data_mut(|data| data.insert_temp(id, Arc::clone(&default)));
+    Some(default)
+}
+
+/// Whether the `value` is known to differ from the default value of its type
+pub(crate) fn differs_from_default(
+    ui: &egui::Ui,
+    type_registry: &TypeRegistry,
+    value: &dyn Reflect,
+) -> bool {
+    cached_default(ui, type_registry, value)
+        .is_some_and(|default| value.reflect_partial_eq(&*default) == Some(false))
+}
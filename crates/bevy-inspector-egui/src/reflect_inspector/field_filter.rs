@@ -0,0 +1,130 @@
+//! Hiding the fields which don't match a search text, see [`InspectorUi::with_field_filter`](super::InspectorUi::with_field_filter).
+
+use bevy_reflect::{Reflect, ReflectRef};
+
+use super::FieldPathSegment;
+
+/// Lowercase search text together with the path of the field currently being displayed
+pub(crate) struct FieldFilter {
+    query: String,
+    pub(super) path: String,
+}
+
+impl FieldFilter {
+    /// `None` if the `query` is blank, since that wouldn't hide anything
+    pub(super) fn new(query: &str) -> Option<FieldFilter> {
+        let query = query.trim();
+        (!query.is_empty()).then(|| FieldFilter {
+            query: query.to_lowercase(),
+            path: String::new(),
+        })
+    }
+
+    /// Whether the `value` at the current path should be shown, because its path or the path of any field nested in it matches.
+    ///
+    /// Paths only grow while descending, so everything inside of a matching field matches as well.
+    pub(super) fn matches(&self, value: &dyn Reflect) -> bool {
+        any_field_path(value, &mut self.path.clone(), &mut |path| {
+            path.to_lowercase().contains(&self.query)
+        })
+    }
+}
+
+/// Whether the `value` has at least `min` fields, counting nested fields and collection elements as well.
+/// Stops counting once `min` is reached.
+pub(crate) fn has_fields(value: &dyn Reflect, min: usize) -> bool {
+    let mut count = 0;
+    any_field_path(value, &mut String::new(), &mut |path| {
+        count += usize::from(!path.is_empty());
+        count >= min
+    })
+}
+
+/// Calls `f` with the `path` of the `value` and then with the paths of its fields recursively, until it returns `true`.
+///
+/// The paths have the same syntax as in [`ui_for_value_with_callback`](super::ui_for_value_with_callback).
+/// The entries of maps aren't visited, so maps count as a single field.
+fn any_field_path(value: &dyn Reflect, path: &mut String, f: &mut dyn FnMut(&str) -> bool) -> bool {
+    if f(path) {
+        return true;
+    }
+
+    let mut field = |path: &mut String, segment: FieldPathSegment<'_>, field: &dyn Reflect| {
+        let len = segment.push_to(path);
+        let found = any_field_path(field, path, f);
+        path.truncate(len);
+        found
+    };
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len()).any(|i| {
+            let segment = FieldPathSegment::Field(value.name_at(i).unwrap());
+            field(path, segment, value.field_at(i).unwrap())
+        }),
+        ReflectRef::TupleStruct(value) => (0..value.field_len()).any(|i| {
+            field(
+                path,
+                FieldPathSegment::TupleIndex(i),
+                value.field(i).unwrap(),
+            )
+        }),
+        ReflectRef::Tuple(value) => (0..value.field_len()).any(|i| {
+            field(
+                path,
+                FieldPathSegment::TupleIndex(i),
+                value.field(i).unwrap(),
+            )
+        }),
+        ReflectRef::List(value) => value
+            .iter()
+            .enumerate()
+            .any(|(i, item)| field(path, FieldPathSegment::ListIndex(i), item)),
+        ReflectRef::Array(value) => value
+            .iter()
+            .enumerate()
+            .any(|(i, item)| field(path, FieldPathSegment::ListIndex(i), item)),
+        ReflectRef::Enum(value) => (0..value.field_len()).any(|i| {
+            let segment = match value.name_at(i) {
+                Some(name) => FieldPathSegment::Field(name),
+                None => FieldPathSegment::TupleIndex(i),
+            };
+            field(path, segment, value.field_at(i).unwrap())
+        }),
+        ReflectRef::Map(_) | ReflectRef::Value(_) => false,
+    }
+}
+
+/// Text field for the search text of a [`with_field_filter`](super::InspectorUi::with_field_filter) call, which is
+/// remembered under the `id`. Returns the current search text.
+///
+/// If no field of the `value` matches, a note is shown below the text field.
+/// Whether any field matches is only checked again once the search text changes.
+pub fn field_filter_ui(ui: &mut egui::Ui, id: egui::Id, value: &dyn Reflect) -> String {
+    let filter_id = id.with("field filter");
+    let mut query = ui.data_mut(|data| data.get_temp::<String>(filter_id).unwrap_or_default());
+    let response = ui.add(
+        egui::TextEdit::singleline(&mut query)
+            .id(filter_id)
+            .hint_text("Filter fields")
+            .desired_width(f32::INFINITY),
+    );
+    if response.changed() {
+        ui.data_mut(|data| data.insert_temp(filter_id, query.clone()));
+    }
+
+    let Some(filter) = FieldFilter::new(&query) else {
+        return query;
+    };
+    let matches_id = filter_id.with("matches");
+    let matches = match ui.data(|data| data.get_temp::<(String, bool)>(matches_id)) {
+        Some((cached_query, matches)) if cached_query == query => matches,
+        _ => {
+            let matches = filter.matches(value);
+            ui.data_mut(|data| data.insert_temp(matches_id, (query.clone(), matches)));
+            matches
+        }
+    };
+    if !matches {
+        ui.weak("No matching fields");
+    }
+    query
+}
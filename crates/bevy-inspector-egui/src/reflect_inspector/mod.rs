@@ -1,5 +1,11 @@
 //! General-purpose machinery for displaying [`Reflect`](bevy_reflect::Reflect) types using [`egui`]
 //!
+//! Walking and displaying values in this module only needs an [`egui::Ui`] and a [`TypeRegistry`].
+//! Everything depending on the bevy `World` happens in the [`InspectorEguiImpl`]s of types like `Entity`
+//! and in the short circuiting functions of [`bevy_inspector`](crate::bevy_inspector::short_circuit),
+//! which read it from the [`Context`]. See [`register_default_config`](crate::register_default_config)
+//! for filling a registry without an `App`.
+//!
 //! # Examples
 //! **Basic usage**
 //! ```rust
@@ -59,21 +65,37 @@
 //! }
 //! ```
 
+use crate::egui_utils;
 use crate::inspector_egui_impls::{iter_all_eq, InspectorEguiImpl};
 use crate::inspector_options::{InspectorOptions, ReflectInspectorOptions, Target};
 use crate::restricted_world_view::RestrictedWorldView;
 use bevy_ecs::system::CommandQueue;
 use bevy_reflect::{std_traits::ReflectDefault, DynamicStruct};
 use bevy_reflect::{
-    Array, DynamicEnum, DynamicTuple, DynamicVariant, Enum, EnumInfo, List, ListInfo, Map, Reflect,
-    ReflectMut, ReflectRef, Struct, StructInfo, Tuple, TupleInfo, TupleStruct, TupleStructInfo,
-    TypeInfo, TypeRegistry, ValueInfo, VariantInfo, VariantType,
+    Array, ArrayInfo, DynamicEnum, DynamicTuple, DynamicVariant, Enum, EnumInfo, List, ListInfo,
+    Map, Reflect, ReflectMut, ReflectRef, Struct, StructInfo, Tuple, TupleInfo, TupleStruct,
+    TupleStructInfo, TypeInfo, TypeRegistry, ValueInfo, VariantInfo, VariantType,
 };
+use breadcrumb::Breadcrumb;
 use egui::Grid;
+use field_filter::FieldFilter;
 use std::any::{Any, TypeId};
 use std::borrow::Cow;
+use std::fmt::Write;
 
+mod breadcrumb;
+pub(crate) mod default_diff;
 pub(crate) mod errors;
+pub(crate) mod field_filter;
+mod serialized;
+mod text;
+mod validation;
+
+pub use breadcrumb::breadcrumb_ui;
+pub use field_filter::field_filter_ui;
+pub(crate) use text::value_summary;
+pub use text::value_to_text;
+pub use validation::{InspectorValidator, RegisterInspectorValidator};
 
 /// Display the value without any [`Context`] or short circuiting behaviour.
 /// This means that for example bevy's `Handle<StandardMaterial>` values cannot be displayed,
@@ -99,6 +121,28 @@ pub fn ui_for_value_readonly(value: &dyn Reflect, ui: &mut egui::Ui, type_regist
         .ui_for_reflect_readonly(value, ui);
 }
 
+/// Like [`ui_for_value`], but calls `on_change` with the new value and its path for every field that was changed.
+///
+/// The path uses the syntax of [`GetPath`](bevy_reflect::GetPath), e.g. `transform.translation.x` or `items[2].0`,
+/// and is empty if the `value` itself was changed. Map entries are written as the `Debug` output of their key, like `counts["apples"]`,
+/// which `GetPath` can't follow.
+pub fn ui_for_value_with_callback(
+    value: &mut dyn Reflect,
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+    mut on_change: impl FnMut(&dyn Reflect, &str),
+) -> bool {
+    InspectorUi::new_no_short_circuit(type_registry, &mut Context::default())
+        .with_field_changed_callback(&mut on_change)
+        .ui_for_reflect(value, ui)
+}
+
+/// The parts of the bevy `World` available to an [`InspectorUi`].
+///
+/// Walking and displaying reflected values never needs them, so an empty `Context::default()` works for anything
+/// which doesn't live in the world. Only the [`InspectorEguiImpl`]s and short circuiting functions of types referring
+/// into the world, like `Entity` or asset handles, read the `world` or push commands to the `queue`,
+/// and show an error instead of the value if they're missing.
 #[derive(Default)]
 pub struct Context<'a> {
     pub world: Option<RestrictedWorldView<'a>>,
@@ -145,6 +189,7 @@ pub type ShortCircuitFnMany = fn(
     projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
 ) -> Option<bool>;
 
+/// Created with [`InspectorUi::new`] and configured with its `with_*` methods, since some of its fields are private.
 pub struct InspectorUi<'a, 'c> {
     /// Reference to the [`TypeRegistry`]
     pub type_registry: &'a TypeRegistry,
@@ -157,8 +202,63 @@ pub struct InspectorUi<'a, 'c> {
     /// Same as [`short_circuit`](InspectorUi::short_circuit), but for read only usage.
     pub short_circuit_readonly: ShortCircuitFnReadonly,
     pub short_circuit_many: ShortCircuitFnMany,
+
+    /// Maximum number of list or map elements to display at once.
+    /// The remaining elements can be revealed page by page using a button below the collection.
+    pub max_collection_items: usize,
+    /// Maximum nesting depth of values to display. Values nested deeper are replaced by a "… (max depth)" label,
+    /// which guards against runaway recursion through data which refers to itself.
+    pub max_depth: usize,
+    /// Depth of the value currently being displayed
+    pub(crate) depth: usize,
+
+    /// Set using [`InspectorUi::with_field_changed_callback`]
+    pub(crate) changed_fields: Option<ChangedFields<'a>>,
+    /// Set using [`InspectorUi::with_field_filter`]
+    pub(crate) field_filter: Option<FieldFilter>,
+    /// Set using [`InspectorUi::with_breadcrumb`]
+    pub(crate) breadcrumb: Option<Breadcrumb>,
+    /// Set using [`InspectorUi::with_default_highlight`]
+    pub(crate) highlight_non_default: bool,
+}
+
+/// Keeps track of the path of the field currently being displayed, to report changes to it
+pub(crate) struct ChangedFields<'a> {
+    callback: &'a mut dyn FnMut(&dyn Reflect, &str),
+    path: String,
+    /// Number of changes reported so far, used to only report the innermost changed field
+    reported: usize,
+}
+
+enum FieldPathSegment<'s> {
+    Field(&'s str),
+    TupleIndex(usize),
+    ListIndex(usize),
+    /// Map entries, written as the `Debug` output of their key
+    MapKey(&'s dyn Reflect),
+}
+
+impl FieldPathSegment<'_> {
+    /// Appends the segment to the `path` and returns its previous length
+    fn push_to(&self, path: &mut String) -> usize {
+        let len = path.len();
+        let separator = if len == 0 { "" } else { "." };
+        match self {
+            FieldPathSegment::Field(name) => write!(path, "{separator}{name}"),
+            FieldPathSegment::TupleIndex(i) => write!(path, "{separator}{i}"),
+            FieldPathSegment::ListIndex(i) => write!(path, "[{i}]"),
+            FieldPathSegment::MapKey(key) => write!(path, "[{key:?}]"),
+        }
+        .unwrap();
+        len
+    }
 }
 
+/// Default value of [`InspectorUi::max_collection_items`]
+pub const DEFAULT_MAX_COLLECTION_ITEMS: usize = 100;
+/// Default value of [`InspectorUi::max_depth`]
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
 impl<'a, 'c> InspectorUi<'a, 'c> {
     pub fn new(
         type_registry: &'a TypeRegistry,
@@ -173,6 +273,13 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
             short_circuit: short_circuit.unwrap_or(|_, _, _, _, _| None),
             short_circuit_readonly: short_circuit_readonly.unwrap_or(|_, _, _, _, _| None),
             short_circuit_many: short_circuit_many.unwrap_or(|_, _, _, _, _, _, _, _| None),
+            max_collection_items: DEFAULT_MAX_COLLECTION_ITEMS,
+            max_depth: DEFAULT_MAX_DEPTH,
+            depth: 0,
+            changed_fields: None,
+            field_filter: None,
+            breadcrumb: None,
+            highlight_non_default: false,
         }
     }
 
@@ -182,6 +289,66 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
     ) -> Self {
         InspectorUi::new(type_registry, context, None, None, None)
     }
+
+    /// Calls `callback` with the new value and its path for every field that was changed through this [`InspectorUi`].
+    ///
+    /// See [`ui_for_value_with_callback`] for the path syntax.
+    /// Fields displayed by custom [`InspectorEguiImpl`]s or short circuiting functions are reported as a whole.
+    pub fn with_field_changed_callback(
+        mut self,
+        callback: &'a mut dyn FnMut(&dyn Reflect, &str),
+    ) -> Self {
+        self.changed_fields = Some(ChangedFields {
+            callback,
+            path: String::new(),
+            reported: 0,
+        });
+        self
+    }
+
+    /// Only shows the fields whose path contains `filter`, ignoring case, together with the fields containing them.
+    /// Everything nested inside of a matching field is shown as well, and a blank `filter` shows all fields.
+    ///
+    /// See [`ui_for_value_with_callback`] for the path syntax, e.g. `window.width` or `items[2].name`.
+    /// Fields displayed by custom [`InspectorEguiImpl`]s or short circuiting functions are shown as a whole if they match,
+    /// and multi-editing with [`InspectorUi::ui_for_reflect_many`] isn't filtered.
+    ///
+    /// [`field_filter_ui`] shows a text field for the filter.
+    pub fn with_field_filter(mut self, filter: &str) -> Self {
+        self.field_filter = FieldFilter::new(filter);
+        self
+    }
+
+    /// Remembers the path to the nested header which was expanded last, to be shown by [`breadcrumb_ui`] with the same `id`.
+    ///
+    /// Only the collapsing headers of nested fields in the `#[inspector(grid)]` layout are part of the path,
+    /// and headers inside of values displayed by custom [`InspectorEguiImpl`]s or short circuiting functions aren't tracked.
+    pub fn with_breadcrumb(mut self, id: egui::Id) -> Self {
+        self.breadcrumb = Some(Breadcrumb::new(id));
+        self
+    }
+
+    /// Shows the names of struct fields which differ from the field of the struct's [`ReflectDefault`] value in bold,
+    /// and puts a `•` after the collapsing headers of such fields in the `#[inspector(grid)]` layout.
+    ///
+    /// Fields are compared using [`Reflect::reflect_partial_eq`], so fields which can't be compared aren't highlighted,
+    /// and neither are the fields of structs without a registered `ReflectDefault`.
+    pub fn with_default_highlight(mut self, highlight: bool) -> Self {
+        self.highlight_non_default = highlight;
+        self
+    }
+
+    /// Sets [`InspectorUi::max_collection_items`]
+    pub fn with_max_collection_items(mut self, max_collection_items: usize) -> Self {
+        self.max_collection_items = max_collection_items;
+        self
+    }
+
+    /// Sets [`InspectorUi::max_depth`]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
 }
 
 impl InspectorUi<'_, '_> {
@@ -195,6 +362,52 @@ impl InspectorUi<'_, '_> {
         self.ui_for_reflect_readonly_with_options(value, ui, egui::Id::null(), &());
     }
 
+    /// Draws the inspector UI for the given value, deriving the ids of all widgets from `id_salt`.
+    ///
+    /// egui keeps state like the open state of collapsing headers or the keyboard focus by widget id.
+    /// [`ui_for_reflect`](InspectorUi::ui_for_reflect) derives these ids from [`egui::Id::null`], so two inspectors
+    /// in the same [`egui::Context`] would share their state or get id clashes.
+    /// Use a different salt for every inspector which is shown at the same time, for example when displaying values in
+    /// tooltips or [`egui::Area`]s on their own layer:
+    ///
+    /// ```rust,no_run
+    /// # use bevy_inspector_egui::reflect_inspector::{Context, InspectorUi};
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// fn floating_inspector(ctx: &egui::Context, value: &mut dyn Reflect, type_registry: &TypeRegistry) {
+    ///     egui::Area::new("floating inspector")
+    ///         .order(egui::Order::Foreground)
+    ///         .show(ctx, |ui| {
+    ///             let mut cx = Context::default();
+    ///             InspectorUi::new_no_short_circuit(type_registry, &mut cx)
+    ///                 .ui_for_reflect_with_id_salt(value, ui, "floating inspector");
+    ///         });
+    /// }
+    /// ```
+    pub fn ui_for_reflect_with_id_salt(
+        &mut self,
+        value: &mut dyn Reflect,
+        ui: &mut egui::Ui,
+        id_salt: impl std::hash::Hash,
+    ) -> bool {
+        let id = egui::Id::new(id_salt);
+        egui_utils::with_stable_ids(ui, id, |ui| {
+            self.ui_for_reflect_with_options(value, ui, id, &())
+        })
+    }
+
+    /// Read-only version of [`ui_for_reflect_with_id_salt`](InspectorUi::ui_for_reflect_with_id_salt).
+    pub fn ui_for_reflect_readonly_with_id_salt(
+        &mut self,
+        value: &dyn Reflect,
+        ui: &mut egui::Ui,
+        id_salt: impl std::hash::Hash,
+    ) {
+        let id = egui::Id::new(id_salt);
+        egui_utils::with_stable_ids(ui, id, |ui| {
+            self.ui_for_reflect_readonly_with_options(value, ui, id, &());
+        });
+    }
+
     /// Draws the inspector UI for the given value with some options.
     ///
     /// The options can be [`struct@InspectorOptions`] for structs or enums with nested options for their fields,
@@ -206,6 +419,86 @@ impl InspectorUi<'_, '_> {
         ui: &mut egui::Ui,
         id: egui::Id,
         options: &dyn Any,
+    ) -> bool {
+        if self.max_depth_reached(ui) {
+            return false;
+        }
+        if let Some(validator) = self
+            .type_registry
+            .get_type_data::<InspectorValidator>(Any::type_id(value))
+        {
+            return self.ui_for_reflect_validated(*validator, value, ui, id, options);
+        }
+        let reported_before = self.changed_fields.as_ref().map(|fields| fields.reported);
+        self.depth += 1;
+        let changed = self.ui_for_reflect_with_options_inner(value, ui, id, options);
+        self.depth -= 1;
+        if let Some(fields) = &mut self.changed_fields {
+            if changed && reported_before == Some(fields.reported) {
+                fields.reported += 1;
+                (fields.callback)(value, &fields.path);
+            }
+        }
+        changed
+    }
+
+    /// Like [`InspectorUi::ui_for_reflect_with_options`], but undoes the edit if the `validator` rejects it
+    fn ui_for_reflect_validated(
+        &mut self,
+        validator: InspectorValidator,
+        value: &mut dyn Reflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) -> bool {
+        // edits can only be undone if there was input which could have caused them
+        let before = egui_utils::input_may_edit(ui).then(|| value.clone_value());
+        let error_id = id.with("validation error");
+        // nested fields are only reported once the edit is accepted, as a change of the whole value
+        let mut changed_fields = self.changed_fields.take();
+
+        let mut changed = false;
+        ui.vertical(|ui| {
+            self.depth += 1;
+            changed = self.ui_for_reflect_with_options_inner(value, ui, id, options);
+            self.depth -= 1;
+
+            let result = changed.then(|| validator.validate(value));
+            match result {
+                Some(Ok(())) => ui.data_mut(|data| data.remove::<String>(error_id)),
+                Some(Err(error)) => {
+                    if let Some(before) = &before {
+                        validation::restore(value, &**before, self.type_registry);
+                        changed = false;
+                    }
+                    ui.data_mut(|data| data.insert_temp(error_id, error));
+                }
+                None if ui.input(|input| input.pointer.any_pressed()) => {
+                    ui.data_mut(|data| data.remove::<String>(error_id));
+                }
+                None => {}
+            }
+            if let Some(error) = ui.data(|data| data.get_temp::<String>(error_id)) {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        });
+
+        if let Some(fields) = &mut changed_fields {
+            if changed {
+                fields.reported += 1;
+                (fields.callback)(value, &fields.path);
+            }
+        }
+        self.changed_fields = changed_fields;
+        changed
+    }
+
+    fn ui_for_reflect_with_options_inner(
+        &mut self,
+        value: &mut dyn Reflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
     ) -> bool {
         let mut options = options;
         if options.is::<()>() {
@@ -221,7 +514,10 @@ impl InspectorUi<'_, '_> {
             .type_registry
             .get_type_data::<InspectorEguiImpl>(Any::type_id(value))
         {
-            return s.execute(value.as_any_mut(), ui, options, id, self.reborrow());
+            let env = self.reborrow();
+            return egui_utils::with_stable_ids(ui, id, |ui| {
+                s.execute(value.as_any_mut(), ui, options, id, env)
+            });
         }
 
         if let Some(changed) = (self.short_circuit)(self, value, ui, id, options) {
@@ -251,6 +547,21 @@ impl InspectorUi<'_, '_> {
         ui: &mut egui::Ui,
         id: egui::Id,
         options: &dyn Any,
+    ) {
+        if self.max_depth_reached(ui) {
+            return;
+        }
+        self.depth += 1;
+        self.ui_for_reflect_readonly_with_options_inner(value, ui, id, options);
+        self.depth -= 1;
+    }
+
+    fn ui_for_reflect_readonly_with_options_inner(
+        &mut self,
+        value: &dyn Reflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
     ) {
         let mut options = options;
         if options.is::<()>() {
@@ -309,6 +620,27 @@ impl InspectorUi<'_, '_> {
         options: &dyn Any,
         values: &mut [&mut dyn Reflect],
         projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+    ) -> bool {
+        if self.max_depth_reached(ui) {
+            return false;
+        }
+        self.depth += 1;
+        let changed = self.ui_for_reflect_many_with_options_inner(
+            type_id, name, ui, id, options, values, projector,
+        );
+        self.depth -= 1;
+        changed
+    }
+
+    fn ui_for_reflect_many_with_options_inner(
+        &mut self,
+        type_id: TypeId,
+        name: &str,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        values: &mut [&mut dyn Reflect],
+        projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
     ) -> bool {
         let Some(registration) = self.type_registry.get(type_id) else {
             errors::not_in_type_registry(ui, name);
@@ -330,7 +662,10 @@ impl InspectorUi<'_, '_> {
             .type_registry
             .get_type_data::<InspectorEguiImpl>(type_id)
         {
-            return s.execute_many(ui, options, id, self.reborrow(), values, projector);
+            let env = self.reborrow();
+            return egui_utils::with_stable_ids(ui, id, |ui| {
+                s.execute_many(ui, options, id, env, values, projector)
+            });
         }
 
         if let Some(changed) =
@@ -351,11 +686,7 @@ impl InspectorUi<'_, '_> {
             }
             TypeInfo::List(info) => self.ui_for_list_many(info, ui, id, options, values, projector),
             TypeInfo::Array(info) => {
-                errors::no_multiedit(
-                    ui,
-                    &pretty_type_name::pretty_type_name_str(info.type_name()),
-                );
-                false
+                self.ui_for_array_many(info, ui, id, options, values, projector)
             }
             TypeInfo::Map(info) => {
                 errors::no_multiedit(
@@ -378,17 +709,23 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) -> bool {
+        if grid_layout(options) {
+            return self.ui_for_struct_grid(value, ui, id, options);
+        }
+
         let mut changed = false;
         Grid::new(id).show(ui, |ui| {
             for i in 0..value.field_len() {
-                ui.label(value.name_at(i).unwrap());
+                let name = value.name_at(i).unwrap();
+                let path = self.push_field_path(FieldPathSegment::Field(name));
+                if !self.field_visible(value.field_at(i).unwrap()) {
+                    self.pop_field_path(path);
+                    continue;
+                }
+                changed |= self.struct_field_label(ui, value, i, options);
                 let field = value.field_at_mut(i).unwrap();
-                changed |= self.ui_for_reflect_with_options(
-                    field,
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
-                );
+                changed |= self.ui_for_field(field, ui, id.with(i), options, Target::Field(i));
+                self.pop_field_path(path);
                 ui.end_row();
             }
         });
@@ -402,21 +739,145 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        if grid_layout(options) {
+            return self.ui_for_struct_grid_readonly(value, ui, id, options);
+        }
+
         Grid::new(id).show(ui, |ui| {
             for i in 0..value.field_len() {
-                ui.label(value.name_at(i).unwrap());
+                let name = value.name_at(i).unwrap();
                 let field = value.field_at(i).unwrap();
+                let path = self.push_field_path(FieldPathSegment::Field(name));
+                if !self.field_visible(field) {
+                    self.pop_field_path(path);
+                    continue;
+                }
+                let help = field_help(options, Target::Field(i), value.get_represented_type_info());
+                field_label(ui, self.field_name(ui, value, i), help);
                 self.ui_for_reflect_readonly_with_options(
                     field,
                     ui,
                     id.with(i),
                     inspector_options_struct_field(options, i),
                 );
+                self.pop_field_path(path);
                 ui.end_row();
             }
         });
     }
 
+    /// `#[inspector(grid)]` layout: primitive fields are packed into [`GRID_COLUMNS`] label/value pairs per row,
+    /// everything else is shown in a collapsing header below the grid.
+    fn ui_for_struct_grid(
+        &mut self,
+        value: &mut dyn Struct,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) -> bool {
+        let (primitive, nested): (Vec<usize>, Vec<usize>) = self
+            .visible_struct_fields(value)
+            .into_iter()
+            .partition(|&i| self.is_primitive(value.field_at(i).unwrap()));
+
+        let mut changed = false;
+        Grid::new(id).num_columns(GRID_COLUMNS * 2).show(ui, |ui| {
+            for (n, i) in primitive.into_iter().enumerate() {
+                let name = value.name_at(i).unwrap();
+                let path = self.push_field_path(FieldPathSegment::Field(name));
+                changed |= self.struct_field_label(ui, value, i, options);
+                let field = value.field_at_mut(i).unwrap();
+                changed |= self.ui_for_field(field, ui, id.with(i), options, Target::Field(i));
+                self.pop_field_path(path);
+                if (n + 1) % GRID_COLUMNS == 0 {
+                    ui.end_row();
+                }
+            }
+        });
+        for i in nested {
+            let help = field_help(options, Target::Field(i), value.get_represented_type_info());
+            let name = value.name_at(i).unwrap().to_owned();
+            let modified = self.field_modified(ui, value, i);
+            let summary = self.collapsed_summary(ui, id.with(i), value.field_at(i).unwrap());
+            let header = NestedHeader {
+                name: &name,
+                modified,
+                summary,
+            };
+            let response = self.nested_field_header(ui, header, id.with(i), |env, ui| {
+                let field = value.field_at_mut(i).unwrap();
+                changed |= env.ui_for_field(field, ui, id.with(i), options, Target::Field(i));
+            });
+            if let Some(help) = help {
+                response.on_hover_text(help);
+            }
+        }
+        changed
+    }
+
+    fn ui_for_struct_grid_readonly(
+        &mut self,
+        value: &dyn Struct,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) {
+        let (primitive, nested): (Vec<usize>, Vec<usize>) = self
+            .visible_struct_fields(value)
+            .into_iter()
+            .partition(|&i| self.is_primitive(value.field_at(i).unwrap()));
+
+        Grid::new(id).num_columns(GRID_COLUMNS * 2).show(ui, |ui| {
+            for (n, i) in primitive.into_iter().enumerate() {
+                let name = value.name_at(i).unwrap();
+                let path = self.push_field_path(FieldPathSegment::Field(name));
+                let help = field_help(options, Target::Field(i), value.get_represented_type_info());
+                field_label(ui, self.field_name(ui, value, i), help);
+                self.ui_for_reflect_readonly_with_options(
+                    value.field_at(i).unwrap(),
+                    ui,
+                    id.with(i),
+                    inspector_options_struct_field(options, i),
+                );
+                self.pop_field_path(path);
+                if (n + 1) % GRID_COLUMNS == 0 {
+                    ui.end_row();
+                }
+            }
+        });
+        for i in nested {
+            let help = field_help(options, Target::Field(i), value.get_represented_type_info());
+            let name = value.name_at(i).unwrap();
+            let modified = self.field_modified(ui, value, i);
+            let summary = self.collapsed_summary(ui, id.with(i), value.field_at(i).unwrap());
+            let header = NestedHeader {
+                name,
+                modified,
+                summary,
+            };
+            let response = self.nested_field_header(ui, header, id.with(i), |env, ui| {
+                env.ui_for_reflect_readonly_with_options(
+                    value.field_at(i).unwrap(),
+                    ui,
+                    id.with(i),
+                    inspector_options_struct_field(options, i),
+                );
+            });
+            if let Some(help) = help {
+                response.on_hover_text(help);
+            }
+        }
+    }
+
+    /// Whether the value is displayed by a single widget, as opposed to being made up of nested fields
+    fn is_primitive(&self, value: &dyn Reflect) -> bool {
+        matches!(value.reflect_ref(), ReflectRef::Value(_))
+            || self
+                .type_registry
+                .get_type_data::<InspectorEguiImpl>(Any::type_id(value))
+                .is_some()
+    }
+
     fn ui_for_struct_many(
         &mut self,
         info: &StructInfo,
@@ -429,19 +890,29 @@ impl InspectorUi<'_, '_> {
         let mut changed = false;
         Grid::new(id).show(ui, |ui| {
             for (i, field) in info.iter().enumerate() {
-                ui.label(field.name());
-                changed |= self.ui_for_reflect_many_with_options(
-                    field.type_id(),
-                    field.type_name(),
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
-                    values,
-                    &|a| match projector(a).reflect_mut() {
-                        ReflectMut::Struct(strukt) => strukt.field_at_mut(i).unwrap(),
-                        _ => unreachable!(),
-                    },
+                let help = field_help(
+                    options,
+                    Target::Field(i),
+                    self.type_registry.get_type_info(info.type_id()),
                 );
+                field_label(ui, field.name(), help);
+                let read_only = field_read_only(options, Target::Field(i));
+                changed |= ui
+                    .add_enabled_ui(!read_only, |ui| {
+                        self.ui_for_reflect_many_with_options(
+                            field.type_id(),
+                            field.type_name(),
+                            ui,
+                            id.with(i),
+                            inspector_options_struct_field(options, i),
+                            values,
+                            &|a| match projector(a).reflect_mut() {
+                                ReflectMut::Struct(strukt) => strukt.field_at_mut(i).unwrap(),
+                                _ => unreachable!(),
+                            },
+                        )
+                    })
+                    .inner;
                 ui.end_row();
             }
         });
@@ -458,16 +929,18 @@ impl InspectorUi<'_, '_> {
         maybe_grid(value.field_len(), ui, id, |ui, label| {
             (0..value.field_len())
                 .map(|i| {
+                    let path = self.push_field_path(FieldPathSegment::TupleIndex(i));
+                    if !self.field_visible(value.field(i).unwrap()) {
+                        self.pop_field_path(path);
+                        return false;
+                    }
                     if label {
                         ui.label(i.to_string());
                     }
                     let field = value.field_mut(i).unwrap();
-                    let changed = self.ui_for_reflect_with_options(
-                        field,
-                        ui,
-                        id.with(i),
-                        inspector_options_struct_field(options, i),
-                    );
+                    let changed =
+                        self.ui_for_field(field, ui, id.with(i), options, Target::Field(i));
+                    self.pop_field_path(path);
                     ui.end_row();
                     changed
                 })
@@ -484,16 +957,22 @@ impl InspectorUi<'_, '_> {
     ) {
         maybe_grid_readonly(value.field_len(), ui, id, |ui, label| {
             for i in 0..value.field_len() {
+                let field = value.field(i).unwrap();
+                let path = self.push_field_path(FieldPathSegment::TupleIndex(i));
+                if !self.field_visible(field) {
+                    self.pop_field_path(path);
+                    continue;
+                }
                 if label {
                     ui.label(i.to_string());
                 }
-                let field = value.field(i).unwrap();
                 self.ui_for_reflect_readonly_with_options(
                     field,
                     ui,
                     id.with(i),
                     inspector_options_struct_field(options, i),
                 );
+                self.pop_field_path(path);
                 ui.end_row();
             }
         })
@@ -515,18 +994,23 @@ impl InspectorUi<'_, '_> {
                     if label {
                         ui.label(i.to_string());
                     }
-                    let changed = self.ui_for_reflect_many_with_options(
-                        field.type_id(),
-                        field.type_name(),
-                        ui,
-                        id.with(i),
-                        inspector_options_struct_field(options, i),
-                        values,
-                        &|a| match projector(a).reflect_mut() {
-                            ReflectMut::TupleStruct(strukt) => strukt.field_mut(i).unwrap(),
-                            _ => unreachable!(),
-                        },
-                    );
+                    let read_only = field_read_only(options, Target::Field(i));
+                    let changed = ui
+                        .add_enabled_ui(!read_only, |ui| {
+                            self.ui_for_reflect_many_with_options(
+                                field.type_id(),
+                                field.type_name(),
+                                ui,
+                                id.with(i),
+                                inspector_options_struct_field(options, i),
+                                values,
+                                &|a| match projector(a).reflect_mut() {
+                                    ReflectMut::TupleStruct(strukt) => strukt.field_mut(i).unwrap(),
+                                    _ => unreachable!(),
+                                },
+                            )
+                        })
+                        .inner;
                     ui.end_row();
                     changed
                 })
@@ -544,6 +1028,11 @@ impl InspectorUi<'_, '_> {
         maybe_grid(value.field_len(), ui, id, |ui, label| {
             (0..value.field_len())
                 .map(|i| {
+                    let path = self.push_field_path(FieldPathSegment::TupleIndex(i));
+                    if !self.field_visible(value.field(i).unwrap()) {
+                        self.pop_field_path(path);
+                        return false;
+                    }
                     if label {
                         ui.label(i.to_string());
                     }
@@ -554,6 +1043,7 @@ impl InspectorUi<'_, '_> {
                         id.with(i),
                         inspector_options_struct_field(options, i),
                     );
+                    self.pop_field_path(path);
                     ui.end_row();
                     changed
                 })
@@ -570,16 +1060,22 @@ impl InspectorUi<'_, '_> {
     ) {
         maybe_grid_readonly(value.field_len(), ui, id, |ui, label| {
             for i in 0..value.field_len() {
+                let field = value.field(i).unwrap();
+                let path = self.push_field_path(FieldPathSegment::TupleIndex(i));
+                if !self.field_visible(field) {
+                    self.pop_field_path(path);
+                    continue;
+                }
                 if label {
                     ui.label(i.to_string());
                 }
-                let field = value.field(i).unwrap();
                 self.ui_for_reflect_readonly_with_options(
                     field,
                     ui,
                     id.with(i),
                     inspector_options_struct_field(options, i),
                 );
+                self.pop_field_path(path);
                 ui.end_row();
             }
         });
@@ -633,7 +1129,13 @@ impl InspectorUi<'_, '_> {
             // let mut to_delete = None;
 
             let len = list.len();
-            for i in 0..len {
+            let visible = self.visible_collection_items(ui, id, len);
+            for i in 0..visible {
+                let path = self.push_field_path(FieldPathSegment::ListIndex(i));
+                if !self.field_visible(list.get(i).unwrap()) {
+                    self.pop_field_path(path);
+                    continue;
+                }
                 let val = list.get_mut(i).unwrap();
                 ui.horizontal(|ui| {
                     /*if utils::ui::label_button(ui, "✖", egui::Color32::RED) {
@@ -641,11 +1143,13 @@ impl InspectorUi<'_, '_> {
                     }*/
                     changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
                 });
+                self.pop_field_path(path);
 
-                if i != len - 1 {
+                if i + 1 != visible {
                     ui.separator();
                 }
             }
+            self.show_more_items(ui, id, visible, len);
 
             let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
                 return;
@@ -693,16 +1197,24 @@ impl InspectorUi<'_, '_> {
     ) {
         ui.vertical(|ui| {
             let len = list.len();
-            for i in 0..len {
+            let visible = self.visible_collection_items(ui, id, len);
+            for i in 0..visible {
                 let val = list.get(i).unwrap();
+                let path = self.push_field_path(FieldPathSegment::ListIndex(i));
+                if !self.field_visible(val) {
+                    self.pop_field_path(path);
+                    continue;
+                }
                 ui.horizontal(|ui| {
                     self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options)
                 });
+                self.pop_field_path(path);
 
-                if i != len - 1 {
+                if i + 1 != visible {
                     ui.separator();
                 }
             }
+            self.show_more_items(ui, id, visible, len);
         });
     }
 
@@ -805,15 +1317,20 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         _options: &dyn Any,
     ) -> bool {
-        let changed = false;
+        let mut changed = false;
+        let visible = self.visible_collection_items(ui, id, map.len());
         egui::Grid::new(id).show(ui, |ui| {
-            for (i, (key, value)) in map.iter().enumerate() {
-                self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &());
-                // TODO: iterate over values mutably
-                self.ui_for_reflect_readonly_with_options(value, ui, id.with(i), &());
+            // keys can't be edited in place, since that would change where the entry is stored
+            for i in 0..visible {
+                let (key, value) = map.get_at_mut(i).unwrap();
+                self.ui_for_reflect_readonly_with_options(key, ui, id.with((i, "key")), &());
+                let path = self.push_field_path(FieldPathSegment::MapKey(key));
+                changed |= self.ui_for_reflect_with_options(value, ui, id.with(i), &());
+                self.pop_field_path(path);
                 ui.end_row();
             }
         });
+        self.show_more_items(ui, id, visible, map.len());
 
         changed
     }
@@ -825,13 +1342,17 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         _options: &dyn Any,
     ) {
+        let visible = self.visible_collection_items(ui, id, map.len());
         egui::Grid::new(id).show(ui, |ui| {
-            for (i, (key, value)) in map.iter().enumerate() {
+            for (i, (key, value)) in map.iter().take(visible).enumerate() {
                 self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &());
+                let path = self.push_field_path(FieldPathSegment::MapKey(key));
                 self.ui_for_reflect_readonly_with_options(value, ui, id.with(i), &());
+                self.pop_field_path(path);
                 ui.end_row();
             }
         });
+        self.show_more_items(ui, id, visible, map.len());
     }
 
     fn ui_for_array(
@@ -841,23 +1362,25 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) -> bool {
-        let mut changed = false;
-
-        ui.vertical(|ui| {
-            let len = array.len();
-            for i in 0..len {
-                let val = array.get_mut(i).unwrap();
-                ui.horizontal(|ui| {
-                    changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
-                });
-
-                if i != len - 1 {
-                    ui.separator();
-                }
-            }
-        });
-
-        changed
+        maybe_grid(array.len(), ui, id, |ui, label| {
+            (0..array.len())
+                .map(|i| {
+                    let path = self.push_field_path(FieldPathSegment::ListIndex(i));
+                    if !self.field_visible(array.get(i).unwrap()) {
+                        self.pop_field_path(path);
+                        return false;
+                    }
+                    if label {
+                        ui.label(i.to_string());
+                    }
+                    let val = array.get_mut(i).unwrap();
+                    let changed = self.ui_for_reflect_with_options(val, ui, id.with(i), options);
+                    self.pop_field_path(path);
+                    ui.end_row();
+                    changed
+                })
+                .fold(false, or)
+        })
     }
 
     fn ui_for_array_readonly(
@@ -867,21 +1390,58 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
-        ui.vertical(|ui| {
-            let len = array.len();
-            for i in 0..len {
+        maybe_grid_readonly(array.len(), ui, id, |ui, label| {
+            for i in 0..array.len() {
                 let val = array.get(i).unwrap();
-                ui.horizontal(|ui| {
-                    self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options);
-                });
-
-                if i != len - 1 {
-                    ui.separator();
+                let path = self.push_field_path(FieldPathSegment::ListIndex(i));
+                if !self.field_visible(val) {
+                    self.pop_field_path(path);
+                    continue;
                 }
-            }
+                if label {
+                    ui.label(i.to_string());
+                }
+                self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options);
+                self.pop_field_path(path);
+                ui.end_row();
+            }
         });
     }
 
+    fn ui_for_array_many(
+        &mut self,
+        info: &ArrayInfo,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        values: &mut [&mut dyn Reflect],
+        projector: impl Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+    ) -> bool {
+        maybe_grid(info.capacity(), ui, id, |ui, label| {
+            (0..info.capacity())
+                .map(|i| {
+                    if label {
+                        ui.label(i.to_string());
+                    }
+                    let changed = self.ui_for_reflect_many_with_options(
+                        info.item_type_id(),
+                        info.item_type_name(),
+                        ui,
+                        id.with(i),
+                        options,
+                        values,
+                        &|a| match projector(a).reflect_mut() {
+                            ReflectMut::Array(array) => array.get_mut(i).unwrap(),
+                            _ => unreachable!(),
+                        },
+                    );
+                    ui.end_row();
+                    changed
+                })
+                .fold(false, or)
+        })
+    }
+
     fn ui_for_enum(
         &mut self,
         value: &mut dyn Enum,
@@ -897,12 +1457,20 @@ impl InspectorUi<'_, '_> {
             TypeInfo::Enum(info) => info,
             _ => unreachable!("invalid reflect impl: type info mismatch"),
         };
+        if number_with_unit(options, type_info) {
+            return self.ui_for_number_with_unit(value, ui, id, options, type_info);
+        }
 
         let mut changed = false;
 
         ui.vertical(|ui| {
-            let changed_variant =
-                self.ui_for_enum_variant_select(id, ui, value.variant_index(), type_info);
+            let changed_variant = self.ui_for_enum_variant_select(
+                id,
+                ui,
+                value.variant_index(),
+                type_info,
+                Some(&*value),
+            );
             if let Some((_new_variant, dynamic_enum)) = changed_variant {
                 changed = true;
                 value.apply(&dynamic_enum);
@@ -914,22 +1482,42 @@ impl InspectorUi<'_, '_> {
                 maybe_grid_label_if(value.field_len(), ui, id, always_show_label, |ui, label| {
                     (0..value.field_len())
                         .map(|i| {
+                            let path = self.push_field_path(match value.name_at(i) {
+                                Some(name) => FieldPathSegment::Field(name),
+                                None => FieldPathSegment::TupleIndex(i),
+                            });
+                            if !self.field_visible(value.field_at(i).unwrap()) {
+                                self.pop_field_path(path);
+                                return false;
+                            }
                             if label {
-                                if let Some(name) = value.name_at(i) {
-                                    ui.label(name);
-                                } else {
-                                    ui.label(i.to_string());
+                                let help = field_help(
+                                    options,
+                                    Target::VariantField {
+                                        variant_index,
+                                        field_index: i,
+                                    },
+                                    value.get_represented_type_info(),
+                                );
+                                match value.name_at(i) {
+                                    Some(name) => field_label(ui, name, help),
+                                    None => field_label(ui, i.to_string(), help),
                                 }
                             }
                             let field_value = value
                                 .field_at_mut(i)
                                 .expect("invalid reflect impl: field len");
-                            let changed = self.ui_for_reflect_with_options(
+                            let changed = self.ui_for_field(
                                 field_value,
                                 ui,
                                 id.with(i),
-                                inspector_options_enum_variant_field(options, variant_index, i),
+                                options,
+                                Target::VariantField {
+                                    variant_index,
+                                    field_index: i,
+                                },
                             );
+                            self.pop_field_path(path);
                             ui.end_row();
                             changed
                         })
@@ -940,6 +1528,82 @@ impl InspectorUi<'_, '_> {
         changed
     }
 
+    /// Shows an enum [with a number and a unit](crate::inspector_options::ContainerOptions::number_with_unit) as the number followed by a dropdown for the unit.
+    /// Switching between units keeps the number, and units without one like `Auto` only show the dropdown.
+    fn ui_for_number_with_unit(
+        &mut self,
+        value: &mut dyn Enum,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        info: &EnumInfo,
+    ) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let variant_index = value.variant_index();
+            if let Some(number) = value.field_at_mut(0) {
+                let path = self.push_field_path(FieldPathSegment::TupleIndex(0));
+                let target = Target::VariantField {
+                    variant_index,
+                    field_index: 0,
+                };
+                changed |= self.ui_for_field(number, ui, id.with(0), options, target);
+                self.pop_field_path(path);
+            }
+
+            let mut selected = variant_index;
+            egui::ComboBox::new(id.with("unit"), "")
+                .selected_text(value.variant_name())
+                .show_ui(ui, |ui| {
+                    for (i, variant) in info.iter().enumerate() {
+                        ui.selectable_value(&mut selected, i, variant.name());
+                    }
+                });
+            if selected == variant_index {
+                return;
+            }
+
+            let number = value
+                .field_at(0)
+                .and_then(|number| number.downcast_ref::<f32>());
+            let variant = info.variant_at(selected).unwrap();
+            let dynamic_variant = match variant {
+                VariantInfo::Tuple(_) => {
+                    let mut tuple = DynamicTuple::default();
+                    tuple.insert(number.copied().unwrap_or_default());
+                    DynamicVariant::Tuple(tuple)
+                }
+                _ => DynamicVariant::Unit,
+            };
+            value.apply(&DynamicEnum::new(variant.name(), dynamic_variant));
+            changed = true;
+        });
+        changed
+    }
+
+    fn ui_for_number_with_unit_readonly(
+        &mut self,
+        value: &dyn Enum,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) {
+        ui.horizontal(|ui| {
+            if let Some(number) = value.field_at(0) {
+                let path = self.push_field_path(FieldPathSegment::TupleIndex(0));
+                let field_options =
+                    inspector_options_enum_variant_field(options, value.variant_index(), 0);
+                self.ui_for_reflect_readonly_with_options(number, ui, id.with(0), field_options);
+                self.pop_field_path(path);
+            }
+            ui.add_enabled_ui(false, |ui| {
+                egui::ComboBox::new(id.with("unit"), "")
+                    .selected_text(value.variant_name())
+                    .show_ui(ui, |_| {})
+            });
+        });
+    }
+
     fn ui_for_enum_many(
         &mut self,
         info: &EnumInfo,
@@ -965,7 +1629,8 @@ impl InspectorUi<'_, '_> {
             let mut variant = info.variant_at(variant_index).unwrap();
 
             ui.vertical(|ui| {
-                let variant_changed = self.ui_for_enum_variant_select(id, ui, variant_index, info);
+                let variant_changed =
+                    self.ui_for_enum_variant_select(id, ui, variant_index, info, None);
                 if let Some((new_variant_idx, dynamic_enum)) = variant_changed {
                     changed = true;
                     variant = info.variant_at(new_variant_idx).unwrap();
@@ -1000,19 +1665,28 @@ impl InspectorUi<'_, '_> {
                                 })
                                 .collect();
 
-                            self.ui_for_reflect_many_with_options(
-                                field_type_id,
-                                field_type_name,
-                                ui,
-                                id.with(field_index),
-                                inspector_options_enum_variant_field(
-                                    options,
+                            let read_only = field_read_only(
+                                options,
+                                Target::VariantField {
                                     variant_index,
                                     field_index,
-                                ),
-                                variants_across.as_mut_slice(),
-                                &|a| a,
+                                },
                             );
+                            ui.add_enabled_ui(!read_only, |ui| {
+                                self.ui_for_reflect_many_with_options(
+                                    field_type_id,
+                                    field_type_name,
+                                    ui,
+                                    id.with(field_index),
+                                    inspector_options_enum_variant_field(
+                                        options,
+                                        variant_index,
+                                        field_index,
+                                    ),
+                                    variants_across.as_mut_slice(),
+                                    &|a| a,
+                                );
+                            });
 
                             ui.end_row();
 
@@ -1057,12 +1731,16 @@ impl InspectorUi<'_, '_> {
         changed
     }
 
+    /// Variant dropdown, returning the newly selected variant.
+    ///
+    /// If the `current` value is given, fields of the new variant with the same name and type keep their values.
     fn ui_for_enum_variant_select(
         &mut self,
         id: egui::Id,
         ui: &mut egui::Ui,
         active_variant_idx: usize,
         info: &bevy_reflect::EnumInfo,
+        current: Option<&dyn Enum>,
     ) -> Option<(usize, DynamicEnum)> {
         let mut changed_variant = None;
 
@@ -1075,7 +1753,7 @@ impl InspectorUi<'_, '_> {
                         let is_active_variant = i == active_variant_idx;
 
                         let variant_is_constructable =
-                            variant_constructable(self.type_registry, variant);
+                            variant_constructable(self.type_registry, variant, current);
 
                         ui.add_enabled_ui(variant_is_constructable.is_ok(), |ui| {
                             let mut variant_label_response =
@@ -1105,7 +1783,7 @@ impl InspectorUi<'_, '_> {
 
                             if variant_label_response.clicked() {
                                 if let Ok(dynamic_enum) =
-                                    self.construct_default_variant(variant, ui)
+                                    self.construct_default_variant(variant, current, ui)
                                 {
                                     changed_variant = Some((i, dynamic_enum));
                                 };
@@ -1127,6 +1805,12 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        if let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() {
+            if number_with_unit(options, info) {
+                return self.ui_for_number_with_unit_readonly(value, ui, id, options);
+            }
+        }
+
         ui.vertical(|ui| {
             let active_variant = value.variant_name();
             ui.add_enabled_ui(false, |ui| {
@@ -1143,21 +1827,37 @@ impl InspectorUi<'_, '_> {
                 always_show_label,
                 |ui, label| {
                     for i in 0..value.field_len() {
+                        let field_value =
+                            value.field_at(i).expect("invalid reflect impl: field len");
+                        let path = self.push_field_path(match value.name_at(i) {
+                            Some(name) => FieldPathSegment::Field(name),
+                            None => FieldPathSegment::TupleIndex(i),
+                        });
+                        if !self.field_visible(field_value) {
+                            self.pop_field_path(path);
+                            continue;
+                        }
                         if label {
-                            if let Some(name) = value.name_at(i) {
-                                ui.label(name);
-                            } else {
-                                ui.label(i.to_string());
+                            let help = field_help(
+                                options,
+                                Target::VariantField {
+                                    variant_index: value.variant_index(),
+                                    field_index: i,
+                                },
+                                value.get_represented_type_info(),
+                            );
+                            match value.name_at(i) {
+                                Some(name) => field_label(ui, name, help),
+                                None => field_label(ui, i.to_string(), help),
                             }
                         }
-                        let field_value =
-                            value.field_at(i).expect("invalid reflect impl: field len");
                         self.ui_for_reflect_readonly_with_options(
                             field_value,
                             ui,
                             id.with(i),
                             inspector_options_enum_variant_field(options, value.variant_index(), i),
                         );
+                        self.pop_field_path(path);
                         ui.end_row();
                     }
                 },
@@ -1169,9 +1869,15 @@ impl InspectorUi<'_, '_> {
         &mut self,
         value: &mut dyn Reflect,
         ui: &mut egui::Ui,
-        _id: egui::Id,
+        id: egui::Id,
         _options: &dyn Any,
     ) -> bool {
+        if let Some(changed) = serialized::serialized_value_ui(value, ui, id, self.type_registry) {
+            return changed;
+        }
+        if debug_value_ui(value, ui) {
+            return false;
+        }
         errors::reflect_value_no_impl(ui, value.type_name());
         false
     }
@@ -1183,6 +1889,12 @@ impl InspectorUi<'_, '_> {
         _id: egui::Id,
         _options: &dyn Any,
     ) {
+        if serialized::serialized_value_ui_readonly(value, ui, self.type_registry) {
+            return;
+        }
+        if debug_value_ui(value, ui) {
+            return;
+        }
         errors::reflect_value_no_impl(ui, value.type_name());
     }
 
@@ -1198,15 +1910,357 @@ impl InspectorUi<'_, '_> {
     }
 }
 
-impl<'a, 'c> InspectorUi<'a, 'c> {
-    fn reborrow<'s>(&'s mut self) -> InspectorUi<'s, 'c> {
+/// Last resort for opaque values which can neither be edited nor serialized: their reflected `Debug` output, read-only.
+///
+/// Returns `false` if the type doesn't reflect its `Debug` implementation.
+fn debug_value_ui(value: &dyn Reflect, ui: &mut egui::Ui) -> bool {
+    let Some(text) = text::reflected_debug(value) else {
+        return false;
+    };
+    ui.add_enabled(
+        false,
+        egui::Label::new(egui::RichText::new(text).monospace()),
+    )
+    .on_disabled_hover_text(format!(
+        "{} has no InspectorEguiImpl, so it can't be edited",
+        pretty_type_name::pretty_type_name_str(value.type_name())
+    ));
+    true
+}
+
+/// The settings an [`InspectorUi`] passes on to the [`InspectorUi`]s created from it.
+///
+/// Per-call state like the changed field callback, the field filter and the breadcrumb is not passed on.
+/// Taking the settings is a copy, so an [`InspectorUi`] for another [`Context`] can be created
+/// while the context of the original one is borrowed.
+#[derive(Clone, Copy)]
+pub(crate) struct InheritedSettings<'a> {
+    type_registry: &'a TypeRegistry,
+    short_circuit: ShortCircuitFn,
+    short_circuit_readonly: ShortCircuitFnReadonly,
+    short_circuit_many: ShortCircuitFnMany,
+    max_collection_items: usize,
+    max_depth: usize,
+    depth: usize,
+    highlight_non_default: bool,
+}
+
+impl<'a> InheritedSettings<'a> {
+    /// An [`InspectorUi`] with these settings, displaying values using the `context`
+    pub(crate) fn with_context<'s, 'c>(self, context: &'s mut Context<'c>) -> InspectorUi<'s, 'c>
+    where
+        'a: 's,
+    {
         InspectorUi {
             type_registry: self.type_registry,
-            context: self.context,
+            context,
+            short_circuit: self.short_circuit,
+            short_circuit_readonly: self.short_circuit_readonly,
+            short_circuit_many: self.short_circuit_many,
+            max_collection_items: self.max_collection_items,
+            max_depth: self.max_depth,
+            depth: self.depth,
+            changed_fields: None,
+            field_filter: None,
+            breadcrumb: None,
+            highlight_non_default: self.highlight_non_default,
+        }
+    }
+}
+
+impl<'a, 'c> InspectorUi<'a, 'c> {
+    pub(crate) fn inherited_settings(&self) -> InheritedSettings<'a> {
+        InheritedSettings {
+            type_registry: self.type_registry,
             short_circuit: self.short_circuit,
             short_circuit_readonly: self.short_circuit_readonly,
             short_circuit_many: self.short_circuit_many,
+            max_collection_items: self.max_collection_items,
+            max_depth: self.max_depth,
+            depth: self.depth,
+            highlight_non_default: self.highlight_non_default,
+        }
+    }
+
+    fn reborrow<'s>(&'s mut self) -> InspectorUi<'s, 'c> {
+        self.inherited_settings().with_context(self.context)
+    }
+
+    /// UI for a field of a struct or enum variant, which is shown like in [`InspectorUi::ui_for_reflect_readonly`] if it has
+    /// [`FieldOptions::read_only`](crate::inspector_options::FieldOptions::read_only) set.
+    fn ui_for_field(
+        &mut self,
+        field: &mut dyn Reflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        target: Target,
+    ) -> bool {
+        let field_options = match target {
+            Target::Field(i) => inspector_options_struct_field(options, i),
+            Target::VariantField {
+                variant_index,
+                field_index,
+            } => inspector_options_enum_variant_field(options, variant_index, field_index),
+        };
+        if field_read_only(options, target) {
+            self.ui_for_reflect_readonly_with_options(field, ui, id, field_options);
+            return false;
+        }
+        self.ui_for_reflect_with_options(field, ui, id, field_options)
+    }
+
+    /// [`field_label`] of the `i`th field, followed by a button resetting the field to its default value if it has
+    /// [`FieldOptions::reset`](crate::inspector_options::FieldOptions::reset) set.
+    fn struct_field_label(
+        &mut self,
+        ui: &mut egui::Ui,
+        value: &mut dyn Struct,
+        i: usize,
+        options: &dyn Any,
+    ) -> bool {
+        let help = field_help(options, Target::Field(i), value.get_represented_type_info());
+        let name = self.field_name(ui, value, i);
+        if !field_reset(options, Target::Field(i)) || field_read_only(options, Target::Field(i)) {
+            field_label(ui, name, help);
+            return false;
+        }
+
+        let default = self.field_default(ui, value, i);
+        let field = value.field_at_mut(i).unwrap();
+        ui.horizontal(|ui| {
+            field_label(ui, name, help);
+            let response = ui
+                .add_enabled(default.is_some(), egui::Button::new("⟲").small())
+                .on_hover_text("Reset to default")
+                .on_disabled_hover_text("No default value is registered for this type");
+            match default {
+                Some(default) if response.clicked() => {
+                    field.apply(&*default);
+                    self.report_change(field);
+                    true
+                }
+                _ => false,
+            }
+        })
+        .inner
+    }
+
+    /// Label of the `i`th field of the struct, which is bold if it is [modified](InspectorUi::field_modified)
+    fn field_name(&self, ui: &egui::Ui, value: &dyn Struct, i: usize) -> egui::RichText {
+        let name = egui::RichText::new(value.name_at(i).unwrap());
+        match self.field_modified(ui, value, i) {
+            true => name.strong(),
+            false => name,
+        }
+    }
+
+    /// Whether the `i`th field of the struct differs from the struct's default value,
+    /// if that is [highlighted](InspectorUi::with_default_highlight)
+    fn field_modified(&self, ui: &egui::Ui, value: &dyn Struct, i: usize) -> bool {
+        if !self.highlight_non_default {
+            return false;
+        }
+        let Some(default) =
+            default_diff::cached_default(ui, self.type_registry, value.as_reflect())
+        else {
+            return false;
+        };
+        let ReflectRef::Struct(default) = default.reflect_ref() else {
+            return false;
+        };
+        let (Some(field), Some(default)) = (value.field_at(i), default.field_at(i)) else {
+            return false;
+        };
+        field.reflect_partial_eq(default) == Some(false)
+    }
+
+    /// The field of the struct's `ReflectDefault` value, or the default value of the field type.
+    ///
+    /// Both defaults are only created once, see [`default_diff::cached_default`].
+    fn field_default(
+        &self,
+        ui: &egui::Ui,
+        value: &dyn Struct,
+        i: usize,
+    ) -> Option<Box<dyn Reflect>> {
+        let struct_default =
+            default_diff::cached_default(ui, self.type_registry, value.as_reflect());
+        if let Some(struct_default) = struct_default {
+            if let ReflectRef::Struct(struct_default) = struct_default.reflect_ref() {
+                return struct_default.field_at(i).map(Reflect::clone_value);
+            }
+        }
+
+        default_diff::cached_default(ui, self.type_registry, value.field_at(i)?)
+            .map(|field_default| field_default.clone_value())
+    }
+
+    /// Reports a change made outside of [`InspectorUi::ui_for_reflect_with_options`] to the value at the current field path
+    fn report_change(&mut self, value: &dyn Reflect) {
+        if let Some(fields) = &mut self.changed_fields {
+            fields.reported += 1;
+            (fields.callback)(value, &fields.path);
+        }
+    }
+
+    /// Appends the segment to the paths of changed fields, of the field filter and of the breadcrumb,
+    /// and returns the length to restore in [`InspectorUi::pop_field_path`].
+    ///
+    /// All paths start out empty and are pushed and popped together, so they always have the same length.
+    fn push_field_path(&mut self, segment: FieldPathSegment<'_>) -> usize {
+        let mut len = 0;
+        if let Some(fields) = &mut self.changed_fields {
+            len = segment.push_to(&mut fields.path);
+        }
+        if let Some(filter) = &mut self.field_filter {
+            len = segment.push_to(&mut filter.path);
+        }
+        if let Some(breadcrumb) = &mut self.breadcrumb {
+            len = segment.push_to(&mut breadcrumb.path);
+        }
+        len
+    }
+
+    fn pop_field_path(&mut self, len: usize) {
+        if let Some(fields) = &mut self.changed_fields {
+            fields.path.truncate(len);
+        }
+        if let Some(filter) = &mut self.field_filter {
+            filter.path.truncate(len);
+        }
+        if let Some(breadcrumb) = &mut self.breadcrumb {
+            breadcrumb.path.truncate(len);
+        }
+    }
+
+    /// Shows a collapsing header for a nested field, keeping track of it for the [breadcrumb](InspectorUi::with_breadcrumb).
+    /// The field path includes the name of the field while `add_body` runs.
+    fn nested_field_header(
+        &mut self,
+        ui: &mut egui::Ui,
+        header: NestedHeader<'_>,
+        id: egui::Id,
+        add_body: impl FnOnce(&mut Self, &mut egui::Ui),
+    ) -> egui::Response {
+        let (header_id, open) = self.filtered_header_id(id);
+        let path = self.push_field_path(FieldPathSegment::Field(header.name));
+        let title = match header.modified {
+            true => egui::RichText::new(format!("{} •", header.name)).strong(),
+            false => egui::RichText::new(header.name),
+        };
+        let title = egui_utils::header_title(ui, title, false, header.summary);
+        let response = egui::CollapsingHeader::new(title)
+            .id_source(header_id)
+            .default_open(open)
+            .show(ui, |ui| {
+                if let Some(breadcrumb) = &mut self.breadcrumb {
+                    breadcrumb.enter();
+                }
+                add_body(self, ui);
+                if let Some(breadcrumb) = &mut self.breadcrumb {
+                    breadcrumb.exit();
+                }
+            });
+        let open = response.body_returned.is_some();
+        ui.data_mut(|data| data.insert_temp(header_id.with("header open"), open));
+        if let Some(breadcrumb) = &mut self.breadcrumb {
+            breadcrumb.header_shown(ui, &response.header_response);
+        }
+        self.pop_field_path(path);
+        response.header_response
+    }
+
+    /// [`value_summary`] of the `field` if its [nested field header](InspectorUi::nested_field_header) with the `id` is closed
+    fn collapsed_summary(
+        &self,
+        ui: &egui::Ui,
+        id: egui::Id,
+        field: &dyn Reflect,
+    ) -> Option<String> {
+        let (header_id, default_open) = self.filtered_header_id(id);
+        let open = ui.data(|data| data.get_temp::<bool>(header_id.with("header open")));
+        if open.unwrap_or(default_open) {
+            return None;
+        }
+        let summary = value_summary(field, NESTED_SUMMARY_LEN);
+        (!summary.is_empty()).then_some(summary)
+    }
+
+    /// Whether the `value` at the current field path passes the [field filter](InspectorUi::with_field_filter)
+    fn field_visible(&self, value: &dyn Reflect) -> bool {
+        self.field_filter
+            .as_ref()
+            .map_or(true, |filter| filter.matches(value))
+    }
+
+    /// Indices of the fields of the struct which pass the [field filter](InspectorUi::with_field_filter)
+    fn visible_struct_fields(&mut self, value: &dyn Struct) -> Vec<usize> {
+        (0..value.field_len())
+            .filter(|&i| {
+                let path = self.push_field_path(FieldPathSegment::Field(value.name_at(i).unwrap()));
+                let visible = self.field_visible(value.field_at(i).unwrap());
+                self.pop_field_path(path);
+                visible
+            })
+            .collect()
+    }
+
+    /// Id of a collapsing header which is opened while the field filter is active, without changing whether
+    /// the header is open once the filter is cleared
+    fn filtered_header_id(&self, id: egui::Id) -> (egui::Id, bool) {
+        match self.field_filter {
+            Some(_) => (id.with("filtered"), true),
+            None => (id, false),
+        }
+    }
+
+    /// How many of the `len` elements of the collection with the given `id` should be displayed
+    pub(crate) fn visible_collection_items(
+        &self,
+        ui: &egui::Ui,
+        id: egui::Id,
+        len: usize,
+    ) -> usize {
+        let shown = ui
+            .data_mut(|data| data.get_temp::<usize>(id.with("shown_items")))
+            .unwrap_or(self.max_collection_items);
+        len.min(shown)
+    }
+
+    /// Shows a placeholder instead of the value if it would be nested deeper than [`InspectorUi::max_depth`]
+    fn max_depth_reached(&self, ui: &mut egui::Ui) -> bool {
+        let reached = self.depth >= self.max_depth;
+        if reached {
+            ui.weak("… (max depth)").on_hover_text(format!(
+                "Values nested deeper than {} levels aren't displayed",
+                self.max_depth
+            ));
+        }
+        reached
+    }
+
+    /// Button revealing the next page of a collection where only `visible` out of `len` elements are displayed
+    pub(crate) fn show_more_items(
+        &self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        visible: usize,
+        len: usize,
+    ) {
+        let remaining = len - visible;
+        if remaining == 0 {
+            return;
         }
+        let next = remaining.min(self.max_collection_items.max(1));
+        ui.vertical_centered_justified(|ui| {
+            if ui
+                .button(format!("… {remaining} more, click to show next {next}"))
+                .clicked()
+            {
+                ui.data_mut(|data| data.insert_temp(id.with("shown_items"), visible + next));
+            }
+        });
     }
 
     fn get_default_value_for(&mut self, type_id: TypeId) -> Option<Box<dyn Reflect>> {
@@ -1217,15 +2271,22 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
         None
     }
 
+    /// Constructs the `variant` from default field values, or the fields of the `current` value with the same name and type
     fn construct_default_variant(
         &mut self,
         variant: &VariantInfo,
+        current: Option<&dyn Enum>,
         ui: &mut egui::Ui,
     ) -> Result<DynamicEnum, ()> {
         let dynamic_variant = match variant {
             VariantInfo::Struct(struct_info) => {
                 let mut dynamic_struct = DynamicStruct::default();
                 for field in struct_info.iter() {
+                    if let Some(current_value) = preserved_field(current, field) {
+                        dynamic_struct.insert_boxed(field.name(), current_value.clone_value());
+                        continue;
+                    }
+
                     let field_default_value = match self.get_default_value_for(field.type_id()) {
                         Some(value) => value,
                         None => {
@@ -1258,6 +2319,53 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
     }
 }
 
+/// Number of label/value pairs per row in the `#[inspector(grid)]` layout
+const GRID_COLUMNS: usize = 2;
+
+fn grid_layout(options: &dyn Any) -> bool {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .is_some_and(|options| options.container().grid)
+}
+
+/// Maximum number of characters of the value summary next to the name of a collapsed nested field header
+const NESTED_SUMMARY_LEN: usize = 40;
+
+/// Title of a [nested field header](InspectorUi::nested_field_header)
+struct NestedHeader<'a> {
+    name: &'a str,
+    /// Whether the field differs from its default, see [`InspectorUi::with_default_highlight`]
+    modified: bool,
+    /// Shown in a weaker color after the name while the header is closed
+    summary: Option<String>,
+}
+
+/// Whether the enum opted into [`ContainerOptions::number_with_unit`](crate::inspector_options::ContainerOptions::number_with_unit) and has the right shape for it
+fn number_with_unit(options: &dyn Any, info: &EnumInfo) -> bool {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .is_some_and(|options| options.container().number_with_unit)
+        && is_number_with_unit(info)
+}
+
+/// Whether the enum is a number with a unit, like `Val` of `bevy_ui`: all variants either hold a single `f32`
+/// like `Px(10.0)`, or are units without a number like `Auto`.
+fn is_number_with_unit(info: &EnumInfo) -> bool {
+    let mut has_number = false;
+    for variant in info.iter() {
+        match variant {
+            VariantInfo::Unit(_) => {}
+            VariantInfo::Tuple(tuple)
+                if tuple.field_len() == 1 && tuple.field_at(0).unwrap().is::<f32>() =>
+            {
+                has_number = true;
+            }
+            _ => return false,
+        }
+    }
+    has_number
+}
+
 #[must_use]
 fn maybe_grid(
     i: usize,
@@ -1316,9 +2424,21 @@ fn maybe_grid_readonly_label_if(
     }
 }
 
+/// The field of the `current` struct variant with the same name and type as `field`
+fn preserved_field<'a>(
+    current: Option<&'a dyn Enum>,
+    field: &bevy_reflect::NamedField,
+) -> Option<&'a dyn Reflect> {
+    current
+        .filter(|current| current.variant_type() == VariantType::Struct)
+        .and_then(|current| current.field(field.name()))
+        .filter(|value| value.type_name() == field.type_name())
+}
+
 fn variant_constructable<'a>(
     type_registry: &TypeRegistry,
     variant: &'a VariantInfo,
+    current: Option<&dyn Enum>,
 ) -> Result<(), Vec<&'a str>> {
     let type_id_is_constructable = |type_id: TypeId| {
         type_registry
@@ -1329,6 +2449,7 @@ fn variant_constructable<'a>(
     let unconstructable_fields: Vec<&'a str> = match variant {
         VariantInfo::Struct(variant) => variant
             .iter()
+            .filter(|field| preserved_field(current, field).is_none())
             .filter_map(|field| {
                 (!type_id_is_constructable(field.type_id())).then_some(field.type_name())
             })
@@ -1372,6 +2493,1155 @@ fn inspector_options_enum_variant_field<'a>(
         .unwrap_or(&())
 }
 
-fn or(a: bool, b: bool) -> bool {
-    a || b
+/// Help text of the field from its [`FieldOptions`], or its doc comment if the `documentation` feature is enabled
+fn field_help(
+    options: &dyn Any,
+    target: Target,
+    type_info: Option<&'static TypeInfo>,
+) -> Option<&'static str> {
+    let help = options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.field(target))
+        .and_then(|field| field.help);
+
+    #[cfg(feature = "documentation")]
+    let help = help
+        .or_else(|| match (type_info?, target) {
+            (TypeInfo::Struct(info), Target::Field(i)) => info.field_at(i)?.docs(),
+            (TypeInfo::TupleStruct(info), Target::Field(i)) => info.field_at(i)?.docs(),
+            (
+                TypeInfo::Enum(info),
+                Target::VariantField {
+                    variant_index,
+                    field_index,
+                },
+            ) => match info.variant_at(variant_index)? {
+                VariantInfo::Struct(variant) => variant.field_at(field_index)?.docs(),
+                VariantInfo::Tuple(variant) => variant.field_at(field_index)?.docs(),
+                VariantInfo::Unit(_) => None,
+            },
+            _ => None,
+        })
+        .map(str::trim);
+    #[cfg(not(feature = "documentation"))]
+    let _ = type_info;
+
+    help
+}
+
+fn field_reset(options: &dyn Any, target: Target) -> bool {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.field(target))
+        .is_some_and(|field| field.reset)
+}
+
+fn field_read_only(options: &dyn Any, target: Target) -> bool {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.field(target))
+        .is_some_and(|field| field.read_only)
+}
+
+fn field_label(ui: &mut egui::Ui, name: impl Into<egui::WidgetText>, help: Option<&str>) {
+    match help {
+        Some(help) => {
+            ui.horizontal(|ui| {
+                ui.label(name).on_hover_text(help);
+                ui.weak("ℹ").on_hover_text(help);
+            });
+        }
+        None => {
+            ui.label(name);
+        }
+    }
+}
+
+fn or(a: bool, b: bool) -> bool {
+    a || b
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::{Any, TypeId};
+    use std::cell::{Cell, RefCell};
+
+    use bevy_reflect::{
+        std_traits::ReflectDefault, Reflect, ReflectDeserialize, ReflectSerialize, TypeRegistry,
+    };
+    use smallvec::{smallvec, SmallVec};
+
+    use super::{Context, InspectorUi, RegisterInspectorValidator};
+    use crate::inspector_egui_impls::InspectorEguiImpl;
+    use crate::inspector_options::{InspectorOptions, ReflectInspectorOptions, Target};
+    use crate::utils::tests::{std_type_registry, TestUi};
+
+    #[derive(Reflect, Default)]
+    struct Inner {
+        value: f32,
+    }
+
+    #[derive(Reflect, Default)]
+    struct Stats {
+        health: f32,
+        mana: f32,
+        speed: f32,
+        inner: Inner,
+    }
+
+    thread_local! {
+        static RENDERED_F32S: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn count_f32(
+        _: &mut dyn Any,
+        _: &mut egui::Ui,
+        _: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        RENDERED_F32S.with(|count| count.set(count.get() + 1));
+        false
+    }
+
+    /// Registry where `f32`s count how often they are displayed instead of showing a widget
+    fn counting_type_registry() -> TypeRegistry {
+        let mut type_registry = std_type_registry();
+        type_registry
+            .get_mut(TypeId::of::<f32>())
+            .unwrap()
+            .insert(InspectorEguiImpl::new(
+                count_f32,
+                |_, _, _, _, _| {},
+                |_, _, _, _, _, _| false,
+            ));
+        type_registry
+    }
+
+    fn count_rendered_f32s(f: impl FnOnce()) -> usize {
+        RENDERED_F32S.with(|count| count.set(0));
+        f();
+        RENDERED_F32S.with(Cell::get)
+    }
+
+    /// Returns how many `f32` fields were displayed
+    fn render_stats(options: &InspectorOptions) -> usize {
+        let mut type_registry = counting_type_registry();
+        type_registry.register::<Stats>();
+
+        let mut stats = Stats::default();
+        count_rendered_f32s(|| {
+            TestUi::new().run(&mut |ui| {
+                let mut cx = Context::default();
+                let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+                env.ui_for_reflect_with_options(&mut stats, ui, egui::Id::new("stats"), options);
+            })
+        })
+    }
+
+    #[test]
+    fn grid_layout() {
+        // nested struct fields go into a collapsing header, which is closed by default
+        let mut options = InspectorOptions::new();
+        options.container_mut().grid = true;
+        assert_eq!(render_stats(&options), 3);
+
+        assert_eq!(render_stats(&InspectorOptions::new()), 4);
+    }
+
+    #[test]
+    fn collapsed_header_summary() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Stats>();
+        let mut options = InspectorOptions::new();
+        options.container_mut().grid = true;
+
+        let mut stats = Stats::default();
+        let mut add_contents =
+            |ui: &mut egui::Ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_options(&mut stats, ui, egui::Id::new("stats"), &options);
+            };
+        let mut test_ui = TestUi::new();
+        // grids are only painted from the second frame on
+        test_ui.run(&mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("inner { value: 0.0 }").is_some());
+
+        // the summary is gone once the header is opened
+        let header = test_ui.text_position("inner { value: 0.0 }").unwrap();
+        test_ui.click(header, &mut add_contents);
+        test_ui.run(&mut add_contents);
+        assert!(test_ui.text_position("inner").is_some());
+        assert!(test_ui.text_position("inner { value: 0.0 }").is_none());
+    }
+
+    #[test]
+    fn paginate_long_lists() {
+        let mut type_registry = counting_type_registry();
+        type_registry.register::<Vec<f32>>();
+
+        let mut list = vec![0.0f32; 250];
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            super::ui_for_value(&mut list, ui, &type_registry);
+        };
+
+        assert_eq!(count_rendered_f32s(|| test_ui.run(&mut ui)), 100);
+
+        let button = test_ui
+            .text_position("… 150 more, click to show next 100")
+            .unwrap();
+        test_ui.click(button, &mut ui);
+        assert_eq!(count_rendered_f32s(|| test_ui.run(&mut ui)), 200);
+        assert!(test_ui
+            .text_position("… 50 more, click to show next 50")
+            .is_some());
+    }
+
+    #[test]
+    fn edit_smallvec_element() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<SmallVec<[u32; 4]>>();
+
+        let mut list: SmallVec<[u32; 4]> = smallvec![1, 2];
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            super::ui_for_value(&mut list, ui, &type_registry);
+        };
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+
+        let element = test_ui.text_position("2").unwrap();
+        test_ui.replace_text(element, "2", "7", &mut ui);
+
+        // like a `Vec`, new elements are added with the default value
+        let add = test_ui.text_position("+").unwrap();
+        test_ui.click(add, &mut ui);
+
+        assert_eq!(list.as_slice(), &[1, 7, 0]);
+    }
+
+    #[test]
+    fn help_tooltip() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Stats>();
+
+        let mut options = InspectorOptions::new();
+        options.field_mut(Target::Field(1)).help = Some("Used to cast spells");
+
+        let mut stats = Stats::default();
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+            env.ui_for_reflect_with_options(&mut stats, ui, egui::Id::new("stats"), &options);
+        };
+
+        // grids are invisible in the first frame
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Used to cast spells").is_none());
+
+        let label = test_ui.text_position("mana").unwrap();
+        test_ui.hover(label, &mut ui);
+        assert!(test_ui.text_position("Used to cast spells").is_some());
+
+        let label = test_ui.text_position("health").unwrap();
+        test_ui.hover(label, &mut ui);
+        assert!(test_ui.text_position("Used to cast spells").is_none());
+    }
+
+    #[cfg(feature = "documentation")]
+    #[derive(Reflect, Default)]
+    struct Documented {
+        /// Meters per second
+        speed: f32,
+        /// Meters per second squared
+        gravity: f32,
+    }
+
+    #[test]
+    #[cfg(feature = "documentation")]
+    fn help_tooltip_from_doc_comment() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Documented>();
+
+        let mut options = InspectorOptions::new();
+        options.field_mut(Target::Field(1)).help = Some("Towards the ground");
+
+        let mut documented = Documented::default();
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+            env.ui_for_reflect_with_options(
+                &mut documented,
+                ui,
+                egui::Id::new("documented"),
+                &options,
+            );
+        };
+
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        let label = test_ui.text_position("speed").unwrap();
+        test_ui.hover(label, &mut ui);
+        assert!(test_ui.text_position("Meters per second").is_some());
+
+        // the options take precedence over the doc comment
+        let label = test_ui.text_position("gravity").unwrap();
+        test_ui.hover(label, &mut ui);
+        assert!(test_ui.text_position("Towards the ground").is_some());
+        assert!(test_ui.text_position("Meters per second squared").is_none());
+    }
+
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    struct Light {
+        intensity: f32,
+        range: f32,
+    }
+
+    impl Default for Light {
+        fn default() -> Self {
+            Light {
+                intensity: 800.0,
+                range: 20.0,
+            }
+        }
+    }
+
+    #[test]
+    fn reset_field_to_default() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Light>();
+
+        let mut options = InspectorOptions::new();
+        options.field_mut(Target::Field(0)).reset = true;
+
+        let mut light = Light {
+            intensity: 5.0,
+            range: 1.0,
+        };
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+            env.ui_for_reflect_with_options(&mut light, ui, egui::Id::new("light"), &options);
+        };
+
+        // grids are invisible in the first frame
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        let button = test_ui.text_position("⟲").unwrap();
+        test_ui.click(button, &mut ui);
+
+        assert_eq!(light.intensity, 800.0);
+        assert_eq!(light.range, 1.0);
+
+        // without a `ReflectDefault` for the struct, the default of the field type is used
+        let mut stats = Stats {
+            health: 5.0,
+            ..Default::default()
+        };
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+            env.ui_for_reflect_with_options(&mut stats, ui, egui::Id::new("stats"), &options);
+        };
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        let button = test_ui.text_position("⟲").unwrap();
+        test_ui.click(button, &mut ui);
+        assert_eq!(stats.health, 0.0);
+    }
+
+    #[test]
+    fn read_only_field() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Light>();
+
+        let mut options = InspectorOptions::new();
+        options.field_mut(Target::Field(0)).read_only = true;
+
+        let mut light = Light {
+            intensity: 5.0,
+            range: 1.0,
+        };
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+            env.ui_for_reflect_with_options(&mut light, ui, egui::Id::new("light"), &options);
+        };
+
+        // grids are invisible in the first frame
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        // the read-only intensity isn't focusable, so tabbing focuses the range
+        test_ui.key(egui::Key::Tab);
+        test_ui.run(&mut ui);
+        for _ in 0..8 {
+            test_ui.key(egui::Key::Backspace);
+        }
+        test_ui.type_text("7");
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(&mut ui);
+
+        assert_eq!(light.intensity, 5.0);
+        assert_eq!(light.range, 7.0);
+    }
+
+    #[derive(Reflect, Default)]
+    struct Scene {
+        stats: Stats,
+        items: Vec<Inner>,
+    }
+
+    #[test]
+    fn changed_field_callback() {
+        // `f32`s with the value 5 are changed to 6 when displayed
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Scene>();
+        type_registry
+            .get_mut(TypeId::of::<f32>())
+            .unwrap()
+            .insert(InspectorEguiImpl::new(
+                |value, _, _, _, _| {
+                    let value = value.downcast_mut::<f32>().unwrap();
+                    let changed = *value == 5.0;
+                    if changed {
+                        *value = 6.0;
+                    }
+                    changed
+                },
+                |_, _, _, _, _| {},
+                |_, _, _, _, _, _| false,
+            ));
+
+        let mut scene = Scene::default();
+        scene.stats.inner.value = 5.0;
+        scene.items = vec![Inner::default(), Inner { value: 5.0 }];
+
+        let mut changes = Vec::new();
+        TestUi::new().run(&mut |ui| {
+            let changed =
+                super::ui_for_value_with_callback(&mut scene, ui, &type_registry, |value, path| {
+                    changes.push((path.to_owned(), *value.downcast_ref::<f32>().unwrap()))
+                });
+            assert!(changed);
+        });
+
+        assert_eq!(
+            changes,
+            [
+                ("stats.inner.value".to_owned(), 6.0),
+                ("items[1].value".to_owned(), 6.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_field_callback_in_map() {
+        #[derive(Reflect, Default)]
+        struct Catalog {
+            by_name: bevy_utils::HashMap<String, Inner>,
+        }
+
+        // `f32`s with the value 5 are changed to 6 when displayed
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Catalog>();
+        type_registry
+            .get_mut(TypeId::of::<f32>())
+            .unwrap()
+            .insert(InspectorEguiImpl::new(
+                |value, _, _, _, _| {
+                    let value = value.downcast_mut::<f32>().unwrap();
+                    let changed = *value == 5.0;
+                    if changed {
+                        *value = 6.0;
+                    }
+                    changed
+                },
+                |_, _, _, _, _| {},
+                |_, _, _, _, _, _| false,
+            ));
+
+        let mut catalog = Catalog::default();
+        catalog
+            .by_name
+            .insert("apple".to_string(), Inner { value: 5.0 });
+
+        let mut changes = Vec::new();
+        TestUi::new().run(&mut |ui| {
+            super::ui_for_value_with_callback(&mut catalog, ui, &type_registry, |_, path| {
+                changes.push(path.to_owned())
+            });
+        });
+
+        assert_eq!(changes, [r#"by_name["apple"].value"#]);
+    }
+
+    #[test]
+    fn field_filter() {
+        let mut type_registry = counting_type_registry();
+        type_registry.register::<Scene>();
+
+        let mut scene = Scene {
+            items: vec![Inner::default(), Inner::default()],
+            ..Default::default()
+        };
+
+        let filter = RefCell::new("Inner.VAL");
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .with_field_filter(&filter.borrow())
+                .ui_for_reflect(&mut scene, ui);
+        };
+
+        // grids are invisible in the first frame
+        test_ui.run(&mut ui);
+        let rendered = count_rendered_f32s(|| test_ui.run(&mut ui));
+
+        // only `stats.inner.value` matches, `items[0].value` doesn't contain `inner`
+        assert_eq!(rendered, 1);
+        assert!(test_ui.text_position("stats").is_some());
+        assert!(test_ui.text_position("inner").is_some());
+        assert!(test_ui.text_position("health").is_none());
+        assert!(test_ui.text_position("items").is_none());
+
+        // a blank filter shows everything
+        *filter.borrow_mut() = " ";
+        assert_eq!(count_rendered_f32s(|| test_ui.run(&mut ui)), 6);
+        assert!(test_ui.text_position("items").is_some());
+    }
+
+    #[test]
+    fn field_filter_ui_without_matches() {
+        let scene = Scene::default();
+        let id = egui::Id::new("scene");
+        let query = RefCell::new("armor");
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let query = query.borrow().to_string();
+            ui.data_mut(|data| data.insert_temp(id.with("field filter"), query));
+            super::field_filter_ui(ui, id, &scene);
+        };
+
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("No matching fields").is_some());
+
+        *query.borrow_mut() = "health";
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("No matching fields").is_none());
+    }
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Volume {
+        master: f32,
+        music: f32,
+    }
+
+    #[test]
+    fn default_highlight() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Volume>();
+
+        let mut volume = Volume {
+            master: 0.5,
+            ..Default::default()
+        };
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .with_default_highlight(true)
+                .ui_for_reflect(&mut volume, ui);
+        };
+
+        // grids are only painted from the second frame on
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+
+        let strong = test_ui.ctx.style().visuals.strong_text_color();
+        assert_eq!(test_ui.text_color("master"), Some(strong));
+        assert_ne!(test_ui.text_color("music"), Some(strong));
+    }
+
+    #[derive(Reflect, Default)]
+    struct Outer {
+        inner: Middle,
+    }
+
+    #[derive(Reflect, Default)]
+    struct Middle {
+        deep: Leaf,
+    }
+
+    #[derive(Reflect, Default)]
+    struct Leaf {
+        value: f32,
+    }
+
+    #[test]
+    fn breadcrumb() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Outer>();
+        type_registry.register::<Middle>();
+        type_registry.register::<Leaf>();
+        // nested fields only get collapsing headers in the grid layout
+        let mut options = InspectorOptions::new();
+        options.container_mut().grid = true;
+        for type_id in [TypeId::of::<Outer>(), TypeId::of::<Middle>()] {
+            let registration = type_registry.get_mut(type_id).unwrap();
+            registration.insert(ReflectInspectorOptions(options.clone()));
+        }
+
+        let mut outer = Outer::default();
+        let id = egui::Id::new("outer");
+        let mut test_ui = TestUi::new();
+        // headers should be at their final position right after clicking them
+        let mut style = (*test_ui.ctx.style()).clone();
+        style.animation_time = 0.0;
+        test_ui.ctx.set_style(style);
+        let mut ui = |ui: &mut egui::Ui| {
+            super::breadcrumb_ui(ui, id, "Root");
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .with_breadcrumb(id)
+                .ui_for_reflect_with_id_salt(&mut outer, ui, id);
+        };
+
+        // grids are only painted from the second frame on
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Root").is_none());
+
+        let inner = test_ui.text_position("inner { deep: (0.0) }").unwrap();
+        test_ui.click(inner, &mut ui);
+        // the strip shows up in the next frame, moving the headers down
+        test_ui.run(&mut ui);
+        let deep = test_ui.text_position("deep { value: 0.0 }").unwrap();
+        test_ui.click(deep, &mut ui);
+        test_ui.run(&mut ui);
+
+        // the strip comes first, so the first `inner` and `deep` are part of it
+        let root = test_ui.text_position("Root").unwrap();
+        assert_eq!(test_ui.text_position("inner").unwrap().y, root.y);
+        assert_eq!(test_ui.text_position("deep").unwrap().y, root.y);
+
+        // going back to `inner` collapses `deep`
+        test_ui.click(test_ui.text_position("inner").unwrap(), &mut ui);
+        test_ui.run(&mut ui);
+        assert_ne!(
+            test_ui.text_position("deep { value: 0.0 }").unwrap().y,
+            root.y
+        );
+        assert!(test_ui.text_position("value").is_none());
+
+        test_ui.click(root, &mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("Root").is_none());
+        assert!(test_ui.text_position("deep").is_none());
+    }
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    enum Shape {
+        #[default]
+        Point,
+        Circle(f32),
+        Rect {
+            width: f32,
+            height: f32,
+        },
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Size {
+        Rect { width: f32, height: f32 },
+        Square { width: f32 },
+    }
+
+    /// Selects the variant `to` in the dropdown of the enum `value`, which currently is the variant `from`
+    fn switch_variant<T: Reflect>(
+        type_registry: &TypeRegistry,
+        value: &mut T,
+        from: &str,
+        to: &str,
+    ) {
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(type_registry, &mut cx).ui_for_reflect(value, ui);
+        };
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position(from).unwrap();
+        test_ui.click(pos, &mut ui);
+        // the popup opens in the frame after the click
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position(to).unwrap();
+        test_ui.click(pos, &mut ui);
+    }
+
+    #[test]
+    fn switch_enum_variants() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Shape>();
+
+        let mut shape = Shape::Point;
+        switch_variant(&type_registry, &mut shape, "Point", "Circle");
+        assert_eq!(shape, Shape::Circle(0.0));
+        switch_variant(&type_registry, &mut shape, "Circle", "Rect");
+        assert_eq!(
+            shape,
+            Shape::Rect {
+                width: 0.0,
+                height: 0.0
+            }
+        );
+        switch_variant(&type_registry, &mut shape, "Rect", "Point");
+        assert_eq!(shape, Shape::Point);
+    }
+
+    /// Registers `Val` of `bevy_ui` with its default options
+    #[cfg(feature = "bevy_ui")]
+    fn val_type_registry() -> TypeRegistry {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<bevy_ui::Val>();
+        crate::inspector_options::default_options::register_default_options(&mut type_registry);
+        type_registry
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_ui")]
+    fn number_with_unit() {
+        use bevy_ui::Val;
+
+        let type_registry = val_type_registry();
+
+        let mut val = Val::Px(10.0);
+        switch_variant(&type_registry, &mut val, "Px", "Percent");
+        assert_eq!(val, Val::Percent(10.0));
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            super::ui_for_value(&mut val, ui, &type_registry);
+        };
+        test_ui.run(&mut ui);
+        let number = test_ui.text_position("10.0").unwrap();
+        test_ui.replace_text(number, "10.0", "50", &mut ui);
+        assert_eq!(val, Val::Percent(50.0));
+
+        switch_variant(&type_registry, &mut val, "Percent", "Auto");
+        assert_eq!(val, Val::Auto);
+        switch_variant(&type_registry, &mut val, "Auto", "Px");
+        assert_eq!(val, Val::Px(0.0));
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_ui")]
+    fn number_with_unit_readonly() {
+        use bevy_ui::Val;
+
+        let val = Val::Percent(50.0);
+        let mut test_ui = TestUi::new();
+
+        // without the option, the number is shown below the variant
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Val>();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_value_readonly(&val, ui, &type_registry);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        let number = test_ui.text_position("50").unwrap();
+        let unit = test_ui.text_position("Percent").unwrap();
+        assert!(number.y > unit.y);
+
+        let type_registry = val_type_registry();
+        let mut ui = |ui: &mut egui::Ui| super::ui_for_value_readonly(&val, ui, &type_registry);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        let number = test_ui.text_position("50").unwrap();
+        let unit = test_ui.text_position("Percent").unwrap();
+        assert!(number.x < unit.x);
+        assert!((number.y - unit.y).abs() < 5.0);
+    }
+
+    #[test]
+    fn switching_variants_keeps_shared_fields() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Size>();
+
+        let mut size = Size::Rect {
+            width: 2.0,
+            height: 3.0,
+        };
+        switch_variant(&type_registry, &mut size, "Rect", "Square");
+        assert_eq!(size, Size::Square { width: 2.0 });
+        switch_variant(&type_registry, &mut size, "Square", "Rect");
+        assert_eq!(
+            size,
+            Size::Rect {
+                width: 2.0,
+                height: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn keyboard_focus_survives_layout_changes() {
+        let type_registry = std_type_registry();
+        let mut stats = Stats::default();
+        let extra_button = Cell::new(false);
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            if extra_button.get() {
+                let _ = ui.button("extra");
+            }
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut stats, ui);
+        };
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::Tab);
+        test_ui.run(&mut ui);
+
+        // the widget before the fields doesn't change their ids, so the focus moves on from `health` to `mana`
+        extra_button.set(true);
+        test_ui.key(egui::Key::Tab);
+        test_ui.run(&mut ui);
+        test_ui.key(egui::Key::ArrowUp);
+        test_ui.run(&mut ui);
+
+        assert_eq!(stats.health, 0.0);
+        assert_eq!(stats.mana, 0.1);
+    }
+
+    /// Focuses the `n`th number field with the keyboard and replaces its value with `text`
+    fn edit_nth_number(
+        test_ui: &mut TestUi,
+        ui: &mut dyn FnMut(&mut egui::Ui),
+        n: usize,
+        text: &str,
+    ) {
+        // grids are invisible in the first frame
+        test_ui.run(ui);
+        test_ui.run(ui);
+        for _ in 0..n {
+            test_ui.key(egui::Key::Tab);
+            test_ui.run(ui);
+        }
+        for _ in 0..8 {
+            test_ui.key(egui::Key::Backspace);
+        }
+        test_ui.type_text(text);
+        test_ui.run(ui);
+        test_ui.key(egui::Key::Enter);
+        test_ui.run(ui);
+    }
+
+    #[test]
+    fn edit_tuple_element() {
+        let type_registry = std_type_registry();
+        let mut tuple = (1.0f32, 2.0f32, true);
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut tuple, ui);
+        };
+        edit_nth_number(&mut test_ui, &mut ui, 2, "7");
+        assert!(["0", "1", "2"]
+            .iter()
+            .all(|label| test_ui.text_position(label).is_some()));
+
+        assert_eq!(tuple, (1.0, 7.0, true));
+    }
+
+    #[test]
+    fn edit_array_element() {
+        let type_registry = std_type_registry();
+        let mut array = [10u8, 20, 30, 40];
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut array, ui);
+        };
+        edit_nth_number(&mut test_ui, &mut ui, 3, "9");
+        assert!(["0", "1", "2", "3"]
+            .iter()
+            .all(|label| test_ui.text_position(label).is_some()));
+
+        assert_eq!(array, [10, 20, 9, 40]);
+    }
+
+    thread_local! {
+        static WIDGET_IDS: Cell<Option<(egui::Id, egui::Id)>> = const { Cell::new(None) };
+    }
+
+    /// Records the id passed to the `f32` and the id egui would give to its next widget
+    fn record_ids(
+        _: &mut dyn Any,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        WIDGET_IDS.with(|ids| ids.set(Some((id, ui.next_auto_id()))));
+        false
+    }
+
+    #[test]
+    fn id_salt() {
+        let mut type_registry = std_type_registry();
+        type_registry
+            .get_mut(TypeId::of::<f32>())
+            .unwrap()
+            .insert(InspectorEguiImpl::new(
+                record_ids,
+                |_, _, _, _, _| {},
+                |_, _, _, _, _, _| false,
+            ));
+
+        let mut test_ui = TestUi::new();
+        let mut ids_with_salt = |salt: &str| {
+            let mut value = 1.0f32;
+            test_ui.run(&mut |ui| {
+                let mut cx = Context::default();
+                InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                    .ui_for_reflect_with_id_salt(&mut value, ui, salt);
+            });
+            WIDGET_IDS.with(Cell::take).unwrap()
+        };
+
+        let (explicit_a, auto_a) = ids_with_salt("a");
+        let (explicit_b, auto_b) = ids_with_salt("b");
+        assert_ne!(explicit_a, explicit_b);
+        assert_ne!(auto_a, auto_b);
+        assert_eq!(ids_with_salt("a"), (explicit_a, auto_a));
+    }
+
+    #[derive(Reflect, Default)]
+    struct Inventory {
+        counts: bevy_utils::HashMap<String, u32>,
+    }
+
+    #[test]
+    fn edit_bevy_hash_map_value() {
+        let mut type_registry = std_type_registry();
+        type_registry.register::<Inventory>();
+
+        let mut inventory = Inventory::default();
+        inventory.counts.insert("apples".to_string(), 3);
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut inventory, ui);
+        };
+        // the key is read-only, but its text field can still be focused to select the text
+        edit_nth_number(&mut test_ui, &mut ui, 2, "7");
+
+        assert_eq!(inventory.counts["apples"], 7);
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+    #[reflect_value(PartialEq, Serialize, Deserialize)]
+    struct Version {
+        major: u32,
+        minor: u32,
+    }
+
+    #[test]
+    fn edit_serialized_value() {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Version>();
+
+        let version = RefCell::new(Version { major: 1, minor: 2 });
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect(&mut *version.borrow_mut(), ui);
+        };
+        test_ui.run(&mut ui);
+        let pos = test_ui.text_position("(major:1,minor:2)").unwrap();
+        test_ui.click(pos, &mut ui);
+
+        // incomplete text is kept, but not applied
+        test_ui.key(egui::Key::End);
+        test_ui.key(egui::Key::Backspace);
+        test_ui.key(egui::Key::Backspace);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("(major:1,minor:").is_some());
+        assert_eq!(*version.borrow(), Version { major: 1, minor: 2 });
+
+        test_ui.type_text("5)");
+        test_ui.run(&mut ui);
+        assert_eq!(*version.borrow(), Version { major: 1, minor: 5 });
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    #[reflect_value(PartialEq, Debug)]
+    struct Opaque {
+        handle: u64,
+    }
+
+    #[test]
+    fn opaque_value_debug() {
+        let type_registry = TypeRegistry::default();
+
+        let mut test_ui = TestUi::new();
+        test_ui.run(&mut |ui| {
+            let mut cx = Context::default();
+            let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+            assert!(!env.ui_for_reflect(&mut Opaque { handle: 7 }, ui));
+        });
+        assert!(test_ui.text_position("Opaque { handle: 7 }").is_some());
+
+        test_ui.run(&mut |ui| {
+            let mut cx = Context::default();
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .ui_for_reflect_readonly(&Opaque { handle: 7 }, ui);
+        });
+        assert!(test_ui.text_position("Opaque { handle: 7 }").is_some());
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[test]
+    fn validator_rejects_edit() {
+        let mut type_registry = std_type_registry();
+        type_registry.add_inspector_validator::<Health>(|value| {
+            let health = value.downcast_ref::<Health>().unwrap();
+            match health.current <= health.max {
+                true => Ok(()),
+                false => Err("current health exceeds max".to_string()),
+            }
+        });
+
+        let health = RefCell::new(Health {
+            current: 5.0,
+            max: 10.0,
+        });
+        let edits = Cell::new(0);
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut callback = |_: &dyn Reflect, _: &str| edits.set(edits.get() + 1);
+            InspectorUi::new_no_short_circuit(&type_registry, &mut cx)
+                .with_field_changed_callback(&mut callback)
+                .ui_for_reflect(&mut *health.borrow_mut(), ui);
+        };
+        let mut edit_current = |test_ui: &mut TestUi, from: &str, to: &str| {
+            // grids are only painted from the second frame on
+            test_ui.run(&mut ui);
+            test_ui.run(&mut ui);
+            let drag_value = test_ui.text_position(from).unwrap();
+            test_ui.replace_text(drag_value, from, to, &mut ui);
+        };
+
+        edit_current(&mut test_ui, "5.0", "20");
+        let expected = Health {
+            current: 5.0,
+            max: 10.0,
+        };
+        assert_eq!(*health.borrow(), expected);
+        assert!(test_ui
+            .text_position("current health exceeds max")
+            .is_some());
+        assert_eq!(edits.get(), 0);
+
+        edit_current(&mut test_ui, "5.0", "8");
+        assert_eq!(health.borrow().current, 8.0);
+        assert_eq!(edits.get(), 1);
+    }
+
+    #[derive(Reflect)]
+    struct Settings {
+        volume: f32,
+        name: String,
+        offset: bevy_math::Vec2,
+    }
+
+    #[test]
+    fn world_independent_rendering() {
+        let mut type_registry = TypeRegistry::default();
+        crate::register_default_config(&mut type_registry);
+        type_registry.register::<Settings>();
+
+        let mut settings = Settings {
+            volume: 0.5,
+            name: "Main".to_string(),
+            offset: bevy_math::Vec2::new(3.0, 4.0),
+        };
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            super::ui_for_value(&mut settings, ui, &type_registry);
+            super::ui_for_value_readonly(&settings, ui, &type_registry);
+        };
+        // grids are only painted from the second frame on
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        for text in ["0.5", "Main", "3.0", "4.0"] {
+            assert!(test_ui.text_position(text).is_some(), "{text} not shown");
+        }
+    }
+
+    #[derive(Reflect, Default)]
+    struct Level1 {
+        level2: Level2,
+    }
+    #[derive(Reflect, Default)]
+    struct Level2 {
+        level3: Level3,
+    }
+    #[derive(Reflect, Default)]
+    struct Level3 {
+        leaf: u32,
+    }
+
+    #[test]
+    fn max_depth() {
+        let type_registry = std_type_registry();
+        let max_depth = Cell::new(super::DEFAULT_MAX_DEPTH);
+
+        let mut test_ui = TestUi::new();
+        let mut ui = |ui: &mut egui::Ui| {
+            let mut cx = Context::default();
+            let mut env = InspectorUi::new_no_short_circuit(&type_registry, &mut cx);
+            env.max_depth = max_depth.get();
+            env.ui_for_reflect(&mut Level1::default(), ui);
+            env.ui_for_reflect_readonly(&Level1::default(), ui);
+        };
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("leaf").is_some());
+        assert!(test_ui.text_position("… (max depth)").is_none());
+
+        // the first two levels are shown, but not the fields of `Level3`
+        max_depth.set(2);
+        test_ui.run(&mut ui);
+        test_ui.run(&mut ui);
+        assert!(test_ui.text_position("level3").is_some());
+        assert!(test_ui.text_position("leaf").is_none());
+        assert!(test_ui.text_position("… (max depth)").is_some());
+    }
 }
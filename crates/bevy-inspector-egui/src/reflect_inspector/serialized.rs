@@ -0,0 +1,114 @@
+//! Fallback for opaque `#[reflect_value]` types without an [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl)
+//! which can be serialized and deserialized: they are edited as RON text.
+
+use std::any::Any;
+
+use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize, TypeRegistry};
+
+/// Text which is being edited, kept until it is valid and the text field lost focus
+#[derive(Clone)]
+struct EditedText {
+    text: String,
+    error: Option<String>,
+}
+
+/// Shows the value as RON text, which is deserialized and applied to the value on every edit.
+/// Invalid text is kept together with the error, without changing the value.
+///
+/// Returns `None` if the type has no [`ReflectSerialize`] and [`ReflectDeserialize`] type data.
+pub(super) fn serialized_value_ui(
+    value: &mut dyn Reflect,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) -> Option<bool> {
+    let type_id = Any::type_id(value);
+    let reflect_serialize = type_registry.get_type_data::<ReflectSerialize>(type_id)?;
+    let reflect_deserialize = type_registry.get_type_data::<ReflectDeserialize>(type_id)?;
+
+    let edited_id = id.with("serialized text");
+    let edited = ui.data_mut(|data| data.get_temp::<EditedText>(edited_id));
+    let EditedText {
+        mut text,
+        mut error,
+    } = match edited {
+        Some(edited) => edited,
+        None => match to_ron(reflect_serialize, value) {
+            Ok(text) => EditedText { text, error: None },
+            Err(error) => {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+                return Some(false);
+            }
+        },
+    };
+
+    let response = ui.add(text_edit(&mut text));
+    let mut changed = false;
+    if response.changed() {
+        match from_ron(reflect_deserialize, &text) {
+            Ok(new_value) => {
+                value.apply(&*new_value);
+                changed = true;
+                error = None;
+            }
+            Err(e) => error = Some(e),
+        }
+    }
+    if let Some(error) = &error {
+        ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+
+    let keep_text = error.is_some() || response.has_focus();
+    ui.data_mut(|data| match keep_text {
+        true => data.insert_temp(edited_id, EditedText { text, error }),
+        false => data.remove::<EditedText>(edited_id),
+    });
+    Some(changed)
+}
+
+/// Shows the value as RON text which can be selected, but not edited.
+///
+/// Returns `false` if the type has no [`ReflectSerialize`] type data.
+pub(super) fn serialized_value_ui_readonly(
+    value: &dyn Reflect,
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+) -> bool {
+    let Some(reflect_serialize) =
+        type_registry.get_type_data::<ReflectSerialize>(Any::type_id(value))
+    else {
+        return false;
+    };
+    match to_ron(reflect_serialize, value) {
+        Ok(text) => {
+            ui.add(text_edit(&mut text.as_str()));
+        }
+        Err(error) => {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+    }
+    true
+}
+
+fn text_edit(text: &mut dyn egui::TextBuffer) -> egui::TextEdit<'_> {
+    egui::TextEdit::multiline(text)
+        .code_editor()
+        .desired_rows(1)
+}
+
+fn to_ron(reflect_serialize: &ReflectSerialize, value: &dyn Reflect) -> Result<String, String> {
+    let serializable = reflect_serialize.get_serializable(value);
+    ron::to_string(serializable.borrow()).map_err(|e| e.to_string())
+}
+
+fn from_ron(
+    reflect_deserialize: &ReflectDeserialize,
+    text: &str,
+) -> Result<Box<dyn Reflect>, String> {
+    let mut deserializer = ron::Deserializer::from_str(text).map_err(|e| e.to_string())?;
+    let value = reflect_deserialize
+        .deserialize(&mut deserializer)
+        .map_err(|e| e.to_string())?;
+    deserializer.end().map_err(|e| e.to_string())?;
+    Ok(value)
+}
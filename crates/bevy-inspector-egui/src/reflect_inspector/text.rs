@@ -0,0 +1,340 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::time::Duration;
+
+use bevy_reflect::{Enum, Reflect, ReflectRef, VariantType};
+
+use crate::inspector_egui_impls::std_impls::humanize_duration;
+
+const INDENT: &str = "    ";
+
+/// Formats the value as an indented tree like the inspector would display it, without rendering any UI.
+///
+/// ```text
+/// Player {
+///     name: "Alice"
+///     inventory: [
+///         0: "Sword"
+///     ]
+/// }
+/// ```
+pub fn value_to_text(value: &dyn Reflect) -> String {
+    let mut text = String::new();
+    write_value(&mut text, value, 0);
+    text
+}
+
+/// Number of fields included in a [`value_summary`]
+const SUMMARY_FIELDS: usize = 3;
+
+/// Formats the first few fields of the value on a single line, like `{ translation: (1.0, 2.0, 3.0), … }`,
+/// cut off with `…` after `max_len` characters.
+///
+/// Only fields which fit on a line are included, that is leaf values and fields made up of leaf values,
+/// which are shown as a tuple. The type name isn't included since it's usually shown right next to the summary,
+/// and leaf values are left empty, since their `Debug` output often doesn't fit on a line.
+pub(crate) fn value_summary(value: &dyn Reflect, max_len: usize) -> String {
+    let mut text = String::new();
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let fields = (0..value.field_len())
+                .map(|i| (Some(value.name_at(i).unwrap()), value.field_at(i).unwrap()));
+            write_summary_fields(&mut text, ("{ ", " }"), fields);
+        }
+        ReflectRef::TupleStruct(value) => {
+            write_summary_fields(
+                &mut text,
+                ("(", ")"),
+                value.iter_fields().map(|f| (None, f)),
+            );
+        }
+        ReflectRef::Tuple(value) => {
+            write_summary_fields(
+                &mut text,
+                ("(", ")"),
+                value.iter_fields().map(|f| (None, f)),
+            );
+        }
+        ReflectRef::Enum(value) => {
+            text.push_str(value.variant_name());
+            let fields = value
+                .iter_fields()
+                .map(|field| (field.name(), field.value()));
+            match value.variant_type() {
+                VariantType::Struct => write_summary_fields(&mut text, (" { ", " }"), fields),
+                VariantType::Tuple => write_summary_fields(&mut text, ("(", ")"), fields),
+                VariantType::Unit => {}
+            }
+        }
+        ReflectRef::List(value) => {
+            let _ = write!(text, "[{} items]", value.len());
+        }
+        ReflectRef::Array(value) => {
+            let _ = write!(text, "[{} items]", value.len());
+        }
+        ReflectRef::Map(value) => {
+            let _ = write!(text, "{{{} entries}}", value.len());
+        }
+        ReflectRef::Value(_) => {}
+    }
+
+    if text.chars().count() > max_len {
+        text = text.chars().take(max_len.saturating_sub(1)).collect();
+        text.push('…');
+    }
+    text
+}
+
+/// Writes the first [`SUMMARY_FIELDS`] fields which fit on a line, with `…` in place of the rest
+fn write_summary_fields<'a>(
+    text: &mut String,
+    (open, close): (&str, &str),
+    fields: impl Iterator<Item = (Option<&'a str>, &'a dyn Reflect)>,
+) {
+    let mut parts = Vec::new();
+    let mut omitted = false;
+    for (name, field) in fields {
+        if parts.len() == SUMMARY_FIELDS {
+            omitted = true;
+            break;
+        }
+        match inline_value(field) {
+            Some(inline) => parts.push(match name {
+                Some(name) => format!("{name}: {inline}"),
+                None => inline,
+            }),
+            None => omitted = true,
+        }
+    }
+    if parts.is_empty() && !omitted {
+        return;
+    }
+    if omitted {
+        parts.push("…".to_string());
+    }
+    text.push_str(open);
+    text.push_str(&parts.join(", "));
+    text.push_str(close);
+}
+
+/// The `{:?}` output of the value, if its type reflects its `Debug` implementation, e.g. with `#[reflect_value(Debug)]`
+pub(crate) fn reflected_debug(value: &dyn Reflect) -> Option<String> {
+    let text = format!("{value:?}");
+    is_reflected_debug(&text).then_some(text)
+}
+
+/// Whether `{:?}` output comes from a reflected `Debug` implementation, and not the `Reflect(…)` placeholder of values without one
+fn is_reflected_debug(text: &str) -> bool {
+    !text.starts_with("Reflect(")
+}
+
+/// Leaf values as they are, and values with only leaf fields (like vectors) as a tuple of their fields.
+///
+/// `None` if the value doesn't fit on a line, or is a leaf value without reflected `Debug` implementation.
+fn inline_value(value: &dyn Reflect) -> Option<String> {
+    let leaf = |value: &dyn Reflect| {
+        if !matches!(value.reflect_ref(), ReflectRef::Value(_)) {
+            return None;
+        }
+        // `Cow<str>` doesn't reflect its `Debug` implementation, but is what `Name` is made of
+        if let Some(text) = value.downcast_ref::<Cow<'static, str>>() {
+            return Some(format!("{text:?}"));
+        }
+        let mut text = String::new();
+        write_value(&mut text, value, 0);
+        is_reflected_debug(&text).then_some(text)
+    };
+    let tuple =
+        |fields: Option<Vec<String>>| fields.map(|fields| format!("({})", fields.join(", ")));
+
+    match value.reflect_ref() {
+        ReflectRef::Value(_) => leaf(value),
+        ReflectRef::Struct(value) => tuple(value.iter_fields().map(leaf).collect()),
+        ReflectRef::TupleStruct(value) => tuple(value.iter_fields().map(leaf).collect()),
+        ReflectRef::Tuple(value) => tuple(value.iter_fields().map(leaf).collect()),
+        ReflectRef::Enum(value) if value.variant_type() == VariantType::Unit => {
+            Some(value.variant_name().to_string())
+        }
+        _ => None,
+    }
+}
+
+fn write_value(text: &mut String, value: &dyn Reflect, depth: usize) {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let name = pretty_type_name::pretty_type_name_str(value.type_name());
+            let fields = (0..value.field_len()).map(|i| {
+                (
+                    value.name_at(i).unwrap().to_string(),
+                    value.field_at(i).unwrap(),
+                )
+            });
+            write_fields(text, &name, ("{", "}"), fields, depth);
+        }
+        ReflectRef::TupleStruct(value) => {
+            let name = pretty_type_name::pretty_type_name_str(value.type_name());
+            let fields = value
+                .iter_fields()
+                .enumerate()
+                .map(|(i, field)| (i.to_string(), field));
+            write_fields(text, &name, ("(", ")"), fields, depth);
+        }
+        ReflectRef::Tuple(value) => {
+            let fields = value
+                .iter_fields()
+                .enumerate()
+                .map(|(i, field)| (i.to_string(), field));
+            write_fields(text, "", ("(", ")"), fields, depth);
+        }
+        ReflectRef::List(value) => {
+            let items = value
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i.to_string(), item));
+            write_fields(text, "", ("[", "]"), items, depth);
+        }
+        ReflectRef::Array(value) => {
+            let items = value
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i.to_string(), item));
+            write_fields(text, "", ("[", "]"), items, depth);
+        }
+        ReflectRef::Map(value) => {
+            let entries = value.iter().map(|(key, value)| {
+                let mut key_text = String::new();
+                write_value(&mut key_text, key, depth + 1);
+                (key_text, value)
+            });
+            write_fields(text, "", ("{", "}"), entries, depth);
+        }
+        ReflectRef::Enum(value) => write_enum(text, value, depth),
+        ReflectRef::Value(value) => match value.downcast_ref::<Duration>() {
+            Some(duration) => text.push_str(&humanize_duration(*duration)),
+            None => {
+                let _ = write!(text, "{value:?}");
+            }
+        },
+    }
+}
+
+fn write_enum(text: &mut String, value: &dyn Enum, depth: usize) {
+    let fields = value.iter_fields().enumerate().map(|(i, field)| {
+        let label = field.name().map_or_else(|| i.to_string(), str::to_string);
+        (label, field.value())
+    });
+    match value.variant_type() {
+        VariantType::Struct => write_fields(text, value.variant_name(), ("{", "}"), fields, depth),
+        VariantType::Tuple => write_fields(text, value.variant_name(), ("(", ")"), fields, depth),
+        VariantType::Unit => text.push_str(value.variant_name()),
+    }
+}
+
+/// Writes `name` followed by one labeled line per field between the `delimiters`
+fn write_fields<'a>(
+    text: &mut String,
+    name: &str,
+    (open, close): (&str, &str),
+    fields: impl Iterator<Item = (String, &'a dyn Reflect)>,
+    depth: usize,
+) {
+    text.push_str(name);
+    if !name.is_empty() && open == "{" {
+        text.push(' ');
+    }
+    text.push_str(open);
+
+    let mut empty = true;
+    for (label, field) in fields {
+        empty = false;
+        text.push('\n');
+        text.push_str(&INDENT.repeat(depth + 1));
+        let _ = write!(text, "{label}: ");
+        write_value(text, field, depth + 1);
+    }
+
+    if !empty {
+        text.push('\n');
+        text.push_str(&INDENT.repeat(depth));
+    }
+    text.push_str(close);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy_reflect::Reflect;
+
+    #[derive(Reflect)]
+    struct Position(f32, f32);
+
+    #[derive(Reflect)]
+    enum State {
+        Idle,
+        Walking { speed: f32 },
+    }
+
+    #[derive(Reflect)]
+    struct Player {
+        name: String,
+        position: Position,
+        inventory: Vec<String>,
+        cooldown: Duration,
+        state: State,
+        previous_state: State,
+    }
+
+    #[test]
+    fn nested_struct() {
+        let player = Player {
+            name: "Alice".to_string(),
+            position: Position(1.0, -2.5),
+            inventory: vec!["Sword".to_string()],
+            cooldown: Duration::from_millis(1250),
+            state: State::Walking { speed: 3.0 },
+            previous_state: State::Idle,
+        };
+
+        let expected = r#"Player {
+    name: "Alice"
+    position: Position(
+        0: 1.0
+        1: -2.5
+    )
+    inventory: [
+        0: "Sword"
+    ]
+    cooldown: 1.25 s
+    state: Walking {
+        speed: 3.0
+    }
+    previous_state: Idle
+}"#;
+        assert_eq!(super::value_to_text(&player), expected);
+        assert_eq!(super::value_to_text(&Vec::<u8>::new()), "[]");
+    }
+
+    #[test]
+    fn summary() {
+        let player = Player {
+            name: "Alice".to_string(),
+            position: Position(1.0, -2.5),
+            inventory: vec!["Sword".to_string()],
+            cooldown: Duration::from_millis(1250),
+            state: State::Idle,
+            previous_state: State::Idle,
+        };
+
+        assert_eq!(
+            super::value_summary(&player, 100),
+            r#"{ name: "Alice", position: (1.0, -2.5), cooldown: 1.25 s, … }"#
+        );
+        assert_eq!(super::value_summary(&player, 12), r#"{ name: "Al…"#);
+        assert_eq!(
+            super::value_summary(&State::Walking { speed: 3.0 }, 100),
+            "Walking { speed: 3.0 }"
+        );
+        assert_eq!(super::value_summary(&1.0f32, 100), "");
+    }
+}
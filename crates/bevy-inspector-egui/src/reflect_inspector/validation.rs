@@ -0,0 +1,96 @@
+//! Rejecting edits which break invariants of a type, see [`InspectorValidator`].
+
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::{GetTypeRegistration, Reflect, ReflectFromReflect, TypeRegistry};
+use std::any::{Any, TypeId};
+
+type ValidateFn = fn(&dyn Reflect) -> Result<(), String>;
+
+/// Check of the invariants of a type, like a `health` which can't exceed the `max_health`, to be registered in the [`TypeRegistry`].
+///
+/// Every edit of the type made through the [`InspectorUi`](super::InspectorUi), including edits of its fields, is checked before
+/// it is kept. Rejected edits are undone, and the error is shown below the value until the next click.
+/// Multi-editing with [`InspectorUi::ui_for_reflect_many`](super::InspectorUi::ui_for_reflect_many) isn't validated.
+#[derive(Clone, Copy)]
+pub struct InspectorValidator {
+    validate: ValidateFn,
+}
+
+impl InspectorValidator {
+    pub fn new(validate: ValidateFn) -> Self {
+        InspectorValidator { validate }
+    }
+
+    pub fn validate(&self, value: &dyn Reflect) -> Result<(), String> {
+        (self.validate)(value)
+    }
+}
+
+/// Extension trait for registering an [`InspectorValidator`] for a type.
+///
+/// ```rust
+/// # use bevy_app::App;
+/// # use bevy_reflect::Reflect;
+/// # use bevy_inspector_egui::reflect_inspector::RegisterInspectorValidator;
+/// #[derive(Reflect)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// App::new().add_inspector_validator::<Health>(|value| {
+///     let health = value.downcast_ref::<Health>().unwrap();
+///     match health.current <= health.max {
+///         true => Ok(()),
+///         false => Err("health can't exceed the maximum".to_string()),
+///     }
+/// });
+/// ```
+pub trait RegisterInspectorValidator {
+    /// Registers `T` if necessary and sets its [`InspectorValidator`], overriding any previous one
+    fn add_inspector_validator<T: Reflect + GetTypeRegistration>(
+        &mut self,
+        validate: ValidateFn,
+    ) -> &mut Self;
+}
+
+impl RegisterInspectorValidator for TypeRegistry {
+    fn add_inspector_validator<T: Reflect + GetTypeRegistration>(
+        &mut self,
+        validate: ValidateFn,
+    ) -> &mut Self {
+        self.register::<T>();
+        self.get_mut(TypeId::of::<T>())
+            .unwrap()
+            .insert(InspectorValidator::new(validate));
+        self
+    }
+}
+
+impl RegisterInspectorValidator for bevy_app::App {
+    fn add_inspector_validator<T: Reflect + GetTypeRegistration>(
+        &mut self,
+        validate: ValidateFn,
+    ) -> &mut Self {
+        self.world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .add_inspector_validator::<T>(validate);
+        self
+    }
+}
+
+/// Sets `value` back to `before`, a [`Reflect::clone_value`] of it.
+///
+/// Applying alone wouldn't remove list elements which were added, so the concrete value is rebuilt if possible.
+pub(super) fn restore(value: &mut dyn Reflect, before: &dyn Reflect, type_registry: &TypeRegistry) {
+    let restored = type_registry
+        .get_type_data::<ReflectFromReflect>(Any::type_id(value))
+        .and_then(|from_reflect| from_reflect.from_reflect(before));
+    match restored {
+        Some(restored) => {
+            let _ = value.set(restored);
+        }
+        None => value.apply(before),
+    }
+}
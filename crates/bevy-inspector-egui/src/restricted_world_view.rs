@@ -1,9 +1,12 @@
 //! A view into the world which may only access certain resources and components
 
 use std::any::TypeId;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 
 use bevy_ecs::{
-    change_detection::MutUntyped, prelude::*, world::unsafe_world_cell::UnsafeWorldCell,
+    change_detection::MutUntyped, component::ComponentTicks, prelude::*,
+    world::unsafe_world_cell::UnsafeWorldCell,
 };
 use bevy_reflect::{Reflect, ReflectFromPtr, TypeRegistry};
 use smallvec::{smallvec, SmallVec};
@@ -47,7 +50,10 @@ type EntityComponent = (Entity, TypeId);
 pub struct RestrictedWorldView<'w> {
     world: UnsafeWorldCell<'w>,
     resources: Allowed<TypeId>,
+    /// Tracked separately from `resources`, since a type can be both a resource and a non-send resource
+    non_send_resources: Allowed<TypeId>,
     components: Allowed<EntityComponent>,
+    tracker: AccessTracker,
 }
 
 #[derive(Clone)]
@@ -70,6 +76,9 @@ impl<T: Clone + PartialEq> Allowed<T> {
     fn nothing() -> Allowed<T> {
         Allowed::AllowList(SmallVec::new())
     }
+    fn allows_everything(&self) -> bool {
+        matches!(self, Allowed::ForbidList(list) if list.is_empty())
+    }
 
     fn allows_access_to(&self, value: T) -> bool {
         match self {
@@ -102,13 +111,12 @@ impl<T: Clone + PartialEq> Allowed<T> {
     {
         match self {
             Allowed::AllowList(list) => {
-                let new = list.clone();
+                let mut new = list.clone();
                 for value in values {
-                    let position = list
+                    let position = new
                         .iter()
                         .position(|item| *item == value)
                         .expect("called `without` without access");
-                    let mut new = list.clone();
                     new.swap_remove(position);
                 }
                 Allowed::AllowList(new)
@@ -122,6 +130,119 @@ impl<T: Clone + PartialEq> Allowed<T> {
     }
 }
 
+/// Single resource or component access, as tracked by [`AccessTracker`]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+enum Access {
+    Resource(TypeId),
+    NonSendResource(TypeId),
+    Component(EntityComponent),
+}
+
+/// Keeps track of which view every split off access was handed out to, to catch splits which
+/// leave the same access in the split view and the rest.
+///
+/// This only exists with `debug_assertions`, in release builds it is zero-sized and does nothing.
+struct AccessTracker {
+    #[cfg(debug_assertions)]
+    owners: std::sync::Arc<std::sync::Mutex<HashMap<Access, u64>>>,
+    #[cfg(debug_assertions)]
+    id: u64,
+    /// Accesses this view was handed out by the view it was split from, with their previous owners
+    #[cfg(debug_assertions)]
+    taken: Vec<(Access, Option<u64>)>,
+}
+
+impl AccessTracker {
+    fn root() -> Self {
+        AccessTracker {
+            #[cfg(debug_assertions)]
+            owners: Default::default(),
+            #[cfg(debug_assertions)]
+            id: Self::next_id(),
+            #[cfg(debug_assertions)]
+            taken: Vec::new(),
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    fn next_id() -> u64 {
+        static NEXT_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+        NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Tracker for the remaining view of a split, which keeps acting as the original view
+    fn rest(&self) -> Self {
+        AccessTracker {
+            #[cfg(debug_assertions)]
+            owners: self.owners.clone(),
+            #[cfg(debug_assertions)]
+            id: self.id,
+            #[cfg(debug_assertions)]
+            taken: Vec::new(),
+        }
+    }
+
+    /// Tracker for a view which is handed out the `accesses` of this view.
+    ///
+    /// Panics with `debug_assertions` if one of them is currently handed out to a different view.
+    fn split(&self, accesses: impl IntoIterator<Item = Access>) -> Self {
+        #[cfg(not(debug_assertions))]
+        let _ = accesses;
+
+        #[cfg(debug_assertions)]
+        {
+            let mut split = AccessTracker {
+                owners: self.owners.clone(),
+                id: Self::next_id(),
+                taken: Vec::new(),
+            };
+            let mut conflict = None;
+            {
+                let mut owners = split
+                    .owners
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                for access in accesses {
+                    let owner = owners.get(&access).copied();
+                    if owner.is_some_and(|owner| owner != self.id) {
+                        conflict = Some(access);
+                        break;
+                    }
+                    owners.insert(access, split.id);
+                    split.taken.push((access, owner));
+                }
+            }
+            debug_assert!(
+                conflict.is_none(),
+                "{conflict:?} is already handed out to another `RestrictedWorldView`"
+            );
+            split
+        }
+        #[cfg(not(debug_assertions))]
+        AccessTracker {}
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for AccessTracker {
+    fn drop(&mut self) {
+        if self.taken.is_empty() {
+            return;
+        }
+        let mut owners = self
+            .owners
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        for (access, previous_owner) in self.taken.drain(..).rev() {
+            match previous_owner {
+                Some(owner) => owners.insert(access, owner),
+                None => owners.remove(&access),
+            };
+        }
+    }
+}
+
 impl<'a> From<&'a mut World> for RestrictedWorldView<'a> {
     fn from(value: &'a mut World) -> Self {
         RestrictedWorldView::new(value)
@@ -136,7 +257,9 @@ impl<'w> RestrictedWorldView<'w> {
         RestrictedWorldView {
             world: world.as_unsafe_world_cell(),
             resources: Allowed::everything(),
+            non_send_resources: Allowed::everything(),
             components: Allowed::everything(),
+            tracker: AccessTracker::root(),
         }
     }
 
@@ -145,17 +268,22 @@ impl<'w> RestrictedWorldView<'w> {
         world: &'w mut World,
     ) -> (RestrictedWorldView<'w>, RestrictedWorldView<'w>) {
         let world = world.as_unsafe_world_cell();
+        let tracker = AccessTracker::root();
 
         // INVARIANTS: `world` is `&mut` so we have access to everything
         let resources = RestrictedWorldView {
             world,
             resources: Allowed::everything(),
+            non_send_resources: Allowed::everything(),
             components: Allowed::nothing(),
+            tracker: tracker.rest(),
         };
         let components = RestrictedWorldView {
             world,
             resources: Allowed::nothing(),
+            non_send_resources: Allowed::nothing(),
             components: Allowed::everything(),
+            tracker: tracker.rest(),
         };
 
         (resources, components)
@@ -165,14 +293,98 @@ impl<'w> RestrictedWorldView<'w> {
         self.world
     }
 
+    /// A view with the same access as this one, which borrows this view for its shorter lifetime.
+    ///
+    /// This is the way to pass the view to a function taking a [`RestrictedWorldView`] by value,
+    /// while continuing to use it afterwards:
+    ///
+    /// ```rust
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_inspector_egui::restricted_world_view::RestrictedWorldView;
+    /// fn helper(_world: RestrictedWorldView<'_>) {}
+    ///
+    /// let mut world = World::new();
+    /// let mut world = RestrictedWorldView::new(&mut world);
+    /// helper(world.reborrow());
+    /// helper(world);
+    /// ```
+    pub fn reborrow(&mut self) -> RestrictedWorldView<'_> {
+        // INVARIANTS: `self` can't be used while the reborrow exists, so they never access anything at the same time
+        RestrictedWorldView {
+            world: self.world,
+            resources: self.resources.clone(),
+            non_send_resources: self.non_send_resources.clone(),
+            components: self.components.clone(),
+            tracker: self.tracker.rest(),
+        }
+    }
+
     /// Whether the resource with the given [`TypeId`] may be accessed from this world view
     pub fn allows_access_to_resource(&self, type_id: TypeId) -> bool {
         self.resources.allows_access_to(type_id)
     }
+    /// Whether the non-send resource with the given [`TypeId`] may be accessed from this world view
+    pub fn allows_access_to_non_send_resource(&self, type_id: TypeId) -> bool {
+        self.non_send_resources.allows_access_to(type_id)
+    }
     /// Whether the given component at the entity may be accessed from this world view
     pub fn allows_access_to_component(&self, component: EntityComponent) -> bool {
         self.components.allows_access_to(component)
     }
+    /// Whether nothing has been split off from this view, so that it may access every resource and component
+    pub fn allows_access_to_everything(&self) -> bool {
+        self.resources.allows_everything()
+            && self.non_send_resources.allows_everything()
+            && self.components.allows_everything()
+    }
+
+    /// Whether all of the given resources and components may be accessed from this world view
+    pub fn allows_access_to_all(
+        &self,
+        resources: &[TypeId],
+        components: &[EntityComponent],
+    ) -> bool {
+        resources
+            .iter()
+            .all(|&type_id| self.allows_access_to_resource(type_id))
+            && components
+                .iter()
+                .all(|&component| self.allows_access_to_component(component))
+    }
+    /// Like [`allows_access_to_all`](RestrictedWorldView::allows_access_to_all), but returns an
+    /// [`Error::NoAccessToResource`] or [`Error::NoAccessToComponent`] for each access which isn't allowed,
+    /// in the order they were passed in.
+    pub fn missing_access(
+        &self,
+        resources: &[TypeId],
+        components: &[EntityComponent],
+    ) -> Vec<Error> {
+        let resources = resources
+            .iter()
+            .filter(|&&type_id| !self.allows_access_to_resource(type_id))
+            .map(|&type_id| Error::NoAccessToResource(type_id));
+        let components = components
+            .iter()
+            .filter(|&&component| !self.allows_access_to_component(component))
+            .map(|&component| Error::NoAccessToComponent(component));
+        resources.chain(components).collect()
+    }
+
+    /// Runs `f` with a shared reference to the whole world, e.g. to run a read-only [`Query`].
+    ///
+    /// This is only possible for views created by [`RestrictedWorldView::new`] from which nothing is split off at the moment,
+    /// because any split off access may be used to mutate the world while `f` runs.
+    /// Panics if the view doesn't [allow access to everything](RestrictedWorldView::allows_access_to_everything).
+    pub fn with_readonly_world<R>(&self, f: impl FnOnce(&World) -> R) -> R {
+        assert!(
+            self.allows_access_to_everything(),
+            "`with_readonly_world` requires a `RestrictedWorldView` with access to everything"
+        );
+        // SAFETY: the view may access everything, and every reference handed out by it borrows `self`,
+        // so there are no mutable references into the world while `f` runs
+        let world = unsafe { self.world.world() };
+        f(world)
+    }
 
     /// Splits this view into one view that only has access the the resource `resource` (`.0`), and the rest (`.1`).
     pub fn split_off_resource(
@@ -185,34 +397,125 @@ impl<'w> RestrictedWorldView<'w> {
         let split = RestrictedWorldView {
             world: self.world,
             resources: Allowed::allow_just(resource),
+            non_send_resources: Allowed::nothing(),
             components: Allowed::nothing(),
+            tracker: self.tracker.split([Access::Resource(resource)]),
         };
         let rest = RestrictedWorldView {
             world: self.world,
             resources: self.resources.without(resource),
+            non_send_resources: self.non_send_resources.clone(),
+            components: self.components.clone(),
+            tracker: self.tracker.rest(),
+        };
+
+        (split, rest)
+    }
+
+    /// Splits this view into one view that only has access the the non-send resource `resource` (`.0`), and the rest (`.1`).
+    pub fn split_off_non_send_resource(
+        &mut self,
+        resource: TypeId,
+    ) -> (RestrictedWorldView<'_>, RestrictedWorldView<'_>) {
+        assert!(self.allows_access_to_non_send_resource(resource));
+
+        // INVARIANTS: `self` had `resource` access, so `split` has access if we remove it from `self`
+        let split = RestrictedWorldView {
+            world: self.world,
+            resources: Allowed::nothing(),
+            non_send_resources: Allowed::allow_just(resource),
+            components: Allowed::nothing(),
+            tracker: self.tracker.split([Access::NonSendResource(resource)]),
+        };
+        let rest = RestrictedWorldView {
+            world: self.world,
+            resources: self.resources.clone(),
+            non_send_resources: self.non_send_resources.without(resource),
             components: self.components.clone(),
+            tracker: self.tracker.rest(),
         };
 
         (split, rest)
     }
 
     /// Like [`RestrictedWorldView::split_off_resource`], but takes `self` and returns `'w` lifetimes.
+    ///
+    /// Returns [`Error::NoAccessToResource`] if the view may not access `R`, and `Ok(None)` if `R` doesn't exist.
+    pub fn try_split_off_resource_typed<R: Resource>(
+        self,
+    ) -> Result<Option<(Mut<'w, R>, RestrictedWorldView<'w>)>, Error> {
+        let type_id = TypeId::of::<R>();
+        if !self.allows_access_to_resource(type_id) {
+            return Err(Error::NoAccessToResource(type_id));
+        }
+
+        // SAFETY: `self` had `R` access, so we have unique access if we remove it from `self`
+        let Some(resource) = (unsafe { self.world().get_resource_mut::<R>() }) else {
+            return Ok(None);
+        };
+
+        let rest = RestrictedWorldView {
+            world: self.world,
+            resources: self.resources.without(type_id),
+            non_send_resources: self.non_send_resources,
+            components: self.components,
+            tracker: self.tracker,
+        };
+
+        Ok(Some((resource, rest)))
+    }
+
+    /// Like [`RestrictedWorldView::try_split_off_resource_typed`], but panics if the view may not access `R`.
+    #[deprecated(since = "0.19.0", note = "use `try_split_off_resource_typed` instead")]
     pub fn split_off_resource_typed<R: Resource>(
         self,
     ) -> Option<(Mut<'w, R>, RestrictedWorldView<'w>)> {
+        match self.try_split_off_resource_typed() {
+            Ok(split) => split,
+            Err(error) => panic!("{error:?}"),
+        }
+    }
+
+    /// Like [`RestrictedWorldView::split_off_non_send_resource`], but takes `self` and returns `'w` lifetimes.
+    ///
+    /// Returns [`Error::NoAccessToResource`] if the view may not access `R`, and `Ok(None)` if `R` doesn't exist.
+    /// Panics when called from a thread other than the main thread.
+    pub fn try_split_off_non_send_resource_typed<R: 'static>(
+        self,
+    ) -> Result<Option<(Mut<'w, R>, RestrictedWorldView<'w>)>, Error> {
         let type_id = TypeId::of::<R>();
-        assert!(self.allows_access_to_resource(type_id));
+        if !self.allows_access_to_non_send_resource(type_id) {
+            return Err(Error::NoAccessToResource(type_id));
+        }
 
         // SAFETY: `self` had `R` access, so we have unique access if we remove it from `self`
-        let resource = unsafe { self.world().get_resource_mut::<R>()? };
+        let Some(resource) = (unsafe { self.world().get_non_send_resource_mut::<R>() }) else {
+            return Ok(None);
+        };
 
         let rest = RestrictedWorldView {
             world: self.world,
-            resources: self.resources.without(type_id),
+            resources: self.resources,
+            non_send_resources: self.non_send_resources.without(type_id),
             components: self.components,
+            tracker: self.tracker,
         };
 
-        Some((resource, rest))
+        Ok(Some((resource, rest)))
+    }
+
+    /// Like [`RestrictedWorldView::try_split_off_non_send_resource_typed`], but panics if the view may not access `R`.
+    #[deprecated(
+        since = "0.19.0",
+        note = "use `try_split_off_non_send_resource_typed` instead"
+    )]
+    pub fn split_off_non_send_resource_typed<R: 'static>(
+        self,
+    ) -> Option<(Mut<'w, R>, RestrictedWorldView<'w>)> {
+        match self.try_split_off_non_send_resource_typed() {
+            Ok(split) => split,
+            Err(error) => panic!("{error:?}"),
+        }
     }
 
     /// Splits this view into one view that only has access the the component `component.1` at the entity `component.0` (`.0`), and the rest (`.1`).
@@ -226,17 +529,53 @@ impl<'w> RestrictedWorldView<'w> {
         let split = RestrictedWorldView {
             world: self.world,
             resources: Allowed::nothing(),
+            non_send_resources: Allowed::nothing(),
             components: Allowed::allow_just(component),
+            tracker: self.tracker.split([Access::Component(component)]),
         };
         let rest = RestrictedWorldView {
             world: self.world,
             resources: self.resources.clone(),
+            non_send_resources: self.non_send_resources.clone(),
             components: self.components.without(component),
+            tracker: self.tracker.rest(),
         };
 
         (split, rest)
     }
 
+    /// Like [`RestrictedWorldView::split_off_component`], but takes `self` and returns `'w` lifetimes.
+    ///
+    /// Returns [`Error::NoAccessToComponent`] if the view may not access `C` at `entity`, and `Ok(None)` if the entity doesn't have `C`.
+    pub fn try_split_off_component_typed<C: Component>(
+        self,
+        entity: Entity,
+    ) -> Result<Option<(Mut<'w, C>, RestrictedWorldView<'w>)>, Error> {
+        let component = (entity, TypeId::of::<C>());
+        if !self.allows_access_to_component(component) {
+            return Err(Error::NoAccessToComponent(component));
+        }
+
+        // SAFETY: `self` had `component` access, so we have unique access if we remove it from `self`
+        let Some(value) = (unsafe {
+            self.world()
+                .get_entity(entity)
+                .and_then(|entity| entity.get_mut::<C>())
+        }) else {
+            return Ok(None);
+        };
+
+        let rest = RestrictedWorldView {
+            world: self.world,
+            resources: self.resources,
+            non_send_resources: self.non_send_resources,
+            components: self.components.without(component),
+            tracker: self.tracker,
+        };
+
+        Ok(Some((value, rest)))
+    }
+
     /// Splits this view into one view that only has access the the component-entity pairs `components` (`.0`), and the rest (`.1`)
     pub fn split_off_components(
         &mut self,
@@ -250,16 +589,65 @@ impl<'w> RestrictedWorldView<'w> {
         let split = RestrictedWorldView {
             world: self.world,
             resources: Allowed::nothing(),
+            non_send_resources: Allowed::nothing(),
             components: Allowed::allow(components),
+            tracker: self.tracker.split(components.map(Access::Component)),
         };
         let rest = RestrictedWorldView {
             world: self.world,
             resources: self.resources.clone(),
+            non_send_resources: self.non_send_resources.clone(),
             components: self.components.without_many(components),
+            tracker: self.tracker.rest(),
         };
 
         (split, rest)
     }
+
+    /// Splits this view into one view that only has access to the components of `entity` (`.0`), and the rest (`.1`).
+    ///
+    /// Components of the entity which this view has no access to are not included in either view.
+    pub fn split_off_entity(
+        &mut self,
+        entity: Entity,
+    ) -> (RestrictedWorldView<'_>, RestrictedWorldView<'_>) {
+        let components: SmallVec<[EntityComponent; 8]> = self
+            .entity_component_types(entity)
+            .filter(|&component| self.allows_access_to_component(component))
+            .collect();
+
+        // INVARIANTS: `self` had access to `components`, so `split` has access if we remove them from `self`
+        let split = RestrictedWorldView {
+            world: self.world,
+            resources: Allowed::nothing(),
+            non_send_resources: Allowed::nothing(),
+            components: Allowed::allow(components.iter().copied()),
+            tracker: self
+                .tracker
+                .split(components.iter().copied().map(Access::Component)),
+        };
+        let rest = RestrictedWorldView {
+            world: self.world,
+            resources: self.resources.clone(),
+            non_send_resources: self.non_send_resources.clone(),
+            components: self.components.without_many(components.into_iter()),
+            tracker: self.tracker.rest(),
+        };
+
+        (split, rest)
+    }
+
+    /// The components of the `entity`, according to its archetype
+    fn entity_component_types(&self, entity: Entity) -> impl Iterator<Item = EntityComponent> + 'w {
+        let components = self.world.components();
+        // this only reads the archetype metadata and doesn't access any component values
+        self.world
+            .get_entity(entity)
+            .into_iter()
+            .flat_map(|entity| entity.archetype().components())
+            .filter_map(move |component_id| components.get_info(component_id)?.type_id())
+            .map(move |type_id| (entity, type_id))
+    }
 }
 
 /// Some safe methods for getting values out of the [`RestrictedWorldView`].
@@ -269,12 +657,44 @@ impl<'w> RestrictedWorldView<'w> {
         self.world().entities().contains(entity)
     }
 
+    /// Gets a shared reference to the resource of the given type, which isn't marked as changed
+    pub fn get_resource<R: Resource>(&self) -> Result<&R, Error> {
+        let type_id = TypeId::of::<R>();
+        if !self.allows_access_to_resource(type_id) {
+            return Err(Error::NoAccessToResource(type_id));
+        }
+
+        // SAFETY: we have access to `type_id` and borrow `&self`, so no mutable reference to it can exist
+        unsafe {
+            self.world()
+                .get_resource::<R>()
+                .ok_or(Error::ResourceDoesNotExist(type_id))
+        }
+    }
+
     /// Gets a mutable reference to the resource of the given type
     pub fn get_resource_mut<R: Resource>(&mut self) -> Result<Mut<'_, R>, Error> {
         // SAFETY: &mut self
         unsafe { self.get_resource_unchecked_mut() }
     }
 
+    /// Gets a mutable reference to the non-send resource of the given type.
+    ///
+    /// Panics when called from a thread other than the main thread, like [`World::get_non_send_resource_mut`].
+    pub fn get_non_send_resource_mut<R: 'static>(&mut self) -> Result<Mut<'_, R>, Error> {
+        let type_id = TypeId::of::<R>();
+        if !self.allows_access_to_non_send_resource(type_id) {
+            return Err(Error::NoAccessToResource(type_id));
+        }
+
+        // SAFETY: we have access to `type_id` and borrow `&mut self`
+        unsafe {
+            self.world()
+                .get_non_send_resource_mut::<R>()
+                .ok_or(Error::ResourceDoesNotExist(type_id))
+        }
+    }
+
     /// Gets mutable reference to two resources. Panics if `R1 = R2`.
     pub fn get_two_resources_mut<R1: Resource, R2: Resource>(
         &mut self,
@@ -307,6 +727,62 @@ impl<'w> RestrictedWorldView<'w> {
         Ok(value)
     }
 
+    /// Whether the component of the entity was changed since the system accessing the world last ran.
+    ///
+    /// Unlike [`get_entity_component_reflect`](RestrictedWorldView::get_entity_component_reflect) this only needs
+    /// to read the change ticks, so the component is never marked as changed.
+    pub fn is_entity_component_changed(
+        &self,
+        entity: Entity,
+        component: TypeId,
+    ) -> Result<bool, Error> {
+        let ticks = self.get_entity_component_ticks(entity, component)?;
+        let world = self.world();
+        Ok(ticks.is_changed(world.last_change_tick(), world.change_tick()))
+    }
+
+    /// The ticks at which the component of the entity was added and last changed
+    pub fn get_entity_component_ticks(
+        &self,
+        entity: Entity,
+        component: TypeId,
+    ) -> Result<ComponentTicks, Error> {
+        if !self.allows_access_to_component((entity, component)) {
+            return Err(Error::NoAccessToComponent((entity, component)));
+        }
+
+        let world = self.world();
+        let component_id = world
+            .components()
+            .get_id(component)
+            .ok_or(Error::NoComponentId(component))?;
+        let entity_cell = world
+            .get_entity(entity)
+            .ok_or(Error::ComponentDoesNotExist((entity, component)))?;
+        // SAFETY: we have access to (entity, component) and only read its ticks
+        unsafe { entity_cell.get_change_ticks_by_id(component_id) }
+            .ok_or(Error::ComponentDoesNotExist((entity, component)))
+    }
+
+    /// Gets a mutable reference to the component `C` of the `entity`
+    pub fn get_entity_component_mut<C: Component>(
+        &mut self,
+        entity: Entity,
+    ) -> Result<Mut<'_, C>, Error> {
+        let component = (entity, TypeId::of::<C>());
+        if !self.allows_access_to_component(component) {
+            return Err(Error::NoAccessToComponent(component));
+        }
+
+        // SAFETY: we have access to (entity, C) and borrow `&mut self`
+        unsafe {
+            self.world()
+                .get_entity(entity)
+                .and_then(|entity| entity.get_mut::<C>())
+                .ok_or(Error::ComponentDoesNotExist(component))
+        }
+    }
+
     /// Gets a mutable reference in form of a [`&mut dyn Reflect`](bevy_reflect::Reflect) to the resource given by `type_id`.
     ///
     /// Returns an error if the type does not register [`Reflect`].
@@ -334,8 +810,9 @@ impl<'w> RestrictedWorldView<'w> {
                 .ok_or(Error::ResourceDoesNotExist(type_id))?
         };
 
+        let reflect_from_ptr = type_registry.reflect_from_ptr(type_id)?;
         // SAFETY: value is of type type_id
-        let value = unsafe { mut_untyped_to_reflect(value, type_registry, type_id)? };
+        let value = unsafe { mut_untyped_to_reflect(value, reflect_from_ptr, type_id) };
 
         Ok(value)
     }
@@ -351,6 +828,37 @@ impl<'w> RestrictedWorldView<'w> {
         component: TypeId,
         type_registry: &TypeRegistry,
     ) -> Result<(&'_ mut dyn Reflect, bool, impl FnOnce() + '_), Error> {
+        let value = self.entity_component_untyped(entity, component)?;
+        let changed = value.is_changed();
+        let reflect_from_ptr = type_registry.reflect_from_ptr(component)?;
+        // SAFETY: value is of type component
+        let (value, set_changed) =
+            unsafe { mut_untyped_to_reflect(value, reflect_from_ptr, component) };
+        Ok((value, changed, set_changed))
+    }
+
+    /// Like [`get_entity_component_reflect`](RestrictedWorldView::get_entity_component_reflect),
+    /// but the type data is looked up through a [`TypeRegistryCache`].
+    pub fn get_entity_component_reflect_cached(
+        &mut self,
+        entity: Entity,
+        component: TypeId,
+        cache: &TypeRegistryCache<'_>,
+    ) -> Result<(&'_ mut dyn Reflect, bool, impl FnOnce() + '_), Error> {
+        let value = self.entity_component_untyped(entity, component)?;
+        let changed = value.is_changed();
+        let reflect_from_ptr = cache.reflect_from_ptr(component)?;
+        // SAFETY: value is of type component
+        let (value, set_changed) =
+            unsafe { mut_untyped_to_reflect(value, reflect_from_ptr, component) };
+        Ok((value, changed, set_changed))
+    }
+
+    fn entity_component_untyped(
+        &mut self,
+        entity: Entity,
+        component: TypeId,
+    ) -> Result<MutUntyped<'_>, Error> {
         if !self.allows_access_to_component((entity, component)) {
             return Err(Error::NoAccessToComponent((entity, component)));
         }
@@ -370,15 +878,11 @@ impl<'w> RestrictedWorldView<'w> {
                 .get_mut_by_id(component_id)
                 .ok_or(Error::ComponentDoesNotExist((entity, component)))?
         };
-        let changed = value.is_changed();
-
-        let (value, set_changed) =
-            // SAFETY: value is of type component
-            unsafe { mut_untyped_to_reflect(value, type_registry, component) }?;
-        Ok((value, changed, set_changed))
+        Ok(value)
     }
 
     // SAFETY: must ensure distinct access
+    #[allow(clippy::mut_from_ref)]
     pub(crate) unsafe fn get_entity_component_reflect_unchecked(
         &self,
         entity: Entity,
@@ -405,30 +909,85 @@ impl<'w> RestrictedWorldView<'w> {
                 .ok_or(Error::ComponentDoesNotExist((entity, component)))?
         };
 
+        let reflect_from_ptr = type_registry.reflect_from_ptr(component)?;
         // SAFETY: value is of type component
-        unsafe { mut_untyped_to_reflect(value, type_registry, component) }
+        Ok(unsafe { mut_untyped_to_reflect(value, reflect_from_ptr, component) })
     }
 }
 
 // SAFETY: MutUntyped is of type with `type_id`
 unsafe fn mut_untyped_to_reflect<'a>(
     value: MutUntyped<'a>,
-    type_registry: &TypeRegistry,
+    reflect_from_ptr: &ReflectFromPtr,
     type_id: TypeId,
-) -> Result<(&'a mut dyn Reflect, impl FnOnce() + 'a), Error> {
-    let registration = type_registry
-        .get(type_id)
-        .ok_or(Error::NoTypeRegistration(type_id))?;
-    let reflect_from_ptr = registration
-        .data::<ReflectFromPtr>()
-        .ok_or(Error::NoTypeData(type_id, "ReflectFromPtr"))?;
-
+) -> (&'a mut dyn Reflect, impl FnOnce() + 'a) {
     let (ptr, set_changed) = crate::utils::mut_untyped_split(value);
     assert_eq!(reflect_from_ptr.type_id(), type_id);
     // SAFETY: ptr is of type type_id as required in safety contract, type_id was checked above
     let value = unsafe { reflect_from_ptr.as_reflect_ptr_mut(ptr) };
 
-    Ok((value, set_changed))
+    (value, set_changed)
+}
+
+/// Lookup of the [`ReflectFromPtr`] needed to access components and resources as [`&mut dyn Reflect`](bevy_reflect::Reflect)
+trait ReflectFromPtrLookup {
+    fn reflect_from_ptr(&self, type_id: TypeId) -> Result<&ReflectFromPtr, Error>;
+}
+
+impl ReflectFromPtrLookup for TypeRegistry {
+    fn reflect_from_ptr(&self, type_id: TypeId) -> Result<&ReflectFromPtr, Error> {
+        self.get(type_id)
+            .ok_or(Error::NoTypeRegistration(type_id))?
+            .data::<ReflectFromPtr>()
+            .ok_or(Error::NoTypeData(type_id, "ReflectFromPtr"))
+    }
+}
+
+/// Remembers the type data looked up in a [`TypeRegistry`], for displaying many values of the same few types like in the world inspector.
+///
+/// The cache borrows the registry, so registrations cannot change while it is alive.
+/// It is meant to be created once per frame.
+pub struct TypeRegistryCache<'a> {
+    type_registry: &'a TypeRegistry,
+    reflect_from_ptr: RefCell<HashMap<TypeId, Option<&'a ReflectFromPtr>>>,
+    registry_lookups: Cell<usize>,
+}
+
+impl<'a> TypeRegistryCache<'a> {
+    pub fn new(type_registry: &'a TypeRegistry) -> Self {
+        TypeRegistryCache {
+            type_registry,
+            reflect_from_ptr: RefCell::default(),
+            registry_lookups: Cell::new(0),
+        }
+    }
+
+    pub fn type_registry(&self) -> &'a TypeRegistry {
+        self.type_registry
+    }
+
+    /// Number of lookups which went to the underlying [`TypeRegistry`] instead of the cache
+    pub fn registry_lookups(&self) -> usize {
+        self.registry_lookups.get()
+    }
+}
+
+impl ReflectFromPtrLookup for TypeRegistryCache<'_> {
+    fn reflect_from_ptr(&self, type_id: TypeId) -> Result<&ReflectFromPtr, Error> {
+        let cached = *self
+            .reflect_from_ptr
+            .borrow_mut()
+            .entry(type_id)
+            .or_insert_with(|| {
+                self.registry_lookups.set(self.registry_lookups.get() + 1);
+                self.type_registry.reflect_from_ptr(type_id).ok()
+            });
+        match cached {
+            Some(reflect_from_ptr) => Ok(reflect_from_ptr),
+            // not cached, so that the error tells why the lookup failed
+            None => self.type_registry.reflect_from_ptr(type_id),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -438,7 +997,7 @@ mod tests {
     use bevy_ecs::prelude::*;
     use bevy_reflect::{Reflect, TypeRegistry};
 
-    use super::RestrictedWorldView;
+    use super::{Allowed, RestrictedWorldView, TypeRegistryCache};
 
     #[derive(Resource)]
     struct A(String);
@@ -447,6 +1006,29 @@ mod tests {
     #[reflect(Resource)]
     struct B(String);
 
+    /// Clears `B` through the view, which it takes by value
+    fn clear_b(mut world: RestrictedWorldView<'_>) {
+        world.get_resource_mut::<B>().unwrap().0.clear();
+    }
+
+    #[test]
+    fn reborrow() {
+        let mut world = World::new();
+        world.insert_resource(A("a".to_string()));
+        world.insert_resource(B("b".to_string()));
+
+        let mut world = RestrictedWorldView::new(&mut world);
+        let (mut a_view, mut rest) = world.split_off_resource(TypeId::of::<A>());
+
+        clear_b(rest.reborrow());
+        // the reborrow has the same restrictions as the original
+        assert!(!rest.reborrow().allows_access_to_resource(TypeId::of::<A>()));
+
+        assert_eq!(rest.get_resource_mut::<B>().unwrap().0, "");
+        assert!(rest.get_resource_mut::<A>().is_err());
+        assert_eq!(a_view.get_resource_mut::<A>().unwrap().0, "a");
+    }
+
     #[test]
     fn disjoint_resource_access() {
         let mut world = World::new();
@@ -484,6 +1066,101 @@ mod tests {
         b.0.downcast_mut::<B>().unwrap().0.clear();
     }
 
+    #[test]
+    fn disjoint_non_send_resource_access() {
+        let mut world = World::new();
+        world.insert_resource(B("resource".to_string()));
+        world.insert_non_send_resource(B("non-send".to_string()));
+
+        let mut world = RestrictedWorldView::new(&mut world);
+        let (mut non_send_view, mut world) = world.split_off_non_send_resource(TypeId::of::<B>());
+
+        // the resource of the same type is still accessible
+        assert!(world.allows_access_to_resource(TypeId::of::<B>()));
+        assert!(!world.allows_access_to_non_send_resource(TypeId::of::<B>()));
+        assert!(world.get_non_send_resource_mut::<B>().is_err());
+        assert!(non_send_view.get_resource_mut::<B>().is_err());
+
+        let mut non_send = non_send_view.get_non_send_resource_mut::<B>().unwrap();
+        let mut resource = world.get_resource_mut::<B>().unwrap();
+        assert_eq!(non_send.0, "non-send");
+        assert_eq!(resource.0, "resource");
+        non_send.0.clear();
+        resource.0.clear();
+    }
+
+    #[test]
+    fn try_split_off_resource_typed() {
+        let mut world = World::new();
+        world.insert_resource(A("a".to_string()));
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let (mut a, _) = world
+            .reborrow()
+            .try_split_off_resource_typed::<A>()
+            .unwrap()
+            .unwrap();
+        a.0.clear();
+
+        // `B` may be accessed, but doesn't exist
+        assert!(matches!(
+            world.reborrow().try_split_off_resource_typed::<B>(),
+            Ok(None)
+        ));
+
+        let (_a_view, rest) = world.split_off_resource(TypeId::of::<A>());
+        assert!(matches!(
+            rest.try_split_off_resource_typed::<A>(),
+            Err(super::Error::NoAccessToResource(resource)) if resource == TypeId::of::<A>()
+        ));
+    }
+
+    #[test]
+    fn get_resource() {
+        let mut world = World::new();
+        world.insert_resource(A("a".to_string()));
+        world.insert_resource(B("b".to_string()));
+        world.clear_trackers();
+
+        let mut view = RestrictedWorldView::new(&mut world);
+        let (a_view, rest) = view.split_off_resource(TypeId::of::<A>());
+        assert_eq!(a_view.get_resource::<A>().unwrap().0, "a");
+        assert_eq!(rest.get_resource::<B>().unwrap().0, "b");
+        assert!(a_view.get_resource::<B>().is_err());
+        assert!(rest.get_resource::<A>().is_err());
+
+        assert!(!world.is_resource_changed::<A>());
+        assert!(!world.is_resource_changed::<B>());
+    }
+
+    #[test]
+    fn try_split_off_non_send_resource_typed() {
+        let mut world = World::new();
+        world.insert_non_send_resource(A("a".to_string()));
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let (mut a, _) = world
+            .reborrow()
+            .try_split_off_non_send_resource_typed::<A>()
+            .unwrap()
+            .unwrap();
+        a.0.clear();
+
+        // `B` may be accessed, but doesn't exist
+        assert!(matches!(
+            world
+                .reborrow()
+                .try_split_off_non_send_resource_typed::<B>(),
+            Ok(None)
+        ));
+
+        let (_a_view, rest) = world.split_off_non_send_resource(TypeId::of::<A>());
+        assert!(matches!(
+            rest.try_split_off_non_send_resource_typed::<A>(),
+            Err(super::Error::NoAccessToResource(resource)) if resource == TypeId::of::<A>()
+        ));
+    }
+
     #[test]
     fn get_two_resources_mut() {
         let mut world = World::new();
@@ -514,6 +1191,64 @@ mod tests {
         assert!(!b_remaining.allows_access_to_resource(TypeId::of::<B>()));
     }
 
+    #[test]
+    fn missing_access() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let mut world = RestrictedWorldView::new(&mut world);
+        let component = (entity, TypeId::of::<B>());
+
+        let (_a_view, mut world) = world.split_off_resource(TypeId::of::<A>());
+        let (_component_view, world) = world.split_off_component(component);
+
+        let resources = [TypeId::of::<A>(), TypeId::of::<B>()];
+        let components = [component, (entity, TypeId::of::<A>())];
+        assert!(!world.allows_access_to_all(&resources, &components));
+        assert!(world.allows_access_to_all(&resources[1..], &components[1..]));
+
+        let missing = world.missing_access(&resources, &components);
+        assert!(matches!(
+            missing.as_slice(),
+            [
+                super::Error::NoAccessToResource(resource),
+                super::Error::NoAccessToComponent(missing_component),
+            ] if *resource == TypeId::of::<A>() && *missing_component == component
+        ));
+        assert!(world
+            .missing_access(&resources[1..], &components[1..])
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is already handed out")]
+    fn double_hand_out() {
+        let mut world = World::new();
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let (_a_view, rest) = world.split_off_resource(TypeId::of::<A>());
+        // a split which forgot to remove `A` from the rest
+        let mut rest = RestrictedWorldView {
+            resources: Allowed::everything(),
+            ..rest
+        };
+        let _ = rest.split_off_resource(TypeId::of::<A>());
+    }
+
+    #[test]
+    fn repeated_split() {
+        let mut world = World::new();
+        world.insert_resource(A("a".to_string()));
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        for _ in 0..2 {
+            let (mut a_view, _) = world.split_off_resource(TypeId::of::<A>());
+            let (mut a_view, _) = a_view.split_off_resource(TypeId::of::<A>());
+            a_view.get_resource_mut::<A>().unwrap().0.push('a');
+        }
+        assert_eq!(world.get_resource_mut::<A>().unwrap().0, "aaa");
+    }
+
     #[derive(Component, Reflect)]
     struct ComponentA(String);
 
@@ -539,4 +1274,145 @@ mod tests {
         component.0.downcast_mut::<ComponentA>().unwrap().0.clear();
         resource.0.clear();
     }
+
+    #[test]
+    fn try_split_off_component_typed() {
+        let mut world = World::new();
+        world.insert_resource(A("a".to_string()));
+        let entity = world.spawn(ComponentA("a".to_string())).id();
+        let other = world.spawn(ComponentA("other".to_string())).id();
+
+        let view = RestrictedWorldView::new(&mut world);
+        let (mut component, mut rest) = view
+            .try_split_off_component_typed::<ComponentA>(entity)
+            .unwrap()
+            .unwrap();
+        assert!(!rest.allows_access_to_component((entity, TypeId::of::<ComponentA>())));
+        assert!(rest.allows_access_to_component((other, TypeId::of::<ComponentA>())));
+
+        let mut resource = rest.get_resource_mut::<A>().unwrap();
+        component.0.push('b');
+        resource.0.push('c');
+
+        assert_eq!(world.get::<ComponentA>(entity).unwrap().0, "ab");
+        assert_eq!(world.resource::<A>().0, "ac");
+
+        let view = RestrictedWorldView::new(&mut world);
+        assert!(matches!(
+            view.try_split_off_component_typed::<ComponentB>(entity),
+            Ok(None)
+        ));
+
+        let mut view = RestrictedWorldView::new(&mut world);
+        let component = (entity, TypeId::of::<ComponentA>());
+        let (_component_view, rest) = view.split_off_component(component);
+        assert!(matches!(
+            rest.try_split_off_component_typed::<ComponentA>(entity),
+            Err(super::Error::NoAccessToComponent(c)) if c == component
+        ));
+    }
+
+    #[derive(Component, Reflect)]
+    struct ComponentB(u32);
+
+    #[test]
+    fn split_off_entity() {
+        let mut type_registry = TypeRegistry::empty();
+        type_registry.register::<ComponentA>();
+        type_registry.register::<ComponentB>();
+
+        let mut world = World::new();
+        let entity = world
+            .spawn((ComponentA("a".to_string()), ComponentB(1)))
+            .id();
+        let other = world.spawn(ComponentA("other".to_string())).id();
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let (mut entity_view, mut rest) = world.split_off_entity(entity);
+        for component in [TypeId::of::<ComponentA>(), TypeId::of::<ComponentB>()] {
+            assert!(entity_view
+                .get_entity_component_reflect(entity, component, &type_registry)
+                .is_ok());
+            assert!(rest
+                .get_entity_component_reflect(entity, component, &type_registry)
+                .is_err());
+        }
+        assert!(!entity_view.allows_access_to_component((other, TypeId::of::<ComponentA>())));
+        assert!(rest.allows_access_to_component((other, TypeId::of::<ComponentA>())));
+
+        // splitting an allow list removes every component from the rest
+        let (_, rest_of_entity_view) = entity_view.split_off_entity(entity);
+        assert!(
+            !rest_of_entity_view.allows_access_to_component((entity, TypeId::of::<ComponentA>()))
+        );
+        assert!(
+            !rest_of_entity_view.allows_access_to_component((entity, TypeId::of::<ComponentB>()))
+        );
+    }
+
+    #[derive(Component)]
+    struct Unregistered;
+
+    #[test]
+    fn cached_type_registry_lookups() {
+        let mut type_registry = TypeRegistry::empty();
+        type_registry.register::<ComponentA>();
+
+        let mut world = World::new();
+        let entities: Vec<_> = (0..100)
+            .map(|i| world.spawn(ComponentA(i.to_string())).id())
+            .collect();
+        let unregistered = world.spawn(Unregistered).id();
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let cache = TypeRegistryCache::new(&type_registry);
+        for &entity in &entities {
+            let (component, ..) = world
+                .get_entity_component_reflect_cached(entity, TypeId::of::<ComponentA>(), &cache)
+                .unwrap();
+            assert!(component.is::<ComponentA>());
+        }
+        assert_eq!(cache.registry_lookups(), 1);
+
+        for _ in 0..2 {
+            let result = world.get_entity_component_reflect_cached(
+                unregistered,
+                TypeId::of::<Unregistered>(),
+                &cache,
+            );
+            assert!(result.is_err());
+        }
+        assert_eq!(cache.registry_lookups(), 2);
+    }
+
+    #[test]
+    fn readonly_query_with_full_access() {
+        let mut world = World::new();
+        world.spawn(ComponentA("a".to_string()));
+        world.spawn(ComponentA("b".to_string()));
+        world.spawn(ComponentB(1));
+        let mut query = world.query::<&ComponentA>();
+
+        let world = RestrictedWorldView::new(&mut world);
+        assert!(world.allows_access_to_everything());
+        let mut names = world.with_readonly_world(|world| {
+            query
+                .iter(world)
+                .map(|component| component.0.clone())
+                .collect::<Vec<_>>()
+        });
+        names.sort();
+        assert_eq!(names, ["a", "b"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "requires a `RestrictedWorldView` with access to everything")]
+    fn readonly_world_after_split() {
+        let mut world = World::new();
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let (_a_view, rest) = world.split_off_resource(TypeId::of::<A>());
+        assert!(!rest.allows_access_to_everything());
+        rest.with_readonly_world(|_| {});
+    }
 }
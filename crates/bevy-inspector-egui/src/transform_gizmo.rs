@@ -0,0 +1,324 @@
+//! Handles for moving the selected entity around in the viewport, drawn with [`bevy_gizmos`].
+//!
+//! The [`TransformGizmoPlugin`] shows the handles while exactly one entity is in the [`SelectedEntities`] resource,
+//! which can be shared with the [`hierarchy_ui`](crate::bevy_inspector::hierarchy::hierarchy_ui) using [`World::resource_scope`].
+//! Dragging one of the axis handles moves the entity along that axis by writing its [`Transform`],
+//! so an inspector showing the entity stays in sync.
+//!
+//! For now, only translation handles are supported.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::transform_gizmo::TransformGizmoPlugin;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(bevy_egui::EguiPlugin)
+//!         .add_plugins(TransformGizmoPlugin)
+//!         .run();
+//! }
+//! ```
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiContext;
+use bevy_gizmos::gizmos::Gizmos;
+use bevy_hierarchy::Parent;
+use bevy_input::{mouse::MouseButton, Input};
+use bevy_math::{Quat, Ray, Vec2, Vec3};
+use bevy_render::{camera::Camera, color::Color};
+use bevy_transform::components::{GlobalTransform, Transform};
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::bevy_inspector::hierarchy::SelectedEntities;
+
+/// Length of the handles, relative to the distance of the entity to the camera
+const HANDLE_LENGTH: f32 = 0.15;
+/// How close the cursor needs to be to a handle to grab it, in logical pixels
+const GRAB_DISTANCE: f32 = 8.0;
+
+/// Initializes the [`SelectedEntities`] and [`TransformGizmoState`] resources, and draws and updates the gizmo.
+///
+/// Requires the `EguiPlugin` and the `GizmoPlugin` from `bevy_gizmos`, which is part of the `DefaultPlugins`.
+pub struct TransformGizmoPlugin;
+
+impl Plugin for TransformGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectedEntities>()
+            .init_resource::<TransformGizmoState>()
+            .add_systems(Update, (drag_gizmo, draw_gizmo).chain());
+    }
+}
+
+/// One of the handles of the gizmo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GizmoAxis {
+    X,
+    Y,
+    Z,
+}
+
+impl GizmoAxis {
+    const ALL: [GizmoAxis; 3] = [GizmoAxis::X, GizmoAxis::Y, GizmoAxis::Z];
+
+    fn direction(self) -> Vec3 {
+        match self {
+            GizmoAxis::X => Vec3::X,
+            GizmoAxis::Y => Vec3::Y,
+            GizmoAxis::Z => Vec3::Z,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            GizmoAxis::X => Color::RED,
+            GizmoAxis::Y => Color::GREEN,
+            GizmoAxis::Z => Color::BLUE,
+        }
+    }
+}
+
+/// Which handle of the gizmo is hovered or dragged at the moment
+#[derive(Resource, Default, Debug)]
+pub struct TransformGizmoState {
+    hovered: Option<GizmoAxis>,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    entity: Entity,
+    axis: GizmoAxis,
+    /// World space position of the entity when the drag started
+    origin: Vec3,
+    /// Position along the axis where the handle was grabbed
+    grabbed_at: f32,
+    start_translation: Vec3,
+}
+
+impl TransformGizmoState {
+    /// The handle which is dragged, or otherwise the one under the cursor
+    pub fn active_axis(&self) -> Option<GizmoAxis> {
+        self.drag.map(|drag| drag.axis).or(self.hovered)
+    }
+
+    /// Whether a handle is being dragged, in which case mouse input should probably not be used for anything else
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+}
+
+fn drag_gizmo(
+    mut state: ResMut<TransformGizmoState>,
+    selected: Res<SelectedEntities>,
+    mouse: Res<Input<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut egui_contexts: Query<&mut EguiContext, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut transforms: Query<(&mut Transform, &GlobalTransform, Option<&Parent>)>,
+    parents: Query<&GlobalTransform>,
+) {
+    let Some((entity, (mut transform, global_transform, parent))) = single_selected(&selected)
+        .and_then(|entity| Some((entity, transforms.get_mut(entity).ok()?)))
+    else {
+        *state = TransformGizmoState::default();
+        return;
+    };
+    let Some(cursor) = windows.get_single().ok().and_then(Window::cursor_position) else {
+        state.hovered = None;
+        return;
+    };
+    let Some((camera, camera_transform)) = active_camera(&cameras) else {
+        return;
+    };
+    let Some(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+
+    if let Some(drag) = state.drag {
+        if drag.entity != entity || !mouse.pressed(MouseButton::Left) {
+            state.drag = None;
+        } else {
+            let direction = drag.axis.direction();
+            if let Some(position) = closest_point_on_axis(drag.origin, direction, ray) {
+                let offset = direction * (position - drag.grabbed_at);
+                // the offset is in world space, but the translation is relative to the parent
+                let offset = match parent.and_then(|parent| parents.get(parent.get()).ok()) {
+                    Some(parent_transform) => parent_transform
+                        .affine()
+                        .inverse()
+                        .transform_vector3(offset),
+                    None => offset,
+                };
+                let translation = drag.start_translation + offset;
+                if transform.translation != translation {
+                    transform.translation = translation;
+                }
+            }
+            return;
+        }
+    }
+
+    let pointer_over_egui = egui_contexts
+        .get_single_mut()
+        .is_ok_and(|mut egui_context| egui_context.get_mut().wants_pointer_input());
+    let origin = global_transform.translation();
+    let length = handle_length(camera_transform, origin);
+    state.hovered = if pointer_over_egui {
+        None
+    } else {
+        hovered_axis(camera, camera_transform, origin, length, cursor)
+    };
+
+    if let Some(axis) = state.hovered {
+        if mouse.just_pressed(MouseButton::Left) {
+            state.drag =
+                closest_point_on_axis(origin, axis.direction(), ray).map(|grabbed_at| Drag {
+                    entity,
+                    axis,
+                    origin,
+                    grabbed_at,
+                    start_translation: transform.translation,
+                });
+        }
+    }
+}
+
+fn draw_gizmo(
+    state: Res<TransformGizmoState>,
+    selected: Res<SelectedEntities>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    global_transforms: Query<&GlobalTransform>,
+    mut gizmos: Gizmos,
+) {
+    let Some(global_transform) =
+        single_selected(&selected).and_then(|entity| global_transforms.get(entity).ok())
+    else {
+        return;
+    };
+    let Some((_, camera_transform)) = active_camera(&cameras) else {
+        return;
+    };
+
+    let origin = global_transform.translation();
+    let length = handle_length(camera_transform, origin);
+    for axis in GizmoAxis::ALL {
+        let color = match state.active_axis() == Some(axis) {
+            true => Color::YELLOW,
+            false => axis.color(),
+        };
+        let end = origin + axis.direction() * length;
+        gizmos.line(origin, end, color);
+        gizmos.sphere(end, Quat::IDENTITY, length * 0.04, color);
+    }
+}
+
+fn single_selected(selected: &SelectedEntities) -> Option<Entity> {
+    match selected.as_slice() {
+        &[entity] => Some(entity),
+        _ => None,
+    }
+}
+
+fn active_camera<'a>(
+    cameras: &'a Query<(&Camera, &GlobalTransform)>,
+) -> Option<(&'a Camera, &'a GlobalTransform)> {
+    cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+}
+
+fn handle_length(camera_transform: &GlobalTransform, origin: Vec3) -> f32 {
+    camera_transform.translation().distance(origin) * HANDLE_LENGTH
+}
+
+/// The axis whose handle is closest to the cursor on screen, if any is within [`GRAB_DISTANCE`]
+fn hovered_axis(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    origin: Vec3,
+    length: f32,
+    cursor: Vec2,
+) -> Option<GizmoAxis> {
+    let start = camera.world_to_viewport(camera_transform, origin)?;
+    GizmoAxis::ALL
+        .into_iter()
+        .filter_map(|axis| {
+            let end = origin + axis.direction() * length;
+            let end = camera.world_to_viewport(camera_transform, end)?;
+            Some((axis, distance_to_segment(cursor, start, end)))
+        })
+        .filter(|&(_, distance)| distance <= GRAB_DISTANCE)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(axis, _)| axis)
+}
+
+fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    if segment.length_squared() == 0.0 {
+        return point.distance(start);
+    }
+    let t = ((point - start).dot(segment) / segment.length_squared()).clamp(0.0, 1.0);
+    point.distance(start + segment * t)
+}
+
+/// The position `t` of the point `origin + t * direction` on the axis which is closest to the `ray`,
+/// or `None` if they are parallel
+fn closest_point_on_axis(origin: Vec3, direction: Vec3, ray: Ray) -> Option<f32> {
+    let offset = origin - ray.origin;
+    let a = direction.dot(direction);
+    let b = direction.dot(ray.direction);
+    let c = ray.direction.dot(ray.direction);
+    let d = direction.dot(offset);
+    let e = ray.direction.dot(offset);
+
+    let denominator = a * c - b * b;
+    if denominator.abs() <= f32::EPSILON {
+        return None;
+    }
+    Some((b * e - c * d) / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Ray, Vec2, Vec3};
+
+    #[test]
+    fn closest_point_on_axis() {
+        // looking down at the x axis from above the point x = 3
+        let ray = Ray {
+            origin: Vec3::new(3.0, 10.0, 0.0),
+            direction: Vec3::NEG_Y,
+        };
+        let t = super::closest_point_on_axis(Vec3::new(1.0, 0.0, 0.0), Vec3::X, ray).unwrap();
+        assert!((t - 2.0).abs() < 1e-5);
+
+        let parallel = Ray {
+            origin: Vec3::new(0.0, 1.0, 0.0),
+            direction: Vec3::X,
+        };
+        assert_eq!(
+            super::closest_point_on_axis(Vec3::ZERO, Vec3::X, parallel),
+            None
+        );
+    }
+
+    #[test]
+    fn distance_to_segment() {
+        let (start, end) = (Vec2::ZERO, Vec2::new(10.0, 0.0));
+        assert_eq!(
+            super::distance_to_segment(Vec2::new(5.0, 3.0), start, end),
+            3.0
+        );
+        assert_eq!(
+            super::distance_to_segment(Vec2::new(14.0, 3.0), start, end),
+            5.0
+        );
+        assert_eq!(
+            super::distance_to_segment(Vec2::new(0.0, 2.0), start, start),
+            2.0
+        );
+    }
+}
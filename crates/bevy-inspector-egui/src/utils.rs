@@ -88,3 +88,233 @@ pub mod guess_entity_name {
         format!("Entity ({entity:?})")
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use bevy_reflect::TypeRegistry;
+
+    /// Type registry with the primitive types and their [`InspectorEguiImpl`](crate::inspector_egui_impls::InspectorEguiImpl)s
+    pub fn std_type_registry() -> TypeRegistry {
+        let mut type_registry = TypeRegistry::default();
+        crate::inspector_egui_impls::register_std_impls(&mut type_registry);
+        type_registry
+    }
+
+    /// Headless egui context which can be fed with simulated input
+    pub struct TestUi {
+        pub ctx: egui::Context,
+        time: f64,
+        events: Vec<egui::Event>,
+        modifiers: egui::Modifiers,
+        output: Option<egui::FullOutput>,
+    }
+
+    impl TestUi {
+        pub fn new() -> Self {
+            TestUi {
+                ctx: egui::Context::default(),
+                time: 0.0,
+                events: Vec::new(),
+                modifiers: egui::Modifiers::NONE,
+                output: None,
+            }
+        }
+
+        /// Runs a single frame with the queued events, calling `add_contents` inside of a central panel
+        pub fn run(&mut self, add_contents: &mut dyn FnMut(&mut egui::Ui)) {
+            self.time += 1.0 / 60.0;
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(800.0, 10000.0),
+                )),
+                time: Some(self.time),
+                events: std::mem::take(&mut self.events),
+                modifiers: self.modifiers,
+                ..Default::default()
+            };
+            let output = self.ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+            });
+            self.output = Some(output);
+        }
+
+        /// Center of the text painted in the last frame
+        pub fn text_position(&self, text: &str) -> Option<egui::Pos2> {
+            fn find(shape: &egui::Shape, text: &str) -> Option<egui::Pos2> {
+                match shape {
+                    egui::Shape::Text(shape) if shape.galley.text() == text => {
+                        Some(shape.galley.rect.translate(shape.pos.to_vec2()).center())
+                    }
+                    egui::Shape::Vec(shapes) => shapes.iter().find_map(|shape| find(shape, text)),
+                    _ => None,
+                }
+            }
+            self.output
+                .as_ref()?
+                .shapes
+                .iter()
+                .find_map(|egui::epaint::ClippedShape(_, shape)| find(shape, text))
+        }
+
+        /// Color of the text painted in the last frame
+        pub fn text_color(&self, text: &str) -> Option<egui::Color32> {
+            fn find(shape: &egui::Shape, text: &str) -> Option<egui::Color32> {
+                match shape {
+                    egui::Shape::Text(shape) if shape.galley.text() == text => Some(
+                        shape
+                            .override_text_color
+                            .unwrap_or(shape.galley.job.sections[0].format.color),
+                    ),
+                    egui::Shape::Vec(shapes) => shapes.iter().find_map(|shape| find(shape, text)),
+                    _ => None,
+                }
+            }
+            self.output
+                .as_ref()?
+                .shapes
+                .iter()
+                .find_map(|egui::epaint::ClippedShape(_, shape)| find(shape, text))
+        }
+
+        /// Text put on the clipboard in the last frame
+        #[cfg(feature = "bevy_scene")]
+        pub fn copied_text(&self) -> &str {
+            self.output
+                .as_ref()
+                .map_or("", |output| &output.platform_output.copied_text)
+        }
+
+        /// Presses and releases the primary mouse button at `pos`, over two frames
+        pub fn click(&mut self, pos: egui::Pos2, add_contents: &mut dyn FnMut(&mut egui::Ui)) {
+            self.click_button(pos, egui::PointerButton::Primary, add_contents);
+        }
+
+        /// Like [`TestUi::click`], but with the `modifiers` held down during both frames
+        pub fn click_with_modifiers(
+            &mut self,
+            pos: egui::Pos2,
+            modifiers: egui::Modifiers,
+            add_contents: &mut dyn FnMut(&mut egui::Ui),
+        ) {
+            self.modifiers = modifiers;
+            self.click_button(pos, egui::PointerButton::Primary, add_contents);
+            self.modifiers = egui::Modifiers::NONE;
+        }
+
+        /// Like [`TestUi::click`], but with the secondary mouse button which opens context menus
+        pub fn secondary_click(
+            &mut self,
+            pos: egui::Pos2,
+            add_contents: &mut dyn FnMut(&mut egui::Ui),
+        ) {
+            self.click_button(pos, egui::PointerButton::Secondary, add_contents);
+        }
+
+        fn click_button(
+            &mut self,
+            pos: egui::Pos2,
+            button: egui::PointerButton,
+            add_contents: &mut dyn FnMut(&mut egui::Ui),
+        ) {
+            for pressed in [true, false] {
+                self.events.push(egui::Event::PointerMoved(pos));
+                self.events.push(egui::Event::PointerButton {
+                    pos,
+                    button,
+                    pressed,
+                    modifiers: self.modifiers,
+                });
+                self.run(add_contents);
+            }
+        }
+
+        /// Presses the primary mouse button at `pos` without releasing it
+        pub fn press(&mut self, pos: egui::Pos2, add_contents: &mut dyn FnMut(&mut egui::Ui)) {
+            self.events.push(egui::Event::PointerMoved(pos));
+            self.events.push(egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed: true,
+                modifiers: egui::Modifiers::NONE,
+            });
+            self.run(add_contents);
+        }
+
+        /// Presses the primary mouse button at `from`, moves the pointer to `to` and releases it there, over three frames
+        pub fn drag(
+            &mut self,
+            from: egui::Pos2,
+            to: egui::Pos2,
+            add_contents: &mut dyn FnMut(&mut egui::Ui),
+        ) {
+            for (pos, pressed) in [(from, Some(true)), (to, None), (to, Some(false))] {
+                self.events.push(egui::Event::PointerMoved(pos));
+                if let Some(pressed) = pressed {
+                    self.events.push(egui::Event::PointerButton {
+                        pos,
+                        button: egui::PointerButton::Primary,
+                        pressed,
+                        modifiers: egui::Modifiers::NONE,
+                    });
+                }
+                self.run(add_contents);
+            }
+        }
+
+        /// Moves the pointer to `pos` and keeps it there for a few frames, so that tooltips are shown
+        pub fn hover(&mut self, pos: egui::Pos2, add_contents: &mut dyn FnMut(&mut egui::Ui)) {
+            self.events.push(egui::Event::PointerMoved(pos));
+            for _ in 0..10 {
+                self.run(add_contents);
+            }
+        }
+
+        /// Scrolls the mouse wheel by `delta` points while hovering `pos`
+        pub fn scroll(&mut self, pos: egui::Pos2, delta: egui::Vec2) {
+            self.events.push(egui::Event::PointerMoved(pos));
+            self.events.push(egui::Event::Scroll(delta));
+        }
+
+        /// Clicks the text field or drag value at `pos`, replaces its text `old` by `new` and confirms it with `Enter`
+        pub fn replace_text(
+            &mut self,
+            pos: egui::Pos2,
+            old: &str,
+            new: &str,
+            add_contents: &mut dyn FnMut(&mut egui::Ui),
+        ) {
+            // moving the pointer onto a drag value while pressing the button would drag it
+            self.events.push(egui::Event::PointerMoved(pos));
+            self.run(add_contents);
+            self.click(pos, add_contents);
+            self.key(egui::Key::End);
+            for _ in old.chars() {
+                self.key(egui::Key::Backspace);
+            }
+            self.type_text(new);
+            self.run(add_contents);
+            self.key(egui::Key::Enter);
+            self.run(add_contents);
+        }
+
+        pub fn key(&mut self, key: egui::Key) {
+            for pressed in [true, false] {
+                self.events.push(egui::Event::Key {
+                    key,
+                    pressed,
+                    repeat: false,
+                    modifiers: egui::Modifiers::NONE,
+                });
+            }
+        }
+
+        pub fn type_text(&mut self, text: &str) {
+            self.events.push(egui::Event::Text(text.to_string()));
+        }
+
+        pub fn paste(&mut self, text: &str) {
+            self.events.push(egui::Event::Paste(text.to_string()));
+        }
+    }
+}